@@ -0,0 +1,649 @@
+use crate::{
+    elf::Elf,
+    message,
+    minidump::{self, Context, Minidump},
+    reason::{self, Reason},
+    symbolizer::{self, Symbolizer},
+    Backtrace, Classification, Exception, Kind, Module, Range, Region, Snapshot, SymbolOptions,
+    Thread,
+};
+use std::fs;
+
+/// Faults this close to 0 are null pointer dereferences, plus a field offset.
+const NULL_PAGE: u64 = 0x10000;
+/// How far below the stack pointer a fault still counts as hitting the stack guard.
+const STACK_GUARD: u64 = 0x10000;
+const PAGE_SIZE: u64 = 0x1000;
+const MAX_DEPTH: u32 = 256;
+
+/// Fill patterns allocators write over freed memory.
+const POISON: [(u32, &str); 6] = [
+    (0xdead_beef, "0xdeadbeef"),
+    (0xfeee_feee, "Windows HeapFree"),
+    (0xdddd_dddd, "MSVC debug CRT freed memory"),
+    (0x5a5a_5a5a, "jemalloc junk-filled freed memory"),
+    (0x5555_5555, "macOS MallocScribble freed memory"),
+    (0x6b6b_6b6b, "slab POISON_FREE"),
+];
+
+/// The threads and modules of a dump, with a classification of its crash.
+pub fn snapshot(dump: &Minidump, symbols: &SymbolOptions) -> Snapshot {
+    snapshot_cached(dump, symbols, &mut symbolizer::Cache::new())
+}
+
+/// The snapshot of a dump, with the symbols of the modules in `cache` loaded by earlier ones.
+pub fn snapshot_cached(
+    dump: &Minidump,
+    symbols: &SymbolOptions,
+    cache: &mut symbolizer::Cache,
+) -> Snapshot {
+    let memory = dump.memory();
+    let arch = dump
+        .system_info()
+        .and_then(|info| minidump::arch(info.architecture));
+    let modules: Vec<Module> = dump
+        .modules()
+        .iter()
+        .map(|m| {
+            let (code_id, debug_id) = dump.module_ids(m);
+            Module {
+                path: dump.string(m.name_rva).unwrap_or_default(),
+                load_address: m.base,
+                size: Some(m.size as u64),
+                text_segment: Some(Range {
+                    start: m.base,
+                    end: m.base + m.size as u64,
+                }),
+                code_id,
+                debug_id,
+                debug_file: dump.debug_file(m),
+                arch: arch.map(ToOwned::to_owned),
+                version: m.version(),
+                symbols: vec![],
+            }
+        })
+        .collect();
+    let exception = dump.exception();
+    let reason = reason::minidump(dump);
+    let regions = regions(dump);
+
+    // Read-only data such as string literals is rarely in the dump, take it from the modules.
+    let symbolizer = Symbolizer::with_cache(&modules, symbols, cache);
+    let files: Vec<(&Module, Vec<u8>)> = modules
+        .iter()
+        .filter_map(|m| Some((m, fs::read(&m.path).ok()?)))
+        .collect();
+    let images: Vec<(u64, Elf)> = files
+        .iter()
+        .filter_map(|(m, data)| {
+            let elf = Elf::parse(data)?;
+            if symbolizer::stale(m, elf.build_id().map(|id| minidump::elf_ids(id).0)) {
+                return None;
+            }
+            Some((m.load_address.wrapping_sub(elf.base_address()), elf))
+        })
+        .collect();
+    let read = |address: u64, buf: &mut [u8]| {
+        let data = memory
+            .iter()
+            .find_map(|&(start, data)| {
+                let offset = address.checked_sub(start)? as usize;
+                data.get(offset..offset.checked_add(buf.len())?)
+            })
+            .or_else(|| {
+                images.iter().find_map(|(bias, elf)| {
+                    elf.read_only_data(address.wrapping_sub(*bias), buf.len())
+                })
+            });
+        data.map(|data| buf.copy_from_slice(data)).is_some()
+    };
+
+    let mut classification = None;
+    let threads = dump
+        .threads()
+        .iter()
+        .map(|thread| {
+            let exception = exception.as_ref().filter(|e| e.thread_id == thread.id);
+            let context = dump.context(exception.map_or(thread.context, |e| e.context));
+            if let (Some(reason), Some(context), true) = (&reason, &context, exception.is_some()) {
+                classification = classify(&regions, reason, context, read);
+            }
+            Thread {
+                id: thread.id as u64,
+                exception: exception.map(|e| Exception {
+                    reason: e.code as i32,
+                    code: [e.flags as i32, 0],
+                    description: reason.as_ref().map(ToString::to_string).unwrap_or_default(),
+                    message: context
+                        .as_ref()
+                        .and_then(|context| message::find(&symbolizer, context.sp(), read)),
+                }),
+                backtrace: context
+                    .map(|c| {
+                        let (pc, sp, fp) = (c.pc(), c.sp(), c.fp());
+                        backtrace(pc, sp, fp, c.pointer_size(), read, &modules, &symbolizer)
+                    })
+                    .unwrap_or_default(),
+            }
+        })
+        .collect();
+
+    Snapshot {
+        threads,
+        modules,
+        classification,
+        signature: vec![],
+        regions,
+    }
+}
+
+/// Unwind the stack from `pc`, `sp` and `fp`, reading it through `read`, with the unwind rules of
+/// symbol files where there are some and the chain of frame pointers of `pointer_size` bytes
+/// elsewhere.
+pub fn backtrace(
+    pc: u64,
+    sp: u64,
+    fp: u64,
+    pointer_size: u64,
+    read: impl Fn(u64, &mut [u8]) -> bool,
+    modules: &[Module],
+    symbolizer: &Symbolizer,
+) -> Vec<Backtrace> {
+    let read_pointer = |address| {
+        let mut buf = [0; 8];
+        read(address, &mut buf[..pointer_size as usize]).then(|| u64::from_le_bytes(buf))
+    };
+    let mut backtrace = vec![Backtrace::new(0, pc, modules)];
+    let (mut pc, mut sp, mut fp) = (pc, sp, fp);
+    for depth in 1..MAX_DEPTH {
+        // Frames grow down, a chain going back up is corrupted or has ended.
+        let (caller, up) = match symbolizer.unwind(pc - (depth > 1) as u64, sp, fp, &read) {
+            Some(caller) => (caller, caller.1 > sp),
+            None => {
+                let (Some(next), Some(pc)) = (read_pointer(fp), read_pointer(fp + pointer_size))
+                else {
+                    break;
+                };
+                ((pc, fp + pointer_size * 2, next), next > fp)
+            }
+        };
+        if caller.0 == 0 {
+            break;
+        }
+        backtrace.push(Backtrace::new(depth, caller.0, modules));
+        if !up {
+            break;
+        }
+        (pc, sp, fp) = caller;
+    }
+    for frame in &mut backtrace {
+        // A return address may be past the end of a call to a function that doesn't return.
+        let address = frame.address - (frame.depth > 0) as u64;
+        if let Some((symbol, start)) = symbolizer.locate(address) {
+            frame.symbol = Some(symbol.to_owned());
+            frame.offset = Some(frame.address - start);
+        }
+        (frame.source, frame.inlined) = symbolizer.source(address);
+    }
+    backtrace
+}
+
+fn classify(
+    regions: &[Region],
+    reason: &Reason,
+    context: &Context,
+    read: impl Fn(u64, &mut [u8]) -> bool,
+) -> Option<Classification> {
+    let name = reason.name.as_str();
+    let classification = |kind, explanation| Some(Classification { kind, explanation });
+
+    if [
+        "SIGABRT",
+        "EXC_UNIX_ABORT",
+        "STATUS_FATAL_APP_EXIT",
+        "STATUS_STACK_BUFFER_OVERRUN",
+        "STATUS_ASSERTION_FAILURE",
+    ]
+    .iter()
+    .any(|abort| name.contains(abort))
+    {
+        return classification(Kind::Abort, format!("the process aborted itself: {reason}"));
+    }
+    if name.starts_with("STATUS_STACK_OVERFLOW") {
+        return classification(
+            Kind::StackOverflow,
+            format!(
+                "stack overflow, with the stack pointer at {:#x}",
+                context.sp()
+            ),
+        );
+    }
+
+    let memory_fault = [
+        "SIGSEGV",
+        "SIGBUS",
+        "EXC_BAD_ACCESS",
+        "STATUS_ACCESS_VIOLATION",
+        "STATUS_IN_PAGE_ERROR",
+    ]
+    .iter()
+    .any(|fault| name.starts_with(fault));
+    if !memory_fault {
+        return None;
+    }
+
+    let (pc, sp) = (context.pc(), context.sp());
+    // A call through a null function pointer faults at its target, which is told apart below.
+    if let Some(address) = reason.address.filter(|&a| a < NULL_PAGE && a != pc) {
+        return classification(
+            Kind::NullPointerDereference,
+            format!("fault at {address:#x}, in the null page"),
+        );
+    }
+    if let Some(address) = reason.address {
+        let below = sp.checked_add(PAGE_SIZE).is_none_or(|end| address < end);
+        if below && sp - address.min(sp) < STACK_GUARD {
+            return classification(
+                Kind::StackOverflow,
+                format!(
+                    "fault at {address:#x}, in the stack guard next to the stack pointer {sp:#x}"
+                ),
+            );
+        }
+    }
+    if reason.address == Some(pc)
+        || name.ends_with("/ EXEC")
+        || executable(regions, pc) == Some(false)
+    {
+        let target = if pc < NULL_PAGE {
+            ", a call through a null function pointer"
+        } else {
+            ""
+        };
+        return classification(
+            Kind::NonExecutableJump,
+            format!("jumped to {pc:#x}, which is not executable memory{target}"),
+        );
+    }
+
+    // A poisoned address may not be reported at all, as x86-64 faults on non-canonical ones
+    // without an address, so look at the registers the faulting instruction dereferences too.
+    let poisoned = reason
+        .address
+        .map(|address| ("fault address".to_owned(), address))
+        .into_iter()
+        .chain(base_registers(context, read))
+        .find_map(|(name, value)| Some((name, value, poison(value)?)));
+    let (name, value, pattern) = poisoned?;
+    classification(
+        Kind::UseAfterFree,
+        format!("{name} {value:#x} holds the {pattern} pattern, likely a use after free"),
+    )
+}
+
+/// The registers holding the base addresses the instruction at the program counter accesses
+/// memory through.
+fn base_registers(context: &Context, read: impl Fn(u64, &mut [u8]) -> bool) -> Vec<(String, u64)> {
+    let pc = context.pc();
+    let indices = match context {
+        Context::Amd64(_) => {
+            // The longest instruction, or up to the end of the captured memory.
+            let mut code = [0; 15];
+            match (1..=code.len()).rev().find(|&n| read(pc, &mut code[..n])) {
+                Some(len) => x86_64_bases(&code[..len]).unwrap_or_default(),
+                None => vec![],
+            }
+        }
+        Context::Arm64(c) => {
+            let mut insn = [0; 4];
+            match read(pc, &mut insn).then(|| arm64_base(u32::from_le_bytes(insn))) {
+                Some(Some(31)) => return vec![("sp".to_owned(), c.sp)],
+                Some(Some(n)) => vec![n],
+                _ => vec![],
+            }
+        }
+        Context::X86(_) => vec![],
+    };
+    let gpr = context.gpr();
+    indices
+        .into_iter()
+        .filter_map(|i| gpr.get(i).cloned())
+        .collect()
+}
+
+/// The numbers of the base registers of the memory operands of an x86-64 instruction.
+fn x86_64_bases(code: &[u8]) -> Option<Vec<usize>> {
+    let mut i = code.iter().position(|b| {
+        !matches!(
+            b,
+            0x26 | 0x2e | 0x36 | 0x3e | 0x64..=0x67 | 0xf0 | 0xf2 | 0xf3
+        )
+    })?;
+    let mut b = 0;
+    if let rex @ 0x40..=0x4f = code[i] {
+        b = rex as usize & 1;
+        i += 1;
+    }
+    let at = match *code.get(i)? {
+        // VEX and EVEX prefixes hold REX.B inverted.
+        0xc5 => i + 3,
+        0xc4 => {
+            b = !code.get(i + 1)? as usize >> 5 & 1;
+            i + 4
+        }
+        0x62 => {
+            b = !code.get(i + 1)? as usize >> 5 & 1;
+            i + 5
+        }
+        0x0f => match *code.get(i + 1)? {
+            0x38 | 0x3a => i + 3,
+            _ => i + 2,
+        },
+        // String instructions address memory through rsi and rdi, the stack ones through rsp.
+        0xa4..=0xa7 => return Some(vec![6, 7]),
+        0xac | 0xad => return Some(vec![6]),
+        0xaa | 0xab | 0xae | 0xaf => return Some(vec![7]),
+        0x50..=0x5f | 0x68 | 0x6a | 0x9c | 0x9d | 0xc2 | 0xc3 | 0xe8 => return Some(vec![4]),
+        _ => i + 1,
+    };
+    let modrm = *code.get(at)?;
+    let base = match (modrm >> 6, modrm & 7) {
+        // A register operand, or one relative to rip.
+        (3, _) | (0, 5) => return None,
+        (mode, 4) => match code.get(at + 1)? & 7 {
+            5 if mode == 0 => return None,
+            base => base,
+        },
+        (_, rm) => rm,
+    };
+    Some(vec![base as usize + b * 8])
+}
+
+/// The base register of an AArch64 load or store, 31 being sp.
+fn arm64_base(insn: u32) -> Option<usize> {
+    let literal = insn & 0x3b00_0000 == 0x1800_0000;
+    (insn & 0x0a00_0000 == 0x0800_0000 && !literal).then_some((insn >> 5 & 31) as usize)
+}
+
+/// The mappings of the Linux maps stream, or else the committed regions of the memory info list,
+/// those of images named after their module.
+fn regions(dump: &Minidump) -> Vec<Region> {
+    if let Some(maps) = dump.stream(minidump::LINUX_MAPS) {
+        return String::from_utf8_lossy(maps)
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.split_whitespace();
+                let (start, end) = fields.next()?.split_once('-')?;
+                let perms = fields.next()?;
+                Some(Region {
+                    start: u64::from_str_radix(start, 16).ok()?,
+                    end: u64::from_str_radix(end, 16).ok()?,
+                    perms: perms.to_owned(),
+                    path: fields.nth(3).unwrap_or_default().to_owned(),
+                })
+            })
+            .collect();
+    }
+    let modules = dump.modules();
+    dump.memory_info()
+        .into_iter()
+        .filter(|info| info.state == minidump::MEM_COMMIT)
+        .map(|info| {
+            let module = modules
+                .iter()
+                .filter(|_| info.kind == minidump::MEM_IMAGE)
+                .find(|m| (m.base..m.base + m.size as u64).contains(&info.base));
+            Region {
+                start: info.base,
+                end: info.base.saturating_add(info.size),
+                perms: perms(info.protect, info.kind),
+                path: module
+                    .and_then(|m| dump.string(m.name_rva))
+                    .unwrap_or_default(),
+            }
+        })
+        .collect()
+}
+
+/// The permissions of a Windows region as in the Linux maps, such as `r-xp`.
+fn perms(protect: u32, kind: u32) -> String {
+    let (read, write, execute) = match protect & 0xff {
+        0x02 => (true, false, false),
+        0x04 | 0x08 => (true, true, false),
+        0x10 => (false, false, true),
+        0x20 => (true, false, true),
+        0x40 | 0x80 => (true, true, true),
+        _ => (false, false, false),
+    };
+    let mut perms = String::new();
+    perms.push(if read { 'r' } else { '-' });
+    perms.push(if write { 'w' } else { '-' });
+    perms.push(if execute { 'x' } else { '-' });
+    perms.push(if kind == minidump::MEM_MAPPED {
+        's'
+    } else {
+        'p'
+    });
+    perms
+}
+
+/// Whether the address is in executable memory, when the mappings are known.
+fn executable(regions: &[Region], address: u64) -> Option<bool> {
+    (!regions.is_empty()).then(|| {
+        regions
+            .iter()
+            .any(|r| (r.start..r.end).contains(&address) && r.perms.contains('x'))
+    })
+}
+
+fn poison(value: u64) -> Option<&'static str> {
+    let (high, low) = ((value >> 32) as u32, value as u32);
+    POISON.iter().find_map(|&(pattern, name)| {
+        // The pattern itself, or a 32-bit one plus an offset.
+        (high == pattern || (high == 0 && low >> 16 == pattern >> 16)).then_some(name)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::minidump::{ContextAmd64, Location, Writer};
+
+    const RIP: u64 = 0x40_1000;
+    const RSP: u64 = 0x7ffc_0000;
+
+    fn amd64(gpr: &[(usize, u64)], rip: u64, sp: u64) -> Context {
+        let mut context = ContextAmd64 {
+            gpr: [0; 16],
+            rip,
+            eflags: 0,
+            segments: [0; 6],
+            fxsave: [0; 512],
+        };
+        for &(i, value) in gpr {
+            context.gpr[i] = value;
+        }
+        context.gpr[4] = sp;
+        Context::Amd64(context)
+    }
+
+    /// Classify a fault of the instruction `code` at `RIP`.
+    fn classified(reason: Reason, context: &Context, code: &[u8]) -> Option<Kind> {
+        let regions = [Region {
+            start: RIP,
+            end: RIP + 0x1000,
+            perms: "r-xp".to_owned(),
+            path: String::new(),
+        }];
+        let read = |address: u64, buf: &mut [u8]| {
+            let Some(code) = address
+                .checked_sub(RIP)
+                .and_then(|i| code.get(i as usize..))
+            else {
+                return false;
+            };
+            let fits = code.len() >= buf.len();
+            if fits {
+                buf.copy_from_slice(&code[..buf.len()]);
+            }
+            fits
+        };
+        classify(&regions, &reason, context, read).map(|c| c.kind)
+    }
+
+    // mov rax, [rdi]
+    const LOAD_RDI: &[u8] = &[0x48, 0x8b, 0x07];
+
+    #[test]
+    fn abort() {
+        let kind = classified(reason::signal(6, -6, 0), &amd64(&[], RIP, RSP), &[]);
+        assert!(matches!(kind, Some(Kind::Abort)));
+    }
+
+    #[test]
+    fn stack_overflow() {
+        let context = amd64(&[], RIP, RSP);
+        let kind = classified(reason::ntstatus(0xc000_00fd, &[]), &context, &[]);
+        assert!(matches!(kind, Some(Kind::StackOverflow)));
+        let kind = classified(reason::signal(11, 2, RSP - 0x100), &context, &[]);
+        assert!(matches!(kind, Some(Kind::StackOverflow)));
+
+        let context = amd64(&[], RIP, u64::MAX - 8);
+        assert!(classified(reason::signal(11, 1, 0x1234_5678), &context, &[]).is_none());
+    }
+
+    #[test]
+    fn non_executable_jump() {
+        let context = amd64(&[], 0x1234_5000, RSP);
+        let kind = classified(reason::signal(11, 2, 0x1234_5000), &context, &[]);
+        assert!(matches!(kind, Some(Kind::NonExecutableJump)));
+        let context = amd64(&[], 0x10, RSP);
+        let kind = classified(reason::signal(11, 1, 0x10), &context, &[]);
+        assert!(matches!(kind, Some(Kind::NonExecutableJump)));
+    }
+
+    #[test]
+    fn null_pointer_dereference() {
+        // A poison-looking register besides doesn't make it a use after free.
+        let context = amd64(&[(0, 0x5555_0010), (7, 0x8)], RIP, RSP);
+        let kind = classified(reason::signal(11, 1, 0x8), &context, LOAD_RDI);
+        assert!(matches!(kind, Some(Kind::NullPointerDereference)));
+    }
+
+    #[test]
+    fn use_after_free() {
+        // A non-canonical address faults without one.
+        let context = amd64(&[(7, 0xdead_beef_dead_beef)], RIP, RSP);
+        let kind = classified(reason::signal(11, 0x80, 0), &context, LOAD_RDI);
+        assert!(matches!(kind, Some(Kind::UseAfterFree)));
+        let kind = classified(reason::signal(11, 1, 0x6b6b_6b6b_6b6b_6b6b), &context, &[]);
+        assert!(matches!(kind, Some(Kind::UseAfterFree)));
+
+        // Only the dereferenced register counts.
+        let context = amd64(&[(0, 0x5555_0010), (7, 0x7f00_0000_0000)], RIP, RSP);
+        assert!(classified(reason::signal(11, 0x80, 0), &context, LOAD_RDI).is_none());
+    }
+
+    #[test]
+    fn bases() {
+        assert_eq!(x86_64_bases(LOAD_RDI), Some(vec![7]));
+        // mov eax, [r12]
+        assert_eq!(x86_64_bases(&[0x41, 0x8b, 0x04, 0x24]), Some(vec![12]));
+        // mov eax, fs:[rbx + 8]
+        assert_eq!(x86_64_bases(&[0x64, 0x8b, 0x43, 0x08]), Some(vec![3]));
+        // mov eax, [rip + 0x10]
+        assert_eq!(x86_64_bases(&[0x8b, 0x05, 0x10, 0, 0, 0]), None);
+        // movzx eax, byte [rsi]
+        assert_eq!(x86_64_bases(&[0x0f, 0xb6, 0x06]), Some(vec![6]));
+        // vmovups ymm0, [rsi], then [r8]
+        assert_eq!(x86_64_bases(&[0xc5, 0xfc, 0x10, 0x06]), Some(vec![6]));
+        assert_eq!(x86_64_bases(&[0xc4, 0xc1, 0x7c, 0x10, 0x00]), Some(vec![8]));
+        // rep movsb
+        assert_eq!(x86_64_bases(&[0xf3, 0xa4]), Some(vec![6, 7]));
+        // mov rax, rdi
+        assert_eq!(x86_64_bases(&[0x48, 0x89, 0xf8]), None);
+
+        // ldr x0, [x1], ldp x29, x30, [sp], str w2, [x3, #4], ldr x0, literal
+        assert_eq!(arm64_base(0xf940_0020), Some(1));
+        assert_eq!(arm64_base(0xa940_7bfd), Some(31));
+        assert_eq!(arm64_base(0xb900_0462), Some(3));
+        assert_eq!(arm64_base(0x5800_0040), None);
+        // add x0, x1, x2
+        assert_eq!(arm64_base(0x8b02_0020), None);
+    }
+
+    #[test]
+    fn memory_info_regions() {
+        let mut writer = Writer::new();
+        let name_rva = writer.string("C:\\app.exe");
+        let module = minidump::Module {
+            base: 0x40_0000,
+            size: 0x2000,
+            time_date_stamp: 0,
+            name_rva,
+            file_version: None,
+            cv_record: Location::default(),
+        };
+        let mut list = 1u32.to_le_bytes().to_vec();
+        list.extend(module.to_bytes());
+        writer.stream(minidump::MODULE_LIST_STREAM, &list);
+        // Base, size, state, protection and type of each region.
+        let infos = [
+            (
+                0x40_0000,
+                0x1000,
+                minidump::MEM_COMMIT,
+                0x20,
+                minidump::MEM_IMAGE,
+            ),
+            (
+                0x40_1000,
+                0x1000,
+                minidump::MEM_COMMIT,
+                0x04,
+                minidump::MEM_IMAGE,
+            ),
+            (0x50_0000, 0x3000, minidump::MEM_COMMIT, 0x104, 0x20000),
+            (0x60_0000, 0x1000, 0x2000, 0x01, 0x20000),
+            (
+                0x70_0000,
+                0x1000,
+                minidump::MEM_COMMIT,
+                0x02,
+                minidump::MEM_MAPPED,
+            ),
+        ];
+        let mut list = [16u32, 48]
+            .iter()
+            .flat_map(|v| v.to_le_bytes())
+            .collect::<Vec<u8>>();
+        list.extend((infos.len() as u64).to_le_bytes());
+        for (base, size, state, protect, kind) in infos {
+            let mut info = [0; 48];
+            info[..8].copy_from_slice(&u64::to_le_bytes(base));
+            info[24..32].copy_from_slice(&u64::to_le_bytes(size));
+            info[32..36].copy_from_slice(&u32::to_le_bytes(state));
+            info[36..40].copy_from_slice(&u32::to_le_bytes(protect));
+            info[40..44].copy_from_slice(&u32::to_le_bytes(kind));
+            list.extend(info);
+        }
+        writer.stream(minidump::MEMORY_INFO_LIST_STREAM, &list);
+        let data = writer.finish(0);
+
+        let regions: Vec<(u64, u64, String, String)> = regions(&Minidump::parse(&data).unwrap())
+            .into_iter()
+            .map(|r| (r.start, r.end, r.perms, r.path))
+            .collect();
+        let region =
+            |start, end, perms: &str, path: &str| (start, end, perms.to_owned(), path.to_owned());
+        assert_eq!(
+            regions,
+            [
+                region(0x40_0000, 0x40_1000, "r-xp", "C:\\app.exe"),
+                region(0x40_1000, 0x40_2000, "rw-p", "C:\\app.exe"),
+                region(0x50_0000, 0x50_3000, "rw-p", ""),
+                region(0x70_0000, 0x70_1000, "r--s", ""),
+            ]
+        );
+    }
+}
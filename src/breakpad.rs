@@ -0,0 +1,868 @@
+use crate::{
+    dwarf::{self, Fde, Line, LineTable, Sections},
+    elf::{self, Elf},
+    macho::{self, MachO},
+    minidump,
+    pdb::{FrameData, Pdb, Procedure},
+    pe::{self, Pe},
+};
+use std::{
+    collections::HashMap,
+    fmt, fs,
+    path::{Path, PathBuf},
+};
+
+/// The symbols, lines and unwind rules of a module, as in a Breakpad `.sym` file, at addresses
+/// relative to the module's load address.
+#[derive(Default)]
+pub struct SymbolFile {
+    pub os: String,
+    pub arch: String,
+    pub debug_id: String,
+    /// The name of the debug file, the PDB of a PE image, which the store is laid out by.
+    pub name: String,
+    pub code_id: Option<String>,
+    pub files: Vec<String>,
+    pub functions: Vec<Function>,
+    /// Symbols without a size, such as exports.
+    pub publics: Vec<(u64, String)>,
+    pub cfi: Vec<Fde>,
+    /// The names of the functions inlined into others.
+    pub origins: Vec<String>,
+    pub stack_win: Vec<FrameData>,
+}
+
+pub struct Function {
+    pub address: u64,
+    pub size: u64,
+    pub name: String,
+    /// At each address, the line of the innermost function inlined there.
+    pub lines: Vec<Line>,
+    pub inlines: Vec<Inline>,
+}
+
+/// A function inlined into another, whose code is at `ranges`.
+pub struct Inline {
+    /// 0 for functions inlined directly into the function, 1 for those inlined into those...
+    pub depth: u32,
+    /// The line and file of the call in the function it's inlined into.
+    pub call_line: u32,
+    pub call_file: usize,
+    /// Index in `origins`.
+    pub origin: usize,
+    pub ranges: Vec<(u64, u64)>,
+}
+
+/// The symbol file of an ELF, Mach-O or PE image or of a PDB named `name`. Those of PE images
+/// only have exports, functions and lines being in their PDB.
+pub fn dump_syms(data: &[u8], name: &str) -> Option<SymbolFile> {
+    if let Some(pdb) = Pdb::parse(data) {
+        return pdb_syms(&pdb, name);
+    }
+    if let Some(elf) = Elf::parse(data) {
+        return elf_syms(&elf, name);
+    }
+    if let Some(image) = MachO::file(macho::slice(data), None) {
+        return macho_syms(&image, name);
+    }
+    pe_syms(&Pe::parse(data)?)
+}
+
+fn elf_syms(elf: &Elf, name: &str) -> Option<SymbolFile> {
+    let arch = elf::arch(elf.header.e_machine)?;
+    let (code_id, debug_id) = minidump::elf_ids(elf.build_id()?);
+    // Debug sections of a stripped or split image are empty, and compressed ones unsupported.
+    let section = |name| {
+        elf.section(name)
+            .filter(|sh| sh.sh_type != elf::SHT_NOBITS && sh.sh_flags & elf::SHF_COMPRESSED == 0)
+    };
+    let data = |name| {
+        section(name)
+            .map(|sh| elf.section_data(&sh))
+            .unwrap_or_default()
+    };
+    let lines = dwarf::lines(&Sections {
+        debug_line: data(".debug_line"),
+        debug_line_str: data(".debug_line_str"),
+        debug_str: data(".debug_str"),
+    });
+    let mut cfi = vec![];
+    for (name, eh_frame) in [(".eh_frame", true), (".debug_frame", false)] {
+        if let Some(sh) = section(name) {
+            cfi.extend(dwarf::cfi(
+                elf.section_data(&sh),
+                sh.sh_addr,
+                eh_frame,
+                arch,
+            ));
+        }
+    }
+    let symbols = elf
+        .symbols()
+        .into_iter()
+        .filter(|sym| sym.kind == elf::STT_FUNC)
+        .map(|sym| (sym.value, sym.size, sym.name.to_owned()))
+        .collect();
+    Some(symbol_file(
+        SymbolFile {
+            os: "Linux".to_owned(),
+            arch: arch.to_owned(),
+            debug_id,
+            name: name.to_owned(),
+            code_id: Some(code_id),
+            ..Default::default()
+        },
+        elf.base_address(),
+        symbols,
+        lines,
+        cfi,
+    ))
+}
+
+/// The symbol file of a Mach-O image or of its dSYM, whose DWARF is in the `__DWARF` segment.
+fn macho_syms<R: Fn(u64, &mut [u8]) -> bool>(image: &MachO<R>, name: &str) -> Option<SymbolFile> {
+    let arch = macho::arch(image.header.cputype)?;
+    let (code_id, debug_id) = macho::ids(&image.uuid()?);
+    let text = image.segments().iter().find(|seg| seg.name == "__TEXT")?;
+    let section = |name| {
+        image
+            .section("__DWARF", name)
+            .map(|(_, data)| data)
+            .unwrap_or_default()
+    };
+    let (debug_line, debug_line_str, debug_str) = (
+        section("__debug_line"),
+        section("__debug_line_str"),
+        section("__debug_str"),
+    );
+    let lines = dwarf::lines(&Sections {
+        debug_line: &debug_line,
+        debug_line_str: &debug_line_str,
+        debug_str: &debug_str,
+    });
+    let mut cfi = vec![];
+    for (segment, name, eh_frame) in [
+        ("__TEXT", "__eh_frame", true),
+        ("__DWARF", "__debug_frame", false),
+    ] {
+        if let Some((address, data)) = image.section(segment, name) {
+            cfi.extend(dwarf::cfi(&data, address, eh_frame, arch));
+        }
+    }
+    let symbols = image
+        .sized_symbols(image.symbols())
+        .into_iter()
+        .filter(|(sym, _)| text.vmaddr <= sym.value && sym.value < text.vmaddr + text.vmsize)
+        .map(|(sym, size)| {
+            // C names are prefixed with an underscore.
+            let name = sym.name.strip_prefix('_').unwrap_or(&sym.name).to_owned();
+            (sym.value, size, name)
+        })
+        .collect();
+    Some(symbol_file(
+        SymbolFile {
+            os: "mac".to_owned(),
+            arch: arch.to_owned(),
+            debug_id,
+            name: name.to_owned(),
+            code_id: Some(code_id),
+            ..Default::default()
+        },
+        text.vmaddr,
+        symbols,
+        lines,
+        cfi,
+    ))
+}
+
+fn pe_syms(pe: &Pe) -> Option<SymbolFile> {
+    let cv = pe.codeview()?;
+    let mut publics: Vec<(u64, String)> = pe
+        .exports()
+        .into_iter()
+        .map(|(name, address)| (address as u64, name))
+        .collect();
+    publics.sort();
+    Some(SymbolFile {
+        os: "windows".to_owned(),
+        arch: pe::arch(pe.header.Machine)?.to_owned(),
+        debug_id: minidump::debug_id(&cv.guid, cv.age),
+        name: cv.path.rsplit(['\\', '/']).next()?.to_owned(),
+        code_id: Some(pe.code_id()),
+        publics,
+        ..Default::default()
+    })
+}
+
+/// The symbol file of the PDB of a PE image, with the functions inlined into others and the FPO
+/// and frame data of x86 images.
+fn pdb_syms(pdb: &Pdb, name: &str) -> Option<SymbolFile> {
+    let (files, procedures) = pdb.procedures();
+    let mut origins = HashMap::new();
+    let mut functions: Vec<Function> = procedures
+        .into_iter()
+        .map(|procedure| function(procedure, &mut origins))
+        .collect();
+    // Functions the linker folded together share an address.
+    functions.sort_by_key(|func| func.address);
+    functions.dedup_by_key(|func| func.address);
+    let mut publics = pdb.publics();
+    publics.retain(|(address, _)| {
+        functions
+            .binary_search_by_key(address, |func| func.address)
+            .is_err()
+    });
+    publics.sort();
+    publics.dedup_by_key(|(address, _)| *address);
+    let mut origins: Vec<(String, usize)> = origins.into_iter().collect();
+    origins.sort_by_key(|&(_, id)| id);
+    Some(SymbolFile {
+        os: "windows".to_owned(),
+        arch: pe::arch(pdb.machine)?.to_owned(),
+        debug_id: pdb.debug_id(),
+        name: name.to_owned(),
+        files,
+        functions,
+        publics,
+        origins: origins.into_iter().map(|(name, _)| name).collect(),
+        stack_win: pdb.frame_data(),
+        ..Default::default()
+    })
+}
+
+/// The function of a procedure, the lines of the functions inlined into it replacing its own at
+/// their addresses, and each inlined function called from the line of the one it's inlined into.
+fn function(procedure: Procedure, origins: &mut HashMap<String, usize>) -> Function {
+    let mut layers: Vec<(u32, &Line)> = procedure.lines.iter().map(|line| (0, line)).collect();
+    let mut inlines = vec![];
+    // The lines of the procedure, then of each site down to the one being visited.
+    let mut parents = vec![&procedure.lines];
+    for site in &procedure.inlines {
+        parents.truncate(site.depth as usize + 1);
+        let Some(first) = site.lines.iter().map(|line| line.address).min() else {
+            continue;
+        };
+        let call = parents.last().and_then(|lines| {
+            lines
+                .iter()
+                .find(|l| l.address <= first && first < l.address + l.size)
+        });
+        let mut ranges: Vec<(u64, u64)> = vec![];
+        let mut lines: Vec<&Line> = site.lines.iter().collect();
+        lines.sort_by_key(|line| line.address);
+        for line in lines {
+            match ranges.last_mut() {
+                Some((address, size)) if *address + *size == line.address => *size += line.size,
+                _ => ranges.push((line.address, line.size)),
+            }
+        }
+        let count = origins.len();
+        inlines.push(Inline {
+            depth: site.depth,
+            call_line: call.map_or(0, |line| line.line),
+            call_file: call.map_or(0, |line| line.file),
+            origin: *origins.entry(site.name.clone()).or_insert(count),
+            ranges,
+        });
+        layers.extend(site.lines.iter().map(|line| (site.depth + 1, line)));
+        parents.push(&site.lines);
+    }
+
+    let mut bounds: Vec<u64> = layers
+        .iter()
+        .flat_map(|(_, line)| [line.address, line.address + line.size])
+        .collect();
+    bounds.sort();
+    bounds.dedup();
+    let mut lines: Vec<Line> = vec![];
+    for bound in bounds.windows(2) {
+        let (start, end) = (bound[0], bound[1]);
+        let Some(&(_, line)) = layers
+            .iter()
+            .filter(|(_, line)| line.address <= start && end <= line.address + line.size)
+            .max_by_key(|&&(depth, _)| depth)
+        else {
+            continue;
+        };
+        match lines.last_mut() {
+            Some(last)
+                if last.address + last.size == start
+                    && (last.line, last.file) == (line.line, line.file) =>
+            {
+                last.size += end - start
+            }
+            _ => lines.push(Line {
+                address: start,
+                size: end - start,
+                ..*line
+            }),
+        }
+    }
+    Function {
+        address: procedure.address,
+        size: procedure.size,
+        name: procedure.name,
+        lines,
+        inlines,
+    }
+}
+
+/// Fill `file` with the functions of `symbols`, their lines, and the symbols without a size as
+/// publics, at addresses relative to `base`.
+fn symbol_file(
+    mut file: SymbolFile,
+    base: u64,
+    mut symbols: Vec<(u64, u64, String)>,
+    table: LineTable,
+    mut cfi: Vec<Fde>,
+) -> SymbolFile {
+    // The first name of each address, preferring those with a size.
+    symbols.sort_by_key(|&(address, size, _)| (address, size == 0));
+    symbols.dedup_by_key(|&mut (address, _, _)| address);
+    let mut lines = table.lines;
+    lines.sort_by_key(|line| line.address);
+    for (address, size, name) in symbols {
+        let name = format!("{:#}", rustc_demangle::demangle(&name));
+        if size == 0 {
+            file.publics.push((address.wrapping_sub(base), name));
+            continue;
+        }
+        let end = address.saturating_add(size);
+        let first = lines.partition_point(|line| line.address < address);
+        let lines = lines[first..]
+            .iter()
+            .take_while(|line| line.address < end)
+            .map(|line| Line {
+                address: line.address.wrapping_sub(base),
+                size: line.size.min(end - line.address),
+                line: line.line,
+                file: line.file,
+            })
+            .collect();
+        file.functions.push(Function {
+            address: address.wrapping_sub(base),
+            size,
+            name,
+            lines,
+            inlines: vec![],
+        });
+    }
+    file.files = table.files;
+    cfi.sort_by_key(|fde| fde.address);
+    // `.debug_frame` may repeat the functions of `.eh_frame`.
+    cfi.dedup_by_key(|fde| fde.address);
+    for fde in &mut cfi {
+        fde.address = fde.address.wrapping_sub(base);
+        for (address, _) in &mut fde.rules {
+            *address = address.wrapping_sub(base);
+        }
+    }
+    file.cfi = cfi;
+    file
+}
+
+impl fmt::Display for SymbolFile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "MODULE {} {} {} {}",
+            self.os, self.arch, self.debug_id, self.name
+        )?;
+        if let Some(code_id) = &self.code_id {
+            writeln!(f, "INFO CODE_ID {code_id}")?;
+        }
+        for (i, file) in self.files.iter().enumerate() {
+            writeln!(f, "FILE {i} {file}")?;
+        }
+        for (i, name) in self.origins.iter().enumerate() {
+            writeln!(f, "INLINE_ORIGIN {i} {name}")?;
+        }
+        for func in &self.functions {
+            writeln!(f, "FUNC {:x} {:x} 0 {}", func.address, func.size, func.name)?;
+            for inline in &func.inlines {
+                write!(
+                    f,
+                    "INLINE {} {} {} {}",
+                    inline.depth, inline.call_line, inline.call_file, inline.origin
+                )?;
+                for (address, size) in &inline.ranges {
+                    write!(f, " {address:x} {size:x}")?;
+                }
+                writeln!(f)?;
+            }
+            for line in &func.lines {
+                writeln!(
+                    f,
+                    "{:x} {:x} {} {}",
+                    line.address, line.size, line.line, line.file
+                )?;
+            }
+        }
+        for (address, name) in &self.publics {
+            writeln!(f, "PUBLIC {address:x} 0 {name}")?;
+        }
+        for frame in &self.stack_win {
+            write!(
+                f,
+                "STACK WIN {:x} {:x} {:x} {:x} {:x} {:x} {:x} {:x} {:x} ",
+                frame.kind,
+                frame.address,
+                frame.size,
+                frame.prolog,
+                frame.epilog,
+                frame.params,
+                frame.saved_regs,
+                frame.locals,
+                frame.max_stack
+            )?;
+            match &frame.program {
+                Some(program) => writeln!(f, "1 {program}")?,
+                None => writeln!(f, "0 {}", frame.allocates_base_pointer as u8)?,
+            }
+        }
+        for fde in &self.cfi {
+            let Some(((_, init), deltas)) = fde.rules.split_first() else {
+                continue;
+            };
+            writeln!(f, "STACK CFI INIT {:x} {:x} {init}", fde.address, fde.size)?;
+            for (address, rules) in deltas {
+                writeln!(f, "STACK CFI {address:x} {rules}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parse a `.sym` file, leaving out the records it doesn't know.
+pub fn parse(text: &str) -> Option<SymbolFile> {
+    let mut lines = text.lines();
+    let module: Vec<&str> = lines.next()?.trim_end().splitn(5, ' ').collect();
+    let ["MODULE", os, arch, debug_id, name] = module[..] else {
+        return None;
+    };
+    let mut file = SymbolFile {
+        os: os.to_owned(),
+        arch: arch.to_owned(),
+        debug_id: debug_id.to_owned(),
+        name: name.to_owned(),
+        ..Default::default()
+    };
+    for line in lines {
+        record(&mut file, line.trim_end());
+    }
+    Some(file)
+}
+
+fn record(file: &mut SymbolFile, line: &str) -> Option<()> {
+    let hex = |s: &str| u64::from_str_radix(s, 16).ok();
+    let (kind, rest) = line.split_once(' ')?;
+    match kind {
+        "INFO" => file.code_id = Some(rest.strip_prefix("CODE_ID ")?.split(' ').next()?.to_owned()),
+        "FILE" => {
+            let (i, name) = rest.split_once(' ')?;
+            let i: usize = i.parse().ok()?;
+            if file.files.len() <= i {
+                file.files.resize(i + 1, String::new());
+            }
+            file.files[i] = name.to_owned();
+        }
+        "FUNC" => {
+            // Functions folded together by the linker are marked `m`.
+            let rest = rest.strip_prefix("m ").unwrap_or(rest);
+            let [address, size, _, name] = rest.splitn(4, ' ').collect::<Vec<_>>()[..] else {
+                return None;
+            };
+            file.functions.push(Function {
+                address: hex(address)?,
+                size: hex(size)?,
+                name: name.to_owned(),
+                lines: vec![],
+                inlines: vec![],
+            });
+        }
+        "INLINE_ORIGIN" => {
+            let (i, name) = rest.split_once(' ')?;
+            let i: usize = i.parse().ok()?;
+            if file.origins.len() <= i {
+                file.origins.resize(i + 1, String::new());
+            }
+            file.origins[i] = name.to_owned();
+        }
+        "INLINE" => {
+            let fields: Vec<&str> = rest.split(' ').collect();
+            let [depth, call_line, call_file, origin, ref ranges @ ..] = fields[..] else {
+                return None;
+            };
+            let inline = Inline {
+                depth: depth.parse().ok()?,
+                call_line: call_line.parse().ok()?,
+                call_file: call_file.parse().ok()?,
+                origin: origin.parse().ok()?,
+                ranges: ranges
+                    .chunks_exact(2)
+                    .map(|range| Some((hex(range[0])?, hex(range[1])?)))
+                    .collect::<Option<_>>()?,
+            };
+            file.functions.last_mut()?.inlines.push(inline);
+        }
+        "PUBLIC" => {
+            let rest = rest.strip_prefix("m ").unwrap_or(rest);
+            let [address, _, name] = rest.splitn(3, ' ').collect::<Vec<_>>()[..] else {
+                return None;
+            };
+            file.publics.push((hex(address)?, name.to_owned()));
+        }
+        "STACK" if rest.starts_with("WIN ") => {
+            let fields: Vec<&str> = rest.splitn(12, ' ').collect();
+            let [_, kind, address, size, prolog, epilog, params, saved_regs, locals, max_stack, has_program, last] =
+                fields[..]
+            else {
+                return None;
+            };
+            let hex32 = |s| u32::from_str_radix(s, 16).ok();
+            file.stack_win.push(FrameData {
+                kind: hex32(kind)? as u8,
+                address: hex(address)?,
+                size: hex32(size)?,
+                prolog: hex32(prolog)?,
+                epilog: hex32(epilog)?,
+                params: hex32(params)?,
+                saved_regs: hex32(saved_regs)?,
+                locals: hex32(locals)?,
+                max_stack: hex32(max_stack)?,
+                program: (has_program == "1").then(|| last.to_owned()),
+                allocates_base_pointer: has_program != "1" && last == "1",
+            });
+        }
+        "STACK" => {
+            let rest = rest.strip_prefix("CFI ")?;
+            if let Some(init) = rest.strip_prefix("INIT ") {
+                let [address, size, rules] = init.splitn(3, ' ').collect::<Vec<_>>()[..] else {
+                    return None;
+                };
+                let address = hex(address)?;
+                file.cfi.push(Fde {
+                    address,
+                    size: hex(size)?,
+                    rules: vec![(address, rules.to_owned())],
+                });
+            } else {
+                let (address, rules) = rest.split_once(' ')?;
+                let fde = file.cfi.last_mut()?;
+                fde.rules.push((hex(address)?, rules.to_owned()));
+            }
+        }
+        _ => {
+            // A line record of the last function.
+            let [address, size, line, i] = line.split(' ').collect::<Vec<_>>()[..] else {
+                return None;
+            };
+            let line = Line {
+                address: hex(address)?,
+                size: hex(size)?,
+                line: line.parse().ok()?,
+                file: i.parse().ok()?,
+            };
+            file.functions.last_mut()?.lines.push(line);
+        }
+    }
+    Some(())
+}
+
+/// Where a store keeps the symbol file of `name`: `<name>/<debug_id>/<name>.sym`.
+pub fn store_path(store: &Path, name: &str, debug_id: &str) -> PathBuf {
+    store.join(name).join(debug_id).join(sym_name(name))
+}
+
+/// The file name of the symbol file of `name`, leaving out the extension of a PDB.
+pub fn sym_name(name: &str) -> String {
+    let stem = match name.to_ascii_lowercase().ends_with(".pdb") {
+        true => &name[..name.len() - 4],
+        false => name,
+    };
+    format!("{stem}.sym")
+}
+
+pub fn load(store: &Path, name: &str, debug_id: &str) -> Option<SymbolFile> {
+    parse(&fs::read_to_string(store_path(store, name, debug_id)).ok()?)
+}
+
+/// The caller's registers from the rules of `fde` in effect at `address` and the callee's
+/// `registers`, reading memory through `read`. Its stack pointer is `.cfa` and its pc `.ra`.
+pub fn unwind(
+    fde: &Fde,
+    address: u64,
+    registers: &HashMap<String, u64>,
+    read: impl Fn(u64, &mut [u8]) -> bool,
+) -> HashMap<String, u64> {
+    let mut rules = HashMap::new();
+    for (_, text) in fde.rules.iter().take_while(|(at, _)| *at <= address) {
+        // A later rule for a register replaces the earlier one.
+        let mut name = None;
+        for token in text.split_whitespace() {
+            match token.strip_suffix(':') {
+                Some(rule) => {
+                    rules.insert(rule, vec![]);
+                    name = Some(rule);
+                }
+                None => {
+                    if let Some(rule) = name.and_then(|name| rules.get_mut(name)) {
+                        rule.push(token);
+                    }
+                }
+            }
+        }
+    }
+    let mut caller = HashMap::new();
+    let mut variables = registers.clone();
+    let Some(cfa) = rules
+        .get(".cfa")
+        .and_then(|rule| evaluate(rule, &variables, &mut HashMap::new(), 8, &read)?)
+    else {
+        return caller;
+    };
+    variables.insert(".cfa".to_owned(), cfa);
+    for (name, rule) in rules {
+        if let Some(Some(value)) = evaluate(&rule, &variables, &mut HashMap::new(), 8, &read) {
+            caller.insert(name.to_owned(), value);
+        }
+    }
+    caller
+}
+
+/// The caller's `$eip`, `$esp` and `$ebp` from the `STACK WIN` record of an x86 function and the
+/// callee's `registers`, reading memory through `read`. `$ebp` is the callee's unless the record
+/// restores it.
+pub fn unwind_win(
+    frame: &FrameData,
+    registers: &HashMap<String, u64>,
+    read: impl Fn(u64, &mut [u8]) -> bool,
+) -> Option<HashMap<String, u64>> {
+    let mut variables = registers.clone();
+    // Where the return address is, above the locals and saved registers.
+    let ra_search = registers.get("$esp")? + frame.locals as u64 + frame.saved_regs as u64;
+    for (name, value) in [
+        (".cbCalleeParams", 0),
+        (".cbParams", frame.params as u64),
+        (".cbSavedRegs", frame.saved_regs as u64),
+        (".cbLocals", frame.locals as u64),
+        (".raSearch", ra_search),
+        (".raSearchStart", ra_search),
+    ] {
+        variables.insert(name.to_owned(), value);
+    }
+    let program = match &frame.program {
+        Some(program) => program,
+        // The saved registers of FPO functions end with `ebp` when they use it.
+        None if frame.allocates_base_pointer => {
+            "$eip .raSearchStart ^ = $ebp .raSearchStart .cbSavedRegs - ^ = $esp .raSearchStart 4 + ="
+        }
+        None => "$eip .raSearchStart ^ = $esp .raSearchStart 4 + =",
+    };
+    let tokens: Vec<&str> = program.split_whitespace().collect();
+    let mut assigned = HashMap::new();
+    if evaluate(&tokens, &variables, &mut assigned, 4, read)?.is_some() {
+        return None;
+    }
+    let mut caller = HashMap::new();
+    for name in ["$eip", "$esp", "$ebp"] {
+        let value = assigned.get(name).or(match name {
+            "$ebp" => registers.get(name),
+            _ => None,
+        });
+        caller.insert(name.to_owned(), *value?);
+    }
+    Some(caller)
+}
+
+/// Evaluate the postfix expression of a rule, or run the program of a `STACK WIN` record whose `=`
+/// assigns the value on top of the stack to the variable under it, into `assigned`. Pointers are
+/// `size` bytes. A program leaves no value on the stack.
+fn evaluate(
+    tokens: &[&str],
+    variables: &HashMap<String, u64>,
+    assigned: &mut HashMap<String, u64>,
+    size: usize,
+    read: impl Fn(u64, &mut [u8]) -> bool,
+) -> Option<Option<u64>> {
+    // Variables are looked up when used, as those being assigned may not exist yet.
+    let mut stack: Vec<Result<u64, &str>> = vec![];
+    let pop =
+        |stack: &mut Vec<Result<u64, &str>>, assigned: &HashMap<String, u64>| match stack.pop()? {
+            Ok(value) => Some(value),
+            Err(name) => assigned.get(name).or(variables.get(name)).copied(),
+        };
+    for &token in tokens {
+        let value = match token {
+            "+" | "-" | "*" | "/" | "%" | "@" => {
+                let (b, a) = (pop(&mut stack, assigned)?, pop(&mut stack, assigned)?);
+                match token {
+                    "+" => a.wrapping_add(b),
+                    "-" => a.wrapping_sub(b),
+                    "*" => a.wrapping_mul(b),
+                    "/" => a.checked_div(b)?,
+                    "%" => a.checked_rem(b)?,
+                    // Align down.
+                    _ => a & b.wrapping_neg(),
+                }
+            }
+            "^" => {
+                let mut buf = [0; 8];
+                if !read(pop(&mut stack, assigned)?, &mut buf[..size]) {
+                    return None;
+                }
+                u64::from_le_bytes(buf)
+            }
+            "=" => {
+                let value = pop(&mut stack, assigned)?;
+                let Err(name) = stack.pop()? else {
+                    return None;
+                };
+                assigned.insert(name.to_owned(), value);
+                continue;
+            }
+            _ => match token.strip_prefix("0x") {
+                Some(hex) => u64::from_str_radix(hex, 16).ok()?,
+                None => match token.parse::<i64>() {
+                    Ok(n) => n as u64,
+                    Err(_) => {
+                        stack.push(Err(token));
+                        continue;
+                    }
+                },
+            },
+        };
+        stack.push(Ok(value));
+    }
+    match stack.len() {
+        0 => Some(None),
+        1 => pop(&mut stack, assigned).map(Some),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Built by make.sh next to it.
+    fn fixture(name: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures/breakpad")
+            .join(name)
+    }
+
+    fn sym() -> String {
+        fs::read_to_string(fixture("crash.sym")).unwrap()
+    }
+
+    #[test]
+    fn dump() {
+        let data = fs::read(fixture("crash")).unwrap();
+        let file = dump_syms(&data, "crash").unwrap();
+        assert_eq!(file.to_string(), sym());
+        assert_eq!(
+            store_path(Path::new("store"), &file.name, &file.debug_id),
+            Path::new("store/crash/D86A0803E4E340D37087C26F7D3C9EB90/crash.sym")
+        );
+        assert_eq!(
+            store_path(Path::new("store"), "app.pdb", "ID"),
+            Path::new("store/app.pdb/ID/app.sym")
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let file = parse(&sym()).unwrap();
+        assert_eq!(file.debug_id, "D86A0803E4E340D37087C26F7D3C9EB90");
+        assert_eq!(file.functions.len(), 4);
+        assert_eq!(file.to_string(), sym());
+        assert!(parse("FUNC 1000 10 0 f\n").is_none());
+    }
+
+    #[test]
+    fn cfi() {
+        let file = parse(&sym()).unwrap();
+        let stack: HashMap<u64, u64> = HashMap::from([(0x7f00, 0x1234), (0x7f08, 0x5678)]);
+        let read = |address, buf: &mut [u8]| {
+            let Some(value) = stack.get(&address) else {
+                return false;
+            };
+            buf.copy_from_slice(&value.to_le_bytes());
+            true
+        };
+        let registers = HashMap::from([("$rsp".to_owned(), 0x7f00)]);
+        let unwound = |address| {
+            let fde = file.cfi.iter().rfind(|fde| fde.address <= address).unwrap();
+            let mut caller: Vec<_> = unwind(fde, address, &registers, read).into_iter().collect();
+            caller.sort();
+            caller
+        };
+        // In `mid`, the return address is on top of the stack.
+        assert_eq!(
+            unwound(0x11a5),
+            [(".cfa".to_owned(), 0x7f08), (".ra".to_owned(), 0x1234)]
+        );
+        // In `main`, once it has pushed `rbx`.
+        assert_eq!(
+            unwound(0x1070),
+            [
+                ("$rbx".to_owned(), 0x1234),
+                (".cfa".to_owned(), 0x7f10),
+                (".ra".to_owned(), 0x5678)
+            ]
+        );
+        // `_start` has no return address.
+        assert_eq!(unwound(0x10a0), [(".cfa".to_owned(), 0x7f08)]);
+    }
+
+    /// Written by make.py next to it.
+    fn pdb_fixture(name: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures/pdb")
+            .join(name)
+    }
+
+    #[test]
+    fn pdb() {
+        let data = fs::read(pdb_fixture("crash.pdb")).unwrap();
+        let file = dump_syms(&data, "crash.pdb").unwrap();
+        let sym = fs::read_to_string(pdb_fixture("crash.sym")).unwrap();
+        assert_eq!(file.to_string(), sym);
+        assert_eq!(parse(&sym).unwrap().to_string(), sym);
+    }
+
+    #[test]
+    fn stack_win() {
+        let file = parse(&fs::read_to_string(pdb_fixture("crash.sym")).unwrap()).unwrap();
+        let stack: HashMap<u64, u32> = HashMap::from([
+            (0x7f00, 0x1234),
+            (0x7f08, 0x5678),
+            (0x7f10, 0x7f40),
+            (0x7f14, 0x9abc),
+        ]);
+        let read = |address, buf: &mut [u8]| {
+            let Some(value) = stack.get(&address) else {
+                return false;
+            };
+            buf.copy_from_slice(&value.to_le_bytes());
+            true
+        };
+        let unwound = |address, esp: u64, ebp: u64| {
+            let frame = file
+                .stack_win
+                .iter()
+                .find(|f| f.address == address)
+                .unwrap();
+            let registers = HashMap::from([("$esp".to_owned(), esp), ("$ebp".to_owned(), ebp)]);
+            let caller = unwind_win(frame, &registers, read).unwrap();
+            ["$eip", "$esp", "$ebp"].map(|name| caller[name])
+        };
+        // `main` has a frame pointer, below its return address.
+        assert_eq!(unwound(0x1000, 0x7ef0, 0x7f10), [0x9abc, 0x7f18, 0x7f40]);
+        // `mid` finds its return address above its locals and saved registers.
+        assert_eq!(unwound(0x1040, 0x7f00, 0x7f10), [0x1234, 0x7f04, 0x7f10]);
+        // `_exported`, with its FPO record.
+        assert_eq!(unwound(0x1080, 0x7f00, 0x7f10), [0x5678, 0x7f0c, 0x7f10]);
+    }
+}
@@ -0,0 +1,207 @@
+use std::{fmt, str::FromStr, sync::mpsc, thread, time::Duration};
+
+/// `<addr|symbol>[:<type>] <op> <value>`, comparing an integer in the target's memory.
+#[derive(Clone)]
+pub struct Condition {
+    pub target: String,
+    width: usize,
+    signed: bool,
+    op: Op,
+    value: i128,
+}
+
+#[derive(Clone, Copy)]
+enum Op {
+    Eq,
+    Ne,
+    Le,
+    Ge,
+    Lt,
+    Gt,
+}
+
+impl Condition {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The address of an `0x` prefixed target.
+    pub fn address(&self) -> Option<u64> {
+        let hex = self.target.strip_prefix("0x")?;
+        u64::from_str_radix(hex, 16).ok()
+    }
+
+    /// Evaluate against the little-endian bytes read from the target.
+    pub fn eval(&self, bytes: &[u8]) -> bool {
+        let mut buf = [0; 16];
+        buf[..self.width].copy_from_slice(&bytes[..self.width]);
+        if self.signed && bytes[self.width - 1] & 0x80 != 0 {
+            buf[self.width..].fill(0xff);
+        }
+        let v = i128::from_le_bytes(buf);
+        match self.op {
+            Op::Eq => v == self.value,
+            Op::Ne => v != self.value,
+            Op::Le => v <= self.value,
+            Op::Ge => v >= self.value,
+            Op::Lt => v < self.value,
+            Op::Gt => v > self.value,
+        }
+    }
+}
+
+impl FromStr for Condition {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (i, op, token) = [
+            (Op::Eq, "=="),
+            (Op::Ne, "!="),
+            (Op::Le, "<="),
+            (Op::Ge, ">="),
+            (Op::Lt, "<"),
+            (Op::Gt, ">"),
+        ]
+        .into_iter()
+        .find_map(|(op, token)| s.find(token).map(|i| (i, op, token)))
+        .ok_or("missing comparison operator")?;
+
+        let lhs = s[..i].trim();
+        let (target, (signed, width)) = match lhs.rsplit_once(':') {
+            // Not the `::` of a path like `app::COUNTER`.
+            Some((target, ty)) if !target.ends_with(':') => {
+                let ty = integer(ty).ok_or_else(|| format!("unknown integer type: {ty}"))?;
+                (target, ty)
+            }
+            _ => (lhs, (true, 4)),
+        };
+        if target.is_empty() {
+            return Err("missing symbol or address".to_owned());
+        }
+
+        let rhs = s[i + token.len()..].trim();
+        let (negative, rhs) = match rhs.strip_prefix('-') {
+            Some(rhs) => (true, rhs),
+            None => (false, rhs),
+        };
+        let value = match rhs.strip_prefix("0x") {
+            Some(hex) => i128::from_str_radix(hex, 16),
+            None => rhs.parse(),
+        }
+        .map_err(|e| format!("invalid value: {e}"))?;
+
+        Ok(Self {
+            target: target.to_owned(),
+            width,
+            signed,
+            op,
+            value: if negative { -value } else { value },
+        })
+    }
+}
+
+/// Signedness and width of an integer type.
+fn integer(ty: &str) -> Option<(bool, usize)> {
+    match ty {
+        "i8" => Some((true, 1)),
+        "u8" => Some((false, 1)),
+        "i16" => Some((true, 2)),
+        "u16" => Some((false, 2)),
+        "i32" => Some((true, 4)),
+        "u32" => Some((false, 4)),
+        "i64" => Some((true, 8)),
+        "u64" => Some((false, 8)),
+        _ => None,
+    }
+}
+
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let op = match self.op {
+            Op::Eq => "==",
+            Op::Ne => "!=",
+            Op::Le => "<=",
+            Op::Ge => ">=",
+            Op::Lt => "<",
+            Op::Gt => ">",
+        };
+        let ty = format!("{}{}", if self.signed { 'i' } else { 'u' }, self.width * 8);
+        write!(f, "{}:{ty} {op} {}", self.target, self.value)
+    }
+}
+
+/// Poll the conditions at their resolved addresses, requesting a dump each time one becomes true.
+pub fn poll(
+    conditions: Vec<(u64, Condition)>,
+    interval: Duration,
+    mut read: impl FnMut(u64, &mut [u8]) -> bool + Send + 'static,
+    dump_tx: mpsc::Sender<()>,
+) {
+    thread::spawn(move || {
+        let mut state = vec![false; conditions.len()];
+        let mut buf = [0; 8];
+        loop {
+            for ((address, condition), was_true) in conditions.iter().zip(&mut state) {
+                let is_true = read(*address, &mut buf[..condition.width()]) && condition.eval(&buf);
+                if is_true && !*was_true {
+                    println!("condition met: {condition}");
+                    if dump_tx.send(()).is_err() {
+                        return;
+                    }
+                }
+                *was_true = is_true;
+            }
+            thread::sleep(interval);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> Condition {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn parse_targets() {
+        assert_eq!(
+            parse("counter:u64 > 1000").to_string(),
+            "counter:u64 > 1000"
+        );
+        assert_eq!(parse("counter==-1").to_string(), "counter:i32 == -1");
+        assert_eq!(parse("0x7f00:u8 != 0xff").to_string(), "0x7f00:u8 != 255");
+        assert_eq!(parse("0x7f00 <= 3").address(), Some(0x7f00));
+        assert_eq!(parse("counter >= 3").address(), None);
+        assert_eq!(
+            parse("app::QUEUE_DEPTH > 1000").to_string(),
+            "app::QUEUE_DEPTH:i32 > 1000"
+        );
+        assert_eq!(
+            parse("app::QUEUE_DEPTH:u16 < 10").to_string(),
+            "app::QUEUE_DEPTH:u16 < 10"
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!("counter".parse::<Condition>().is_err());
+        assert!(":u8 > 1".parse::<Condition>().is_err());
+        assert!("counter:u128 > 1".parse::<Condition>().is_err());
+        assert!("app::COUNTER:usize > 1".parse::<Condition>().is_err());
+        assert!("counter > ten".parse::<Condition>().is_err());
+    }
+
+    #[test]
+    fn eval() {
+        assert!(parse("x:u8 > 127").eval(&[0x80]));
+        assert!(parse("x:i8 < 0").eval(&[0x80]));
+        assert!(parse("x:i16 == -2").eval(&[0xfe, 0xff]));
+        assert!(!parse("x:u16 == -2").eval(&[0xfe, 0xff]));
+        assert!(parse("x > 1000").eval(&1001i32.to_le_bytes()));
+        assert!(!parse("x > 1000").eval(&1000i32.to_le_bytes()));
+        assert!(parse("x:u64 >= 0xffffffffffffffff").eval(&[0xff; 8]));
+        assert!(parse("x:i64 != 0").eval(&[0, 0, 0, 0, 0, 0, 0, 0x80]));
+    }
+}
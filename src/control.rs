@@ -0,0 +1,74 @@
+use std::{
+    fs, io, mem,
+    os::unix::{fs::FileTypeExt, net::UnixListener},
+    path::{Path, PathBuf},
+    ptr,
+    sync::mpsc,
+    thread,
+};
+
+/// Forward every `SIGUSR1` sent to pmortem as a dump request.
+///
+/// Must be called before any other thread is spawned, so that the signal stays
+/// blocked everywhere except in `sigwait`.
+pub fn signal(tx: mpsc::Sender<()>) {
+    unsafe {
+        let mut set = mem::zeroed();
+        libc::sigemptyset(&mut set);
+        libc::sigaddset(&mut set, libc::SIGUSR1);
+        let r = libc::pthread_sigmask(libc::SIG_BLOCK, &set, ptr::null_mut());
+        assert_eq!(r, 0);
+
+        thread::spawn(move || loop {
+            let mut sig = 0;
+            if libc::sigwait(&set, &mut sig) == 0 && tx.send(()).is_err() {
+                break;
+            }
+        });
+    }
+}
+
+/// Unblock the `SIGUSR1` blocked by `signal`, in a child about to exec, which would inherit it.
+pub fn unblock() -> io::Result<()> {
+    unsafe {
+        let mut set = mem::zeroed();
+        libc::sigemptyset(&mut set);
+        libc::sigaddset(&mut set, libc::SIGUSR1);
+        match libc::sigprocmask(libc::SIG_UNBLOCK, &set, ptr::null_mut()) {
+            0 => Ok(()),
+            _ => Err(io::Error::last_os_error()),
+        }
+    }
+}
+
+/// Listen on a Unix domain socket, every accepted connection requests a dump.
+pub fn listen(path: &Path, tx: mpsc::Sender<()>) -> io::Result<Listener> {
+    if fs::metadata(path)
+        .map(|m| m.file_type().is_socket())
+        .unwrap_or_default()
+    {
+        fs::remove_file(path)?;
+    }
+    let listener = UnixListener::bind(path)?;
+
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            if stream.is_ok() && tx.send(()).is_err() {
+                break;
+            }
+        }
+    });
+    Ok(Listener {
+        path: path.to_owned(),
+    })
+}
+
+pub struct Listener {
+    path: PathBuf,
+}
+
+impl Drop for Listener {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
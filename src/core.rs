@@ -0,0 +1,718 @@
+use crate::{
+    elf::{self, Elf, Elf64_Ehdr, Elf64_Nhdr, Elf64_Phdr},
+    minidump::{self, ContextAmd64, MemoryDescriptor, Minidump, Writer},
+    reason::{self, Reason},
+};
+use std::{
+    io::{self, Read, Write},
+    mem,
+};
+
+/// Stack kept below the stack pointer, for leaf functions using the red zone.
+const RED_ZONE: u64 = 128;
+const MAX_STACK: u64 = 1024 * 1024;
+/// Kept from the start of each mapped file, enough for its ELF headers.
+pub const HEADER_SIZE: u64 = 4096;
+const PAGE_SIZE: u64 = 4096;
+
+/// `user_regs_struct` of x86-64.
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct UserRegs {
+    pub r15: u64,
+    pub r14: u64,
+    pub r13: u64,
+    pub r12: u64,
+    pub rbp: u64,
+    pub rbx: u64,
+    pub r11: u64,
+    pub r10: u64,
+    pub r9: u64,
+    pub r8: u64,
+    pub rax: u64,
+    pub rcx: u64,
+    pub rdx: u64,
+    pub rsi: u64,
+    pub rdi: u64,
+    pub orig_rax: u64,
+    pub rip: u64,
+    pub cs: u64,
+    pub eflags: u64,
+    pub rsp: u64,
+    pub ss: u64,
+    pub fs_base: u64,
+    pub gs_base: u64,
+    pub ds: u64,
+    pub es: u64,
+    pub fs: u64,
+    pub gs: u64,
+}
+
+/// `elf_prstatus` of x86-64.
+#[allow(dead_code)]
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+struct PrStatus {
+    si_signo: i32,
+    si_code: i32,
+    si_errno: i32,
+    pr_cursig: i16,
+    _pad: i16,
+    pr_sigpend: u64,
+    pr_sighold: u64,
+    pr_pid: i32,
+    pr_ppid: i32,
+    pr_pgrp: i32,
+    pr_sid: i32,
+    pr_times: [u64; 8],
+    pr_reg: UserRegs,
+    pr_fpvalid: i32,
+    _pad2: i32,
+}
+
+/// `elf_prpsinfo` of x86-64.
+#[allow(dead_code)]
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct PrPsInfo {
+    pr_state: i8,
+    pr_sname: u8,
+    pr_zomb: i8,
+    pr_nice: i8,
+    _pad: u32,
+    pr_flag: u64,
+    pr_uid: u32,
+    pr_gid: u32,
+    pr_pid: i32,
+    pr_ppid: i32,
+    pr_pgrp: i32,
+    pr_sid: i32,
+    pr_fname: [u8; 16],
+    pr_psargs: [u8; 80],
+}
+
+pub struct Thread {
+    pub tid: i32,
+    pub regs: UserRegs,
+    pub fxsave: [u8; 512],
+}
+
+pub struct Signal {
+    pub signo: i32,
+    pub code: i32,
+    pub address: u64,
+}
+
+impl Signal {
+    pub fn reason(&self) -> Reason {
+        reason::signal(self.signo, self.code, self.address)
+    }
+}
+
+/// A file mapping of `NT_FILE`.
+pub struct Mapping {
+    pub start: u64,
+    pub end: u64,
+    pub offset: u64,
+    pub path: String,
+}
+
+/// An x86-64 Linux ELF core, read as a stream and holding only the memory a minidump needs: the
+/// stack of each thread and the headers of each mapped file.
+pub struct Core {
+    pub pid: i32,
+    /// The command line, truncated to 80 bytes.
+    pub args: String,
+    /// The dumping thread first.
+    pub threads: Vec<Thread>,
+    pub signal: Option<Signal>,
+    pub auxv: Vec<u8>,
+    pub mappings: Vec<Mapping>,
+    pub memory: Vec<(u64, Vec<u8>)>,
+}
+
+impl Core {
+    pub fn read(mut r: impl Read) -> io::Result<Self> {
+        let mut buf = vec![0; mem::size_of::<Elf64_Ehdr>()];
+        r.read_exact(&mut buf)?;
+        let header = elf::Elf::parse(&buf)
+            .map(|elf| elf.header)
+            .filter(|h| h.e_type == elf::ET_CORE && h.e_machine == elf::EM_X86_64)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "not an x86-64 ELF core"))?;
+        let mut pos = buf.len() as u64;
+
+        let gap = header.e_phoff.checked_sub(pos).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                "program headers overlap the ELF header",
+            )
+        })?;
+        skip(&mut r, gap)?;
+        let mut phdrs = vec![0; header.e_phnum as usize * header.e_phentsize as usize];
+        r.read_exact(&mut phdrs)?;
+        pos = header.e_phoff + phdrs.len() as u64;
+        let mut phdrs: Vec<Elf64_Phdr> = (0..header.e_phnum as usize)
+            .filter_map(|i| elf::read(&phdrs, i * header.e_phentsize as usize))
+            .collect();
+        phdrs.sort_by_key(|ph| ph.p_offset);
+
+        let mut core = Self {
+            pid: 0,
+            args: String::new(),
+            threads: vec![],
+            signal: None,
+            auxv: vec![],
+            mappings: vec![],
+            memory: vec![],
+        };
+        // The kernel writes the notes before the memory, so the stacks are known by then.
+        for ph in phdrs.iter().filter(|ph| ph.p_filesz > 0) {
+            match ph.p_type {
+                elf::PT_NOTE if ph.p_offset >= pos => {
+                    skip(&mut r, ph.p_offset - pos)?;
+                    let mut notes = vec![0; ph.p_filesz as usize];
+                    r.read_exact(&mut notes)?;
+                    pos = ph.p_offset + ph.p_filesz;
+                    core.add_notes(&notes);
+                }
+                elf::PT_LOAD => {
+                    let mut ranges = core.wanted(ph);
+                    ranges.sort();
+                    for (start, end) in ranges {
+                        let offset = ph.p_offset + start - ph.p_vaddr;
+                        if offset < pos {
+                            continue;
+                        }
+                        skip(&mut r, offset - pos)?;
+                        let mut data = vec![0; (end - start) as usize];
+                        r.read_exact(&mut data)?;
+                        pos = offset + data.len() as u64;
+                        core.memory.push((start, data));
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(core)
+    }
+
+    fn add_notes(&mut self, data: &[u8]) {
+        for note in elf::notes(data) {
+            match (note.name, note.kind) {
+                ("CORE", elf::NT_PRSTATUS) => {
+                    if let Some(status) = elf::read::<PrStatus>(note.desc, 0) {
+                        self.threads.push(Thread {
+                            tid: status.pr_pid,
+                            regs: status.pr_reg,
+                            fxsave: [0; 512],
+                        });
+                    }
+                }
+                // Follows the `NT_PRSTATUS` of its thread.
+                ("CORE", elf::NT_FPREGSET) => {
+                    if let (Some(thread), Some(fxsave)) =
+                        (self.threads.last_mut(), elf::read(note.desc, 0))
+                    {
+                        thread.fxsave = fxsave;
+                    }
+                }
+                ("CORE", elf::NT_PRPSINFO) => {
+                    if let Some(info) = elf::read::<PrPsInfo>(note.desc, 0) {
+                        self.pid = info.pr_pid;
+                        self.args = elf::c_str(&info.pr_psargs, 0)
+                            .unwrap_or_default()
+                            .to_owned();
+                    }
+                }
+                ("CORE", elf::NT_SIGINFO) => {
+                    let field = |offset| elf::read::<i32>(note.desc, offset).unwrap_or_default();
+                    self.signal = Some(Signal {
+                        signo: field(0),
+                        code: field(8),
+                        address: elf::read(note.desc, 16).unwrap_or_default(),
+                    });
+                }
+                ("CORE", elf::NT_AUXV) => self.auxv = note.desc.to_vec(),
+                ("CORE", elf::NT_FILE) => {
+                    let word = |i: usize| elf::read::<u64>(note.desc, i * 8).unwrap_or_default();
+                    let (count, page_size) = (word(0) as usize, word(1));
+                    let names = note.desc.get((2 + count * 3) * 8..).unwrap_or_default();
+                    let names = names.split(|&c| c == 0).map(String::from_utf8_lossy);
+                    for (i, path) in names.take(count).enumerate() {
+                        self.mappings.push(Mapping {
+                            start: word(2 + i * 3),
+                            end: word(3 + i * 3),
+                            offset: word(4 + i * 3) * page_size,
+                            path: path.into_owned(),
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Ranges of a loadable segment to keep.
+    fn wanted(&self, ph: &Elf64_Phdr) -> Vec<(u64, u64)> {
+        let (start, end) = (ph.p_vaddr, ph.p_vaddr + ph.p_filesz);
+        let stacks = self.threads.iter().filter_map(|thread| {
+            let sp = thread.regs.rsp;
+            // An overflowed stack has its pointer in the guard gap, right below the segment.
+            (start <= sp + PAGE_SIZE && sp < end).then(|| {
+                let sp = sp.saturating_sub(RED_ZONE).max(start);
+                (sp, end.min(sp + MAX_STACK))
+            })
+        });
+        let headers = self
+            .mappings
+            .iter()
+            .filter(|m| m.offset == 0 && m.start == start)
+            .map(|_| (start, end.min(start + HEADER_SIZE)));
+        let mut ranges: Vec<(u64, u64)> = vec![];
+        for (start, end) in stacks.chain(headers) {
+            match ranges.iter_mut().find(|r| r.0 <= end && start <= r.1) {
+                Some(r) => *r = (r.0.min(start), r.1.max(end)),
+                None => ranges.push((start, end)),
+            }
+        }
+        ranges
+    }
+
+    /// Convert to a minidump, the dumping thread as the exception thread, along with `extra`
+    /// streams.
+    pub fn minidump(
+        &self,
+        time: u32,
+        cpus: u8,
+        os_version: &str,
+        extra: &[(u32, Vec<u8>)],
+    ) -> Vec<u8> {
+        let mut writer = Writer::new();
+
+        let memory: Vec<MemoryDescriptor> = self
+            .memory
+            .iter()
+            .map(|(start, data)| MemoryDescriptor {
+                start: *start,
+                memory: writer.write(data),
+            })
+            .collect();
+
+        let contexts: Vec<_> = self
+            .threads
+            .iter()
+            .map(|thread| writer.write(&context(thread).to_bytes()))
+            .collect();
+        let mut threads = (self.threads.len() as u32).to_le_bytes().to_vec();
+        for (thread, context) in self.threads.iter().zip(&contexts) {
+            let sp = thread.regs.rsp;
+            let stack = memory
+                .iter()
+                .find(|m| m.start <= sp && sp < m.start + m.memory.size as u64)
+                .copied()
+                .unwrap_or_default();
+            let thread = minidump::Thread {
+                id: thread.tid as u32,
+                stack,
+                context: *context,
+            };
+            threads.extend(thread.to_bytes());
+        }
+        writer.stream(minidump::THREAD_LIST_STREAM, &threads);
+
+        let mut modules: Vec<minidump::Module> = vec![];
+        for m in &self.mappings {
+            if m.offset == 0 {
+                let name_rva = writer.string(&m.path);
+                // The headers in the core are those of the image that was loaded, the file may
+                // have been replaced since.
+                let headers = self
+                    .memory
+                    .iter()
+                    .find(|(start, _)| *start == m.start)
+                    .map_or_else(|| elf::headers(&m.path), |(_, data)| data.clone());
+                let cv_record = Elf::parse(&headers)
+                    .and_then(|image| image.build_id())
+                    .map(|build_id| {
+                        let mut cv = minidump::CV_SIGNATURE_ELF.to_le_bytes().to_vec();
+                        cv.extend_from_slice(build_id);
+                        writer.write(&cv)
+                    })
+                    .unwrap_or_default();
+                modules.push(minidump::Module {
+                    base: m.start,
+                    size: (m.end - m.start) as u32,
+                    time_date_stamp: 0,
+                    name_rva,
+                    file_version: None,
+                    cv_record,
+                });
+            } else if let Some(module) = modules.last_mut() {
+                // The other mappings of a file follow its first one.
+                if m.start > module.base {
+                    module.size = module.size.max((m.end - module.base) as u32);
+                }
+            }
+        }
+        let mut list = (modules.len() as u32).to_le_bytes().to_vec();
+        list.extend(modules.iter().flat_map(minidump::Module::to_bytes));
+        writer.stream(minidump::MODULE_LIST_STREAM, &list);
+
+        let mut list = (memory.len() as u32).to_le_bytes().to_vec();
+        list.extend(memory.iter().copied().flat_map(MemoryDescriptor::to_bytes));
+        writer.stream(minidump::MEMORY_LIST_STREAM, &list);
+
+        if let (Some(signal), Some(thread)) = (&self.signal, self.threads.first()) {
+            let exception = minidump::Exception {
+                thread_id: thread.tid as u32,
+                code: signal.signo as u32,
+                flags: signal.code as u32,
+                address: signal.address,
+                parameters: Vec::new(),
+                context: contexts[0],
+            };
+            writer.stream(minidump::EXCEPTION_STREAM, &exception.to_bytes());
+        }
+
+        let csd_version_rva = writer.string(os_version);
+        let info = minidump::SystemInfo {
+            architecture: minidump::PROCESSOR_ARCHITECTURE_AMD64,
+            cpus,
+            platform: minidump::PLATFORM_LINUX,
+            csd_version_rva,
+        };
+        writer.stream(minidump::SYSTEM_INFO_STREAM, &info.to_bytes());
+
+        if !self.auxv.is_empty() {
+            writer.stream(minidump::LINUX_AUXV, &self.auxv);
+        }
+        for (kind, data) in extra {
+            writer.stream(*kind, data);
+        }
+        writer.finish(time)
+    }
+
+    /// Read back a Linux x86-64 minidump, the exception thread first.
+    pub fn from_minidump(dump: &Minidump) -> Option<Self> {
+        let info = dump.system_info()?;
+        if info.platform != minidump::PLATFORM_LINUX
+            || info.architecture != minidump::PROCESSOR_ARCHITECTURE_AMD64
+        {
+            return None;
+        }
+        let exception = dump.exception();
+
+        let mut threads: Vec<Thread> = dump
+            .threads()
+            .iter()
+            .filter_map(|thread| {
+                let context = ContextAmd64::from_bytes(dump.get(thread.context)?)?;
+                Some(Thread {
+                    tid: thread.id as i32,
+                    regs: regs(&context),
+                    fxsave: context.fxsave,
+                })
+            })
+            .collect();
+        if let Some(exception) = &exception {
+            if let Some(i) = threads
+                .iter()
+                .position(|t| t.tid as u32 == exception.thread_id)
+            {
+                threads[..=i].rotate_right(1);
+            }
+        }
+
+        let stream = |kind| String::from_utf8_lossy(dump.stream(kind).unwrap_or_default());
+        let pid = stream(minidump::LINUX_PROC_STATUS)
+            .lines()
+            .find_map(|line| line.strip_prefix("Pid:")?.trim().parse().ok())
+            .or(threads.first().map(|t| t.tid))
+            .unwrap_or_default();
+        let args = stream(minidump::LINUX_CMD_LINE)
+            .trim_end_matches('\0')
+            .replace('\0', " ");
+
+        let maps = stream(minidump::LINUX_MAPS);
+        let mut mappings: Vec<Mapping> = maps
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.split_whitespace();
+                let (start, end) = fields.next()?.split_once('-')?;
+                let offset = fields.nth(1)?;
+                let path = fields.nth(2).filter(|path| path.starts_with('/'))?;
+                Some(Mapping {
+                    start: u64::from_str_radix(start, 16).ok()?,
+                    end: u64::from_str_radix(end, 16).ok()?,
+                    offset: u64::from_str_radix(offset, 16).ok()?,
+                    path: path.to_owned(),
+                })
+            })
+            .collect();
+        if mappings.is_empty() {
+            mappings = dump
+                .modules()
+                .iter()
+                .map(|module| Mapping {
+                    start: module.base,
+                    end: module.base + module.size as u64,
+                    offset: 0,
+                    path: dump.string(module.name_rva).unwrap_or_default(),
+                })
+                .collect();
+        }
+
+        let mut memory: Vec<(u64, Vec<u8>)> = dump
+            .memory()
+            .into_iter()
+            .map(|(start, data)| (start, data.to_vec()))
+            .collect();
+        memory.sort_by_key(|(start, _)| *start);
+
+        Some(Self {
+            pid,
+            args,
+            threads,
+            signal: exception.map(|e| Signal {
+                signo: e.code as i32,
+                code: e.flags as i32,
+                address: e.address,
+            }),
+            auxv: dump
+                .stream(minidump::LINUX_AUXV)
+                .unwrap_or_default()
+                .to_vec(),
+            mappings,
+            memory,
+        })
+    }
+
+    /// Write as an ELF core, the notes first and then a loadable segment per memory range.
+    pub fn write(&self, mut w: impl Write) -> io::Result<()> {
+        let mut notes = vec![];
+        let mut info: PrPsInfo = unsafe { mem::zeroed() };
+        info.pr_sname = b'R';
+        info.pr_pid = self.pid;
+        let fname = self.args.split(' ').next().unwrap_or_default();
+        let fname = fname.rsplit('/').next().unwrap_or_default().as_bytes();
+        info.pr_fname[..fname.len().min(15)].copy_from_slice(&fname[..fname.len().min(15)]);
+        let args = self.args.as_bytes();
+        info.pr_psargs[..args.len().min(79)].copy_from_slice(&args[..args.len().min(79)]);
+        note(&mut notes, elf::NT_PRPSINFO, elf::bytes(&info));
+
+        if let Some(signal) = &self.signal {
+            let mut siginfo = [0; 128];
+            siginfo[0..4].copy_from_slice(&signal.signo.to_le_bytes());
+            siginfo[8..12].copy_from_slice(&signal.code.to_le_bytes());
+            siginfo[16..24].copy_from_slice(&signal.address.to_le_bytes());
+            note(&mut notes, elf::NT_SIGINFO, &siginfo);
+        }
+        if !self.auxv.is_empty() {
+            note(&mut notes, elf::NT_AUXV, &self.auxv);
+        }
+        let mut files = [self.mappings.len() as u64, PAGE_SIZE]
+            .into_iter()
+            .chain(
+                self.mappings
+                    .iter()
+                    .flat_map(|m| [m.start, m.end, m.offset / PAGE_SIZE]),
+            )
+            .flat_map(u64::to_le_bytes)
+            .collect::<Vec<_>>();
+        for m in &self.mappings {
+            files.extend(m.path.as_bytes());
+            files.push(0);
+        }
+        note(&mut notes, elf::NT_FILE, &files);
+
+        for (i, thread) in self.threads.iter().enumerate() {
+            let signo = match (i, &self.signal) {
+                (0, Some(signal)) => signal.signo,
+                _ => 0,
+            };
+            let status = PrStatus {
+                si_signo: signo,
+                pr_cursig: signo as i16,
+                pr_pid: thread.tid,
+                pr_reg: thread.regs,
+                pr_fpvalid: 1,
+                ..Default::default()
+            };
+            note(&mut notes, elf::NT_PRSTATUS, elf::bytes(&status));
+            note(&mut notes, elf::NT_FPREGSET, &thread.fxsave);
+        }
+
+        let phnum = 1 + self.memory.len();
+        let notes_offset =
+            (mem::size_of::<Elf64_Ehdr>() + phnum * mem::size_of::<Elf64_Phdr>()) as u64;
+        let memory_offset = (notes_offset + notes.len() as u64).next_multiple_of(PAGE_SIZE);
+        let mut header = Elf64_Ehdr {
+            e_type: elf::ET_CORE,
+            e_machine: elf::EM_X86_64,
+            e_version: 1,
+            e_phoff: mem::size_of::<Elf64_Ehdr>() as u64,
+            e_ehsize: mem::size_of::<Elf64_Ehdr>() as u16,
+            e_phentsize: mem::size_of::<Elf64_Phdr>() as u16,
+            e_phnum: phnum as u16,
+            ..Default::default()
+        };
+        header.e_ident[..7].copy_from_slice(b"\x7fELF\x02\x01\x01");
+        w.write_all(elf::bytes(&header))?;
+
+        let mut phdrs = vec![Elf64_Phdr {
+            p_type: elf::PT_NOTE,
+            p_offset: notes_offset,
+            p_filesz: notes.len() as u64,
+            p_align: 4,
+            ..Default::default()
+        }];
+        let mut offset = memory_offset;
+        for (start, data) in &self.memory {
+            phdrs.push(Elf64_Phdr {
+                p_type: elf::PT_LOAD,
+                p_flags: elf::PF_R | elf::PF_W,
+                p_offset: offset,
+                p_vaddr: *start,
+                p_filesz: data.len() as u64,
+                p_memsz: data.len() as u64,
+                p_align: 1,
+                ..Default::default()
+            });
+            offset += data.len() as u64;
+        }
+        for ph in &phdrs {
+            w.write_all(elf::bytes(ph))?;
+        }
+        w.write_all(&notes)?;
+        w.write_all(&vec![
+            0;
+            (memory_offset - notes_offset) as usize - notes.len()
+        ])?;
+        for (_, data) in &self.memory {
+            w.write_all(data)?;
+        }
+        Ok(())
+    }
+}
+
+fn context(thread: &Thread) -> ContextAmd64 {
+    let r = &thread.regs;
+    ContextAmd64 {
+        gpr: [
+            r.rax, r.rcx, r.rdx, r.rbx, r.rsp, r.rbp, r.rsi, r.rdi, r.r8, r.r9, r.r10, r.r11,
+            r.r12, r.r13, r.r14, r.r15,
+        ],
+        rip: r.rip,
+        eflags: r.eflags as u32,
+        segments: [r.cs, r.ds, r.es, r.fs, r.gs, r.ss].map(|s| s as u16),
+        fxsave: thread.fxsave,
+    }
+}
+
+fn regs(context: &ContextAmd64) -> UserRegs {
+    let [rax, rcx, rdx, rbx, rsp, rbp, rsi, rdi, r8, r9, r10, r11, r12, r13, r14, r15] =
+        context.gpr;
+    let [cs, ds, es, fs, gs, ss] = context.segments.map(u64::from);
+    UserRegs {
+        r15,
+        r14,
+        r13,
+        r12,
+        rbp,
+        rbx,
+        r11,
+        r10,
+        r9,
+        r8,
+        rax,
+        rcx,
+        rdx,
+        rsi,
+        rdi,
+        orig_rax: u64::MAX,
+        rip: context.rip,
+        cs,
+        eflags: context.eflags as u64,
+        rsp,
+        ss,
+        fs_base: 0,
+        gs_base: 0,
+        ds,
+        es,
+        fs,
+        gs,
+    }
+}
+
+/// Append a `CORE` note.
+fn note(notes: &mut Vec<u8>, kind: u32, desc: &[u8]) {
+    let nhdr = Elf64_Nhdr {
+        n_namesz: 5,
+        n_descsz: desc.len() as u32,
+        n_type: kind,
+    };
+    notes.extend(elf::bytes(&nhdr));
+    notes.extend(b"CORE\0\0\0\0");
+    notes.extend(desc);
+    notes.resize(notes.len().next_multiple_of(4), 0);
+}
+
+fn skip(r: &mut impl Read, n: u64) -> io::Result<()> {
+    if io::copy(&mut r.take(n), &mut io::sink())? < n {
+        return Err(io::ErrorKind::UnexpectedEof.into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn core() -> Vec<u8> {
+        let core = Core {
+            pid: 42,
+            args: "crash --now".to_owned(),
+            threads: vec![Thread {
+                tid: 42,
+                regs: UserRegs {
+                    rsp: 0x7000_1000,
+                    ..Default::default()
+                },
+                fxsave: [0; 512],
+            }],
+            signal: Some(Signal {
+                signo: 11,
+                code: 1,
+                address: 8,
+            }),
+            auxv: vec![],
+            mappings: vec![],
+            memory: vec![(0x7000_0000, vec![0xaa; 0x2000])],
+        };
+        let mut data = vec![];
+        core.write(&mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn read() {
+        let core = Core::read(&core()[..]).unwrap();
+        assert_eq!(core.pid, 42);
+        assert_eq!(core.args, "crash --now");
+        assert_eq!(core.threads[0].tid, 42);
+        assert_eq!(core.signal.unwrap().address, 8);
+        assert_eq!(core.memory[0].0, 0x7000_1000 - RED_ZONE);
+    }
+
+    #[test]
+    fn malformed() {
+        let mut data = core();
+        let stack = data.len() as u64 - 0x2000;
+        let truncated = Core::read(&data[..stack as usize - 1]).err().unwrap();
+        assert_eq!(truncated.kind(), io::ErrorKind::UnexpectedEof);
+
+        data[32..40].copy_from_slice(&8u64.to_le_bytes());
+        let overlapping = Core::read(&data[..]).err().unwrap();
+        assert_eq!(overlapping.kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,168 @@
+use crate::{analysis, minidump::Minidump, Diff, Growth, Snapshot, SymbolOptions};
+use std::{collections::BTreeMap, fs, path::Path};
+
+/// A snapshot from a minidump, or from the JSON report of `pmortem analyze`.
+pub fn load(path: &Path, symbols: &SymbolOptions) -> Result<Snapshot, String> {
+    let data = fs::read(path).map_err(|e| format!("{}: {e}", path.display()))?;
+    match Minidump::parse(&data) {
+        Some(dump) => Ok(analysis::snapshot(&dump, symbols)),
+        None => serde_json::from_slice(&data)
+            .map_err(|e| format!("{}: neither a minidump nor a snapshot: {e}", path.display())),
+    }
+}
+
+pub fn diff(before: &Snapshot, after: &Snapshot) -> Diff {
+    let mut diff = Diff {
+        threads_appeared: vec![],
+        threads_disappeared: vec![],
+        threads_changed: vec![],
+        threads_unchanged: vec![],
+        modules_loaded: vec![],
+        modules_unloaded: vec![],
+        regions: vec![],
+    };
+
+    for thread in &after.threads {
+        let Some(old) = before.threads.iter().find(|t| t.id == thread.id) else {
+            diff.threads_appeared.push(thread.id);
+            continue;
+        };
+        let same = old.backtrace.len() == thread.backtrace.len()
+            && old
+                .backtrace
+                .iter()
+                .zip(&thread.backtrace)
+                .all(|(a, b)| a.address == b.address);
+        if same {
+            diff.threads_unchanged.push(thread.id);
+        } else {
+            diff.threads_changed.push(thread.id);
+        }
+    }
+    diff.threads_disappeared = before
+        .threads
+        .iter()
+        .filter(|t| !after.threads.iter().any(|u| u.id == t.id))
+        .map(|t| t.id)
+        .collect();
+
+    // A module reloaded at another address was unloaded, then loaded again.
+    let loaded = |snapshot: &Snapshot, module: &crate::Module| {
+        snapshot
+            .modules
+            .iter()
+            .any(|m| m.path == module.path && m.load_address == module.load_address)
+    };
+    diff.modules_loaded = after
+        .modules
+        .iter()
+        .filter(|m| !loaded(before, m))
+        .map(|m| m.path.clone())
+        .collect();
+    diff.modules_unloaded = before
+        .modules
+        .iter()
+        .filter(|m| !loaded(after, m))
+        .map(|m| m.path.clone())
+        .collect();
+
+    let mut sizes: BTreeMap<&str, (u64, u64)> = BTreeMap::new();
+    for region in &before.regions {
+        sizes.entry(region_name(&region.path)).or_default().0 += region.end - region.start;
+    }
+    for region in &after.regions {
+        sizes.entry(region_name(&region.path)).or_default().1 += region.end - region.start;
+    }
+    diff.regions = sizes
+        .into_iter()
+        .filter(|(_, (before, after))| before != after)
+        .map(|(path, (before, after))| Growth {
+            path: path.to_owned(),
+            before,
+            after,
+            growth: after as i64 - before as i64,
+        })
+        .collect();
+    diff.regions.sort_by_key(|g| -g.growth);
+    diff
+}
+
+fn region_name(path: &str) -> &str {
+    if path.is_empty() {
+        "[anon]"
+    } else {
+        path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(threads: &str, modules: &str, regions: &str) -> Snapshot {
+        serde_json::from_str(&format!(
+            r#"{{"threads": [{threads}], "modules": [{modules}], "regions": [{regions}]}}"#
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn changes() {
+        let frame = |address| format!(r#"{{"depth": 0, "address": "{address}"}}"#);
+        let thread = |id, frames: &[String]| {
+            format!(r#"{{"id": {id}, "backtrace": [{}]}}"#, frames.join(","))
+        };
+        let module =
+            |path, address| format!(r#"{{"path": "{path}", "load_address": "{address}"}}"#);
+        let region = |start, end, path| {
+            format!(r#"{{"start": "{start}", "end": "{end}", "perms": "rw-p", "path": "{path}"}}"#)
+        };
+        let before = snapshot(
+            &[
+                thread(1, &[frame("0x1000")]),
+                thread(2, &[frame("0x2000")]),
+                thread(3, &[frame("0x3000")]),
+            ]
+            .join(","),
+            &[module("a", "0x10000"), module("b", "0x20000")].join(","),
+            &[
+                region("0x1000", "0x2000", "[heap]"),
+                region("0x8000", "0x9000", ""),
+            ]
+            .join(","),
+        );
+        let after = snapshot(
+            &[
+                thread(1, &[frame("0x1000")]),
+                thread(2, &[frame("0x2004")]),
+                thread(4, &[frame("0x4000")]),
+            ]
+            .join(","),
+            &[module("a", "0x10000"), module("b", "0x30000")].join(","),
+            &[
+                region("0x1000", "0x4000", "[heap]"),
+                region("0x8000", "0x9000", ""),
+                region("0xa000", "0xb000", "c"),
+            ]
+            .join(","),
+        );
+
+        let diff = diff(&before, &after);
+        assert_eq!(diff.threads_appeared, [4]);
+        assert_eq!(diff.threads_disappeared, [3]);
+        assert_eq!(diff.threads_changed, [2]);
+        assert_eq!(diff.threads_unchanged, [1]);
+        // Reloaded elsewhere.
+        assert_eq!(diff.modules_loaded, ["b"]);
+        assert_eq!(diff.modules_unloaded, ["b"]);
+        let regions: Vec<(&str, u64, u64, i64)> = diff
+            .regions
+            .iter()
+            .map(|g| (g.path.as_str(), g.before, g.after, g.growth))
+            .collect();
+        assert_eq!(
+            regions,
+            [("[heap]", 0x1000, 0x3000, 0x2000), ("c", 0, 0x1000, 0x1000)]
+        );
+    }
+}
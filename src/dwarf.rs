@@ -0,0 +1,754 @@
+use std::collections::{BTreeMap, HashMap};
+
+pub const DW_LNS_COPY: u8 = 1;
+pub const DW_LNS_ADVANCE_PC: u8 = 2;
+pub const DW_LNS_ADVANCE_LINE: u8 = 3;
+pub const DW_LNS_SET_FILE: u8 = 4;
+pub const DW_LNS_CONST_ADD_PC: u8 = 8;
+pub const DW_LNS_FIXED_ADVANCE_PC: u8 = 9;
+pub const DW_LNE_END_SEQUENCE: u8 = 1;
+pub const DW_LNE_SET_ADDRESS: u8 = 2;
+pub const DW_LNE_DEFINE_FILE: u8 = 3;
+pub const DW_LNCT_PATH: u64 = 1;
+pub const DW_LNCT_DIRECTORY_INDEX: u64 = 2;
+pub const DW_FORM_BLOCK: u64 = 0x09;
+pub const DW_FORM_DATA1: u64 = 0x0b;
+pub const DW_FORM_DATA2: u64 = 0x05;
+pub const DW_FORM_DATA4: u64 = 0x06;
+pub const DW_FORM_DATA8: u64 = 0x07;
+pub const DW_FORM_DATA16: u64 = 0x1e;
+pub const DW_FORM_STRING: u64 = 0x08;
+pub const DW_FORM_STRP: u64 = 0x0e;
+pub const DW_FORM_UDATA: u64 = 0x0f;
+pub const DW_FORM_LINE_STRP: u64 = 0x1f;
+pub const DW_CFA_ADVANCE_LOC: u8 = 0x40;
+pub const DW_CFA_OFFSET: u8 = 0x80;
+pub const DW_CFA_RESTORE: u8 = 0xc0;
+pub const DW_CFA_NOP: u8 = 0x00;
+pub const DW_CFA_SET_LOC: u8 = 0x01;
+pub const DW_CFA_ADVANCE_LOC1: u8 = 0x02;
+pub const DW_CFA_ADVANCE_LOC2: u8 = 0x03;
+pub const DW_CFA_ADVANCE_LOC4: u8 = 0x04;
+pub const DW_CFA_OFFSET_EXTENDED: u8 = 0x05;
+pub const DW_CFA_RESTORE_EXTENDED: u8 = 0x06;
+pub const DW_CFA_UNDEFINED: u8 = 0x07;
+pub const DW_CFA_SAME_VALUE: u8 = 0x08;
+pub const DW_CFA_REGISTER: u8 = 0x09;
+pub const DW_CFA_REMEMBER_STATE: u8 = 0x0a;
+pub const DW_CFA_RESTORE_STATE: u8 = 0x0b;
+pub const DW_CFA_DEF_CFA: u8 = 0x0c;
+pub const DW_CFA_DEF_CFA_REGISTER: u8 = 0x0d;
+pub const DW_CFA_DEF_CFA_OFFSET: u8 = 0x0e;
+pub const DW_CFA_DEF_CFA_EXPRESSION: u8 = 0x0f;
+pub const DW_CFA_EXPRESSION: u8 = 0x10;
+pub const DW_CFA_OFFSET_EXTENDED_SF: u8 = 0x11;
+pub const DW_CFA_DEF_CFA_SF: u8 = 0x12;
+pub const DW_CFA_DEF_CFA_OFFSET_SF: u8 = 0x13;
+pub const DW_CFA_VAL_OFFSET: u8 = 0x14;
+pub const DW_CFA_VAL_OFFSET_SF: u8 = 0x15;
+pub const DW_CFA_VAL_EXPRESSION: u8 = 0x16;
+pub const DW_CFA_GNU_ARGS_SIZE: u8 = 0x2e;
+pub const DW_CFA_GNU_NEGATIVE_OFFSET_EXTENDED: u8 = 0x2f;
+pub const DW_EH_PE_OMIT: u8 = 0xff;
+pub const DW_EH_PE_PCREL: u8 = 0x10;
+
+/// Rows of the line number programs of `.debug_line`, as Breakpad line records.
+#[derive(Default)]
+pub struct LineTable {
+    pub files: Vec<String>,
+    pub lines: Vec<Line>,
+}
+
+#[derive(Clone)]
+pub struct Line {
+    pub address: u64,
+    pub size: u64,
+    pub line: u32,
+    /// Index in `files`.
+    pub file: usize,
+}
+
+/// The unwind rules of a function, from an FDE of `.eh_frame` or `.debug_frame`.
+pub struct Fde {
+    pub address: u64,
+    pub size: u64,
+    /// Breakpad rules for all the registers at `address`, then for those that change, where they
+    /// change.
+    pub rules: Vec<(u64, String)>,
+}
+
+/// The sections of the DWARF information of an image.
+#[derive(Default)]
+pub struct Sections<'a> {
+    pub debug_line: &'a [u8],
+    pub debug_line_str: &'a [u8],
+    pub debug_str: &'a [u8],
+}
+
+/// A cursor over little-endian DWARF data.
+struct Reader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(data: &'a [u8], offset: usize) -> Self {
+        Self { data, offset }
+    }
+
+    fn bytes(&mut self, len: usize) -> Option<&'a [u8]> {
+        let bytes = self.data.get(self.offset..self.offset.checked_add(len)?)?;
+        self.offset += len;
+        Some(bytes)
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        self.bytes(1).map(|b| b[0])
+    }
+
+    fn u16(&mut self) -> Option<u16> {
+        self.bytes(2)
+            .map(|b| u16::from_le_bytes(b.try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        self.bytes(4)
+            .map(|b| u32::from_le_bytes(b.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> Option<u64> {
+        self.bytes(8)
+            .map(|b| u64::from_le_bytes(b.try_into().unwrap()))
+    }
+
+    /// A 4 or 8-byte offset, as the unit is in the 32 or 64-bit DWARF format.
+    fn offset(&mut self, dwarf64: bool) -> Option<u64> {
+        match dwarf64 {
+            false => self.u32().map(u64::from),
+            true => self.u64(),
+        }
+    }
+
+    fn uleb(&mut self) -> Option<u64> {
+        let mut value = 0;
+        for shift in (0..64).step_by(7) {
+            let byte = self.u8()?;
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Some(value);
+            }
+        }
+        None
+    }
+
+    fn sleb(&mut self) -> Option<i64> {
+        let mut value = 0;
+        for shift in (0..64).step_by(7) {
+            let byte = self.u8()?;
+            value |= ((byte & 0x7f) as i64) << shift;
+            if byte & 0x80 == 0 {
+                if shift < 57 && byte & 0x40 != 0 {
+                    value |= -1 << (shift + 7);
+                }
+                return Some(value);
+            }
+        }
+        None
+    }
+
+    fn str(&mut self) -> Option<&'a str> {
+        let len = self.data.get(self.offset..)?.iter().position(|&c| c == 0)?;
+        let s = std::str::from_utf8(self.bytes(len)?).ok();
+        self.offset += 1;
+        s
+    }
+
+    /// The length of a unit, and whether it is in the 64-bit format.
+    fn unit_length(&mut self) -> Option<(usize, bool)> {
+        match self.u32()? {
+            0xffff_ffff => Some((self.u64()? as usize, true)),
+            len => Some((len as usize, false)),
+        }
+    }
+}
+
+/// The line tables of all the compilation units, with their files merged.
+pub fn lines(sections: &Sections) -> LineTable {
+    let mut table = LineTable::default();
+    let mut files = HashMap::new();
+    let mut offset = 0;
+    while offset < sections.debug_line.len() {
+        let mut r = Reader::new(sections.debug_line, offset);
+        let Some((len, dwarf64)) = r.unit_length() else {
+            break;
+        };
+        let end = r.offset.saturating_add(len).min(sections.debug_line.len());
+        offset = end;
+        let mut r = Reader::new(&sections.debug_line[..end], r.offset);
+        // A unit in an unknown version or format is left out.
+        program(&mut r, dwarf64, sections, &mut table, &mut files);
+    }
+    table
+}
+
+/// Run the line number program of a unit, adding its rows to `table`.
+fn program(
+    r: &mut Reader,
+    dwarf64: bool,
+    sections: &Sections,
+    table: &mut LineTable,
+    files: &mut HashMap<String, usize>,
+) -> Option<()> {
+    let version = r.u16()?;
+    if !(2..=5).contains(&version) {
+        return None;
+    }
+    let mut address_size = 8;
+    if version >= 5 {
+        address_size = r.u8()?;
+        r.u8()?;
+    }
+    let header_length = r.offset(dwarf64)? as usize;
+    let program = r.offset.checked_add(header_length)?;
+    let min_inst_length = r.u8()? as u64;
+    if version >= 4 {
+        r.u8()?;
+    }
+    r.u8()?;
+    let line_base = r.u8()? as i8 as i64;
+    let line_range = r.u8()?;
+    let opcode_base = r.u8()?;
+    if line_range == 0 {
+        return None;
+    }
+    let opcode_lengths = r.bytes(opcode_base.saturating_sub(1) as usize)?.to_vec();
+
+    // Files as indexes into `table.files`, from 1 before DWARF 5 and from 0 since.
+    let mut unit_files = match version {
+        5 => vec![],
+        _ => vec![None],
+    };
+    let mut add_file = |path: String| {
+        let next = files.len();
+        let index = *files.entry(path.clone()).or_insert(next);
+        if index == next {
+            table.files.push(path);
+        }
+        unit_files.push(Some(index));
+    };
+    if version < 5 {
+        // Before DWARF 5, the compilation directory is only in `.debug_info`.
+        let mut dirs = vec![String::new()];
+        // Both lists end with an empty string.
+        while let Some(dir) = r.str().filter(|dir| !dir.is_empty()) {
+            dirs.push(dir.to_owned());
+        }
+        while let Some(name) = r.str().filter(|name| !name.is_empty()) {
+            let dir = r.uleb()? as usize;
+            r.uleb()?;
+            r.uleb()?;
+            add_file(join(dirs.get(dir).map_or("", String::as_str), name));
+        }
+    } else {
+        let dirs: Vec<String> = entries(r, dwarf64, sections)?
+            .into_iter()
+            .map(|(path, _)| path)
+            .collect();
+        for (name, dir) in entries(r, dwarf64, sections)? {
+            add_file(join(dirs.get(dir).map_or("", String::as_str), &name));
+        }
+    }
+
+    r.offset = program;
+    let (mut address, mut file, mut line) = (0u64, 1u64, 1i64);
+    let mut sequence: Vec<(u64, u64, i64)> = vec![];
+    while let Some(opcode) = r.u8() {
+        if opcode >= opcode_base {
+            let adjusted = (opcode - opcode_base) as u64;
+            address = address.wrapping_add(adjusted / line_range as u64 * min_inst_length);
+            line += line_base + (adjusted % line_range as u64) as i64;
+            sequence.push((address, file, line));
+            continue;
+        }
+        match opcode {
+            0 => {
+                let len = r.uleb()? as usize;
+                let end = r.offset.checked_add(len)?;
+                match r.u8()? {
+                    DW_LNE_END_SEQUENCE => {
+                        // Sequences of functions the linker dropped are left at 0 or -1.
+                        let start = sequence.first().map(|row| row.0).unwrap_or_default();
+                        if start != 0 && start < u64::MAX - 1 {
+                            for (i, &(from, file, line)) in sequence.iter().enumerate() {
+                                let to = sequence.get(i + 1).map_or(address, |row| row.0);
+                                let file = unit_files.get(file as usize).copied().flatten();
+                                if let (Some(file), true, Ok(line)) =
+                                    (file, to > from, u32::try_from(line))
+                                {
+                                    table.lines.push(Line {
+                                        address: from,
+                                        size: to - from,
+                                        line,
+                                        file,
+                                    });
+                                }
+                            }
+                        }
+                        sequence.clear();
+                        (address, file, line) = (0, 1, 1);
+                    }
+                    DW_LNE_SET_ADDRESS => {
+                        address = match address_size {
+                            4 => r.u32()? as u64,
+                            _ => r.u64()?,
+                        };
+                    }
+                    DW_LNE_DEFINE_FILE => return None,
+                    _ => {}
+                }
+                r.offset = end;
+            }
+            DW_LNS_COPY => sequence.push((address, file, line)),
+            DW_LNS_ADVANCE_PC => {
+                address = address.wrapping_add(r.uleb()?.wrapping_mul(min_inst_length))
+            }
+            DW_LNS_ADVANCE_LINE => line += r.sleb()?,
+            DW_LNS_SET_FILE => file = r.uleb()?,
+            DW_LNS_CONST_ADD_PC => {
+                let adjusted = (255 - opcode_base) as u64;
+                address = address.wrapping_add(adjusted / line_range as u64 * min_inst_length);
+            }
+            DW_LNS_FIXED_ADVANCE_PC => address = address.wrapping_add(r.u16()? as u64),
+            _ => {
+                for _ in 0..*opcode_lengths.get(opcode as usize - 1)? {
+                    r.uleb()?;
+                }
+            }
+        }
+    }
+    Some(())
+}
+
+/// The directory or file name entries of a DWARF 5 line table header, with their directory
+/// index.
+fn entries(r: &mut Reader, dwarf64: bool, sections: &Sections) -> Option<Vec<(String, usize)>> {
+    let formats: Vec<(u64, u64)> = (0..r.u8()?)
+        .map(|_| Some((r.uleb()?, r.uleb()?)))
+        .collect::<Option<_>>()?;
+    let count = r.uleb()?;
+    let mut entries = vec![];
+    for _ in 0..count {
+        let (mut path, mut dir) = (String::new(), 0);
+        for &(content, form) in &formats {
+            let mut number = 0;
+            let mut string = None;
+            match form {
+                DW_FORM_STRING => string = r.str(),
+                DW_FORM_LINE_STRP | DW_FORM_STRP => {
+                    let offset = r.offset(dwarf64)? as usize;
+                    let section = match form {
+                        DW_FORM_LINE_STRP => sections.debug_line_str,
+                        _ => sections.debug_str,
+                    };
+                    string = Reader::new(section, offset).str();
+                }
+                DW_FORM_UDATA => number = r.uleb()?,
+                DW_FORM_DATA1 => number = r.u8()? as u64,
+                DW_FORM_DATA2 => number = r.u16()? as u64,
+                DW_FORM_DATA4 => number = r.u32()? as u64,
+                DW_FORM_DATA8 => number = r.u64()?,
+                DW_FORM_DATA16 => drop(r.bytes(16)?),
+                DW_FORM_BLOCK => {
+                    let len = r.uleb()? as usize;
+                    r.bytes(len)?;
+                }
+                _ => return None,
+            }
+            match content {
+                DW_LNCT_PATH => path = string.unwrap_or_default().to_owned(),
+                DW_LNCT_DIRECTORY_INDEX => dir = number as usize,
+                _ => {}
+            }
+        }
+        entries.push((path, dir));
+    }
+    Some(entries)
+}
+
+fn join(dir: &str, name: &str) -> String {
+    if dir.is_empty() || name.starts_with('/') {
+        name.to_owned()
+    } else {
+        format!("{}/{name}", dir.trim_end_matches('/'))
+    }
+}
+
+#[derive(Clone, PartialEq)]
+enum Rule {
+    /// Saved at the CFA plus the offset.
+    Offset(i64),
+    /// The CFA plus the offset.
+    ValOffset(i64),
+    Register(u64),
+    SameValue,
+    /// Not recoverable, or computed by a DWARF expression, which Breakpad rules can't express.
+    Undefined,
+}
+
+#[derive(Clone, PartialEq)]
+struct Row {
+    /// The register and offset the CFA is from, `None` for a DWARF expression.
+    cfa: Option<(u64, i64)>,
+    registers: BTreeMap<u64, Rule>,
+}
+
+struct Cie<'a> {
+    code_alignment: u64,
+    data_alignment: i64,
+    return_address: u64,
+    /// `DW_EH_PE_*` encoding of the FDE addresses.
+    encoding: u8,
+    augmentation: bool,
+    instructions: &'a [u8],
+}
+
+/// The FDEs of `.eh_frame`, or `.debug_frame` when `eh_frame` is false, loaded at `address`, with
+/// their rules naming registers as Breakpad does for `arch`.
+pub fn cfi(section: &[u8], address: u64, eh_frame: bool, arch: &str) -> Vec<Fde> {
+    let mut fdes = vec![];
+    let mut cies = HashMap::new();
+    let mut offset = 0;
+    while offset < section.len() {
+        let mut r = Reader::new(section, offset);
+        let Some((len, dwarf64)) = r.unit_length() else {
+            break;
+        };
+        // A 0 length ends `.eh_frame`.
+        if len == 0 {
+            break;
+        }
+        let end = r.offset.saturating_add(len).min(section.len());
+        offset = end;
+        let mut r = Reader::new(&section[..end], r.offset);
+        let id_offset = r.offset;
+        let Some(id) = r.offset(dwarf64) else {
+            break;
+        };
+        let is_cie = match eh_frame {
+            true => id == 0,
+            false => id == 0xffff_ffff || id == u64::MAX,
+        };
+        if is_cie {
+            continue;
+        }
+        let cie_offset = match eh_frame {
+            true => (id_offset as u64).checked_sub(id),
+            false => Some(id),
+        };
+        let Some(cie_offset) = cie_offset else {
+            continue;
+        };
+        let cie = cies
+            .entry(cie_offset)
+            .or_insert_with(|| parse_cie(section, cie_offset as usize, eh_frame));
+        let Some(cie) = cie else {
+            continue;
+        };
+        if let Some(fde) = parse_fde(&mut r, cie, address, arch) {
+            fdes.push(fde);
+        }
+    }
+    fdes
+}
+
+fn parse_cie(section: &[u8], offset: usize, eh_frame: bool) -> Option<Cie<'_>> {
+    let mut r = Reader::new(section, offset);
+    let (len, dwarf64) = r.unit_length()?;
+    let end = r.offset.checked_add(len)?.min(section.len());
+    let mut r = Reader::new(&section[..end], r.offset);
+    r.offset(dwarf64)?;
+    let version = r.u8()?;
+    let augmentation = r.str()?;
+    if !eh_frame && version >= 4 {
+        r.u8()?;
+        r.u8()?;
+    }
+    let code_alignment = r.uleb()?;
+    let data_alignment = r.sleb()?;
+    let return_address = match version {
+        1 => r.u8()? as u64,
+        _ => r.uleb()?,
+    };
+    let mut encoding = 0;
+    if augmentation.starts_with('z') {
+        let len = r.uleb()? as usize;
+        let data_end = r.offset.checked_add(len)?;
+        for c in augmentation.chars().skip(1) {
+            match c {
+                'L' => drop(r.u8()?),
+                'R' => encoding = r.u8()?,
+                'P' => {
+                    let personality = r.u8()?;
+                    pointer(&mut r, personality, 0)?;
+                }
+                _ => break,
+            }
+        }
+        r.offset = data_end;
+    } else if !augmentation.is_empty() {
+        return None;
+    }
+    Some(Cie {
+        code_alignment,
+        data_alignment,
+        return_address,
+        encoding,
+        augmentation: augmentation.starts_with('z'),
+        instructions: r.data.get(r.offset..)?,
+    })
+}
+
+/// A pointer in a `DW_EH_PE_*` encoding, the field being at `address` when loaded.
+fn pointer(r: &mut Reader, encoding: u8, address: u64) -> Option<u64> {
+    if encoding == DW_EH_PE_OMIT {
+        return Some(0);
+    }
+    let value = match encoding & 0x0f {
+        0x00 => r.u64()?,
+        0x01 => r.uleb()?,
+        0x02 => r.u16()? as u64,
+        0x03 => r.u32()? as u64,
+        0x04 => r.u64()?,
+        0x09 => r.sleb()? as u64,
+        0x0a => r.u16()? as i16 as u64,
+        0x0b => r.u32()? as i32 as u64,
+        0x0c => r.u64()?,
+        _ => return None,
+    };
+    match encoding & 0x70 {
+        0 => Some(value),
+        DW_EH_PE_PCREL => Some(address.wrapping_add(value)),
+        _ => None,
+    }
+}
+
+fn parse_fde(r: &mut Reader, cie: &Cie, section_address: u64, arch: &str) -> Option<Fde> {
+    let start = pointer(r, cie.encoding, section_address + r.offset as u64)?;
+    // The range is a plain number in the same format.
+    let size = pointer(r, cie.encoding & 0x0f, 0)?;
+    if cie.augmentation {
+        let len = r.uleb()? as usize;
+        r.bytes(len)?;
+    }
+    if start == 0 || size == 0 {
+        return None;
+    }
+
+    let mut initial = Row {
+        cfa: None,
+        registers: BTreeMap::new(),
+    };
+    execute(cie, cie.instructions, &mut initial, None, &mut vec![])?;
+    let mut rows = vec![(start, initial.clone())];
+    let mut row = initial.clone();
+    let mut location = start;
+    let instructions = r.data.get(r.offset..)?;
+    execute(
+        cie,
+        instructions,
+        &mut row,
+        Some((&initial, &mut location, section_address)),
+        &mut rows,
+    )?;
+    rows.push((location, row));
+    rows.retain(|(location, _)| *location < start + size);
+
+    let name = |reg: u64| match reg == cie.return_address {
+        true => Some(".ra".to_owned()),
+        false => register(arch, reg),
+    };
+    let mut rules = vec![];
+    let mut previous: Option<&Row> = None;
+    for (location, row) in &rows {
+        if previous == Some(row) {
+            continue;
+        }
+        let (cfa_register, cfa_offset) = row.cfa?;
+        let mut text = vec![];
+        if previous.is_none_or(|p| p.cfa != row.cfa) {
+            text.push(format!(
+                ".cfa: {} {cfa_offset} +",
+                register(arch, cfa_register)?
+            ));
+        }
+        for (&reg, rule) in &row.registers {
+            if previous.is_some_and(|p| p.registers.get(&reg) == Some(rule)) {
+                continue;
+            }
+            let Some(reg_name) = name(reg) else {
+                continue;
+            };
+            let value = match rule {
+                Rule::Offset(offset) => format!(".cfa {offset} + ^"),
+                Rule::ValOffset(offset) => format!(".cfa {offset} +"),
+                Rule::Register(other) => match register(arch, *other) {
+                    Some(other) => other,
+                    None => continue,
+                },
+                Rule::SameValue => reg_name.clone(),
+                Rule::Undefined => continue,
+            };
+            text.push(format!("{reg_name}: {value}"));
+        }
+        // Registers whose rule went away are back to their value in the callee.
+        if let Some(previous) = previous {
+            for &reg in previous.registers.keys() {
+                if !row.registers.contains_key(&reg) {
+                    if let Some(reg_name) = name(reg).filter(|name| name != ".ra") {
+                        text.push(format!("{reg_name}: {reg_name}"));
+                    }
+                }
+            }
+        }
+        if !text.is_empty() {
+            rules.push((*location, text.join(" ")));
+        }
+        previous = Some(row);
+    }
+    Some(Fde {
+        address: start,
+        size,
+        rules,
+    })
+}
+
+/// Run call frame instructions on `row`, recording it in `rows` before each advance when running
+/// those of an FDE, which `fde` holds the initial row, location and section address of.
+fn execute(
+    cie: &Cie,
+    instructions: &[u8],
+    row: &mut Row,
+    mut fde: Option<(&Row, &mut u64, u64)>,
+    rows: &mut Vec<(u64, Row)>,
+) -> Option<()> {
+    let mut r = Reader::new(instructions, 0);
+    let mut stack = vec![];
+    let offset = |n: i64| n.wrapping_mul(cie.data_alignment);
+    let initial = |reg: u64, fde: &Option<(&Row, &mut u64, u64)>| {
+        fde.as_ref()
+            .and_then(|(initial, _, _)| initial.registers.get(&reg).cloned())
+    };
+    while let Some(opcode) = r.u8() {
+        let mut advance = None;
+        match (opcode & 0xc0, opcode & 0x3f) {
+            (DW_CFA_ADVANCE_LOC, delta) => advance = Some(delta as u64 * cie.code_alignment),
+            (DW_CFA_OFFSET, reg) => {
+                let n = r.uleb()? as i64;
+                row.registers.insert(reg as u64, Rule::Offset(offset(n)));
+            }
+            (DW_CFA_RESTORE, reg) => match initial(reg as u64, &fde) {
+                Some(rule) => drop(row.registers.insert(reg as u64, rule)),
+                None => drop(row.registers.remove(&(reg as u64))),
+            },
+            _ => match opcode {
+                DW_CFA_NOP => {}
+                DW_CFA_SET_LOC => {
+                    let (_, location, section_address) = fde.as_mut()?;
+                    let address = *section_address;
+                    let to = pointer(&mut r, cie.encoding, address)?;
+                    advance = Some(to.wrapping_sub(**location));
+                }
+                DW_CFA_ADVANCE_LOC1 => advance = Some(r.u8()? as u64 * cie.code_alignment),
+                DW_CFA_ADVANCE_LOC2 => advance = Some(r.u16()? as u64 * cie.code_alignment),
+                DW_CFA_ADVANCE_LOC4 => advance = Some(r.u32()? as u64 * cie.code_alignment),
+                DW_CFA_OFFSET_EXTENDED => {
+                    let (reg, n) = (r.uleb()?, r.uleb()? as i64);
+                    row.registers.insert(reg, Rule::Offset(offset(n)));
+                }
+                DW_CFA_RESTORE_EXTENDED => {
+                    let reg = r.uleb()?;
+                    match initial(reg, &fde) {
+                        Some(rule) => drop(row.registers.insert(reg, rule)),
+                        None => drop(row.registers.remove(&reg)),
+                    }
+                }
+                DW_CFA_UNDEFINED => drop(row.registers.insert(r.uleb()?, Rule::Undefined)),
+                DW_CFA_SAME_VALUE => drop(row.registers.insert(r.uleb()?, Rule::SameValue)),
+                DW_CFA_REGISTER => {
+                    let (reg, other) = (r.uleb()?, r.uleb()?);
+                    row.registers.insert(reg, Rule::Register(other));
+                }
+                DW_CFA_REMEMBER_STATE => stack.push(row.clone()),
+                DW_CFA_RESTORE_STATE => *row = stack.pop()?,
+                DW_CFA_DEF_CFA => row.cfa = Some((r.uleb()?, r.uleb()? as i64)),
+                DW_CFA_DEF_CFA_SF => {
+                    let (reg, n) = (r.uleb()?, r.sleb()?);
+                    row.cfa = Some((reg, offset(n)));
+                }
+                DW_CFA_DEF_CFA_REGISTER => {
+                    let reg = r.uleb()?;
+                    row.cfa = Some((reg, row.cfa?.1));
+                }
+                DW_CFA_DEF_CFA_OFFSET => {
+                    let n = r.uleb()? as i64;
+                    row.cfa = Some((row.cfa?.0, n));
+                }
+                DW_CFA_DEF_CFA_OFFSET_SF => {
+                    let n = r.sleb()?;
+                    row.cfa = Some((row.cfa?.0, offset(n)));
+                }
+                DW_CFA_DEF_CFA_EXPRESSION => {
+                    let len = r.uleb()? as usize;
+                    r.bytes(len)?;
+                    row.cfa = None;
+                }
+                DW_CFA_EXPRESSION | DW_CFA_VAL_EXPRESSION => {
+                    let reg = r.uleb()?;
+                    let len = r.uleb()? as usize;
+                    r.bytes(len)?;
+                    row.registers.insert(reg, Rule::Undefined);
+                }
+                DW_CFA_OFFSET_EXTENDED_SF => {
+                    let (reg, n) = (r.uleb()?, r.sleb()?);
+                    row.registers.insert(reg, Rule::Offset(offset(n)));
+                }
+                DW_CFA_VAL_OFFSET => {
+                    let (reg, n) = (r.uleb()?, r.uleb()? as i64);
+                    row.registers.insert(reg, Rule::ValOffset(offset(n)));
+                }
+                DW_CFA_VAL_OFFSET_SF => {
+                    let (reg, n) = (r.uleb()?, r.sleb()?);
+                    row.registers.insert(reg, Rule::ValOffset(offset(n)));
+                }
+                DW_CFA_GNU_ARGS_SIZE => drop(r.uleb()?),
+                DW_CFA_GNU_NEGATIVE_OFFSET_EXTENDED => {
+                    let (reg, n) = (r.uleb()?, r.uleb()? as i64);
+                    row.registers.insert(reg, Rule::Offset(-offset(n)));
+                }
+                _ => return None,
+            },
+        }
+        if let (Some(delta), Some((_, location, _))) = (advance, fde.as_mut()) {
+            rows.push((**location, row.clone()));
+            **location = location.wrapping_add(delta);
+        }
+    }
+    Some(())
+}
+
+/// The Breakpad name of a DWARF register.
+pub fn register(arch: &str, reg: u64) -> Option<String> {
+    const X86_64: [&str; 17] = [
+        "$rax", "$rdx", "$rcx", "$rbx", "$rsi", "$rdi", "$rbp", "$rsp", "$r8", "$r9", "$r10",
+        "$r11", "$r12", "$r13", "$r14", "$r15", "$rip",
+    ];
+    match arch {
+        "x86_64" => X86_64.get(reg as usize).map(|name| name.to_string()),
+        "arm64" => match reg {
+            0..=30 => Some(format!("x{reg}")),
+            31 => Some("sp".to_owned()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
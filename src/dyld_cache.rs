@@ -0,0 +1,417 @@
+use crate::{
+    elf,
+    macho::{self, MachO},
+};
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    ffi::OsString,
+    fs::{self, File},
+    io::{Read, Seek, SeekFrom},
+    mem,
+    path::Path,
+};
+
+const MAGIC: &[u8] = b"dyld_v1";
+/// Offsets of `dyld_cache_header` fields. The header grew with each version, and the mappings
+/// follow it, so a field is there only when it ends before `mappingOffset`.
+const MAPPING_OFFSET: usize = 16;
+const MAPPING_COUNT: usize = 20;
+const IMAGES_OFFSET_OLD: usize = 24;
+const IMAGES_COUNT_OLD: usize = 28;
+const LOCAL_SYMBOLS_OFFSET: usize = 72;
+const UUID: usize = 88;
+const SUB_CACHE_ARRAY_OFFSET: usize = 392;
+const SUB_CACHE_ARRAY_COUNT: usize = 396;
+const SYMBOL_FILE_UUID: usize = 400;
+const IMAGES_OFFSET: usize = 448;
+const IMAGES_COUNT: usize = 452;
+const CACHE_SUB_TYPE: usize = 456;
+/// Longest header read, past any version so far.
+const MAX_HEADER: usize = 0x1000;
+/// Most entries of a table read at once.
+const MAX_ENTRIES: u32 = 0x10_0000;
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct dyld_cache_mapping_info {
+    pub address: u64,
+    pub size: u64,
+    pub fileOffset: u64,
+    pub maxProt: u32,
+    pub initProt: u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct dyld_cache_image_info {
+    pub address: u64,
+    pub modTime: u64,
+    pub inode: u64,
+    pub pathFileOffset: u32,
+    pub pad: u32,
+}
+
+/// A sub-cache named after the main one with `.1`, `.2`, ...
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct dyld_subcache_entry_v1 {
+    pub uuid: [u8; 16],
+    pub cacheVMOffset: u64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct dyld_subcache_entry {
+    pub uuid: [u8; 16],
+    pub cacheVMOffset: u64,
+    pub fileSuffix: [u8; 32],
+}
+
+/// Offsets are from the start of this record.
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct dyld_cache_local_symbols_info {
+    pub nlistOffset: u32,
+    pub nlistCount: u32,
+    pub stringsOffset: u32,
+    pub stringsSize: u32,
+    pub entriesOffset: u32,
+    pub entriesCount: u32,
+}
+
+/// In caches without a `.symbols` file, with the file offset of the image.
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct dyld_cache_local_symbols_entry {
+    pub dylibOffset: u32,
+    pub nlistStartIndex: u32,
+    pub nlistCount: u32,
+}
+
+/// With the offset of the image from the start of the cache in memory.
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct dyld_cache_local_symbols_entry_64 {
+    pub dylibOffset: u64,
+    pub nlistStartIndex: u32,
+    pub nlistCount: u32,
+}
+
+/// A dyld shared cache as copied from a Mac, such as
+/// /System/Volumes/Preboot/Cryptexes/OS/System/Library/dyld/dyld_shared_cache_arm64e, with the
+/// sub-caches and `.symbols` file next to it.
+pub struct DyldCache {
+    /// The main cache, then the sub-caches.
+    files: Vec<CacheFile>,
+    /// The unslid addresses of the images, by path.
+    images: HashMap<String, u64>,
+    locals: Option<LocalSymbols>,
+}
+
+/// The symbols the cache builder took out of the images' symbol tables, kept in the `.symbols`
+/// file or at the end of the main cache.
+struct LocalSymbols {
+    /// `None` for the main cache.
+    file: Option<CacheFile>,
+    nlists: u64,
+    strings: u64,
+    strings_end: u64,
+    /// The range of `nlists` of each image, by its offset from the start of the cache.
+    entries: HashMap<u64, (u32, u32)>,
+}
+
+/// One file of the cache, read as needed as caches are gigabytes.
+struct CacheFile {
+    file: RefCell<File>,
+    /// The header, as long as its version has it.
+    header: Vec<u8>,
+    mappings: Vec<dyld_cache_mapping_info>,
+}
+
+impl CacheFile {
+    fn open(path: &Path) -> Option<Self> {
+        let mut file = File::open(path).ok()?;
+        let mut start = [0; MAPPING_OFFSET + 4];
+        file.read_exact(&mut start).ok()?;
+        let size: u32 = elf::read(&start, MAPPING_OFFSET)?;
+        if !start.starts_with(MAGIC) || !(start.len()..=MAX_HEADER).contains(&(size as usize)) {
+            return None;
+        }
+        let mut cache = Self {
+            file: RefCell::new(file),
+            header: vec![0; size as usize],
+            mappings: vec![],
+        };
+        let mut header = mem::take(&mut cache.header);
+        if !cache.read(0, &mut header) {
+            return None;
+        }
+        cache.header = header;
+        cache.mappings = cache.table(size as u64, cache.u32(MAPPING_COUNT)?)?;
+        Some(cache)
+    }
+
+    fn read(&self, offset: u64, buf: &mut [u8]) -> bool {
+        let mut file = self.file.borrow_mut();
+        file.seek(SeekFrom::Start(offset)).is_ok() && file.read_exact(buf).is_ok()
+    }
+
+    /// Read at an unslid address this file maps.
+    fn read_mapped(&self, address: u64, buf: &mut [u8]) -> bool {
+        self.mappings.iter().any(|m| {
+            address >= m.address
+                && address + buf.len() as u64 <= m.address + m.size
+                && self.read(m.fileOffset + address - m.address, buf)
+        })
+    }
+
+    fn table<T: Copy>(&self, offset: u64, count: u32) -> Option<Vec<T>> {
+        if count > MAX_ENTRIES {
+            return None;
+        }
+        let mut data = vec![0; count as usize * mem::size_of::<T>()];
+        if !self.read(offset, &mut data) {
+            return None;
+        }
+        data.chunks_exact(mem::size_of::<T>())
+            .map(|entry| elf::read(entry, 0))
+            .collect()
+    }
+
+    fn u32(&self, field: usize) -> Option<u32> {
+        elf::read(&self.header, field)
+    }
+
+    fn uuid(&self, field: usize) -> Option<[u8; 16]> {
+        elf::read(&self.header, field)
+    }
+}
+
+impl DyldCache {
+    /// The caches in `dir`, or only the one with `uuid`.
+    pub fn find(dir: &Path, uuid: Option<[u8; 16]>) -> Vec<Self> {
+        let Ok(entries) = fs::read_dir(dir) else {
+            return vec![];
+        };
+        entries
+            .filter_map(|entry| {
+                let path = entry.ok()?.path();
+                let main = CacheFile::open(&path)?;
+                if uuid.is_some_and(|uuid| main.uuid(UUID) != Some(uuid)) {
+                    return None;
+                }
+                Self::new(&path, main)
+            })
+            .collect()
+    }
+
+    /// Sub-caches and `.symbols` files have no images, and are left out.
+    fn new(path: &Path, main: CacheFile) -> Option<Self> {
+        let (offset, count) = match main.u32(IMAGES_COUNT) {
+            Some(count) => (main.u32(IMAGES_OFFSET)?, count),
+            None => (main.u32(IMAGES_OFFSET_OLD)?, main.u32(IMAGES_COUNT_OLD)?),
+        };
+        if count == 0 {
+            return None;
+        }
+        let images = main
+            .table::<dyld_cache_image_info>(offset as u64, count)?
+            .into_iter()
+            .filter_map(|image| {
+                let read = |offset, buf: &mut [u8]| main.read(offset, buf);
+                let path = macho::read_str(read, image.pathFileOffset as u64, u64::MAX)?;
+                Some((path, image.address))
+            })
+            .collect();
+
+        let sibling = |suffix: &str| {
+            let mut name = OsString::from(path.file_name()?);
+            name.push(suffix);
+            CacheFile::open(&path.with_file_name(name))
+        };
+        // A missing sub-cache leaves out the images it holds, rather than the whole cache.
+        let offset = main.u32(SUB_CACHE_ARRAY_OFFSET).unwrap_or_default() as u64;
+        let count = main.u32(SUB_CACHE_ARRAY_COUNT).unwrap_or_default();
+        let subcaches: Vec<(String, [u8; 16])> = match main.u32(CACHE_SUB_TYPE) {
+            None => main
+                .table::<dyld_subcache_entry_v1>(offset, count)?
+                .iter()
+                .enumerate()
+                .map(|(i, entry)| (format!(".{}", i + 1), entry.uuid))
+                .collect(),
+            Some(_) => main
+                .table::<dyld_subcache_entry>(offset, count)?
+                .iter()
+                .map(|entry| (macho::name(&entry.fileSuffix), entry.uuid))
+                .collect(),
+        };
+        let subcaches: Vec<CacheFile> = subcaches
+            .iter()
+            .filter_map(|(suffix, uuid)| {
+                sibling(suffix).filter(|sub| sub.uuid(UUID) == Some(*uuid))
+            })
+            .collect();
+
+        let locals = match main.uuid(SYMBOL_FILE_UUID) {
+            Some(uuid) if uuid != [0; 16] => sibling(".symbols")
+                .filter(|file| file.uuid(UUID) == Some(uuid))
+                .and_then(|file| LocalSymbols::new(Some(file), &main, true)),
+            uuid => LocalSymbols::new(None, &main, uuid.is_some()),
+        };
+
+        let mut files = vec![main];
+        files.extend(subcaches);
+        Some(Self {
+            files,
+            images,
+            locals,
+        })
+    }
+
+    /// The image at `path`, read at its unslid address.
+    pub fn image(&self, path: &str) -> Option<MachO<impl Fn(u64, &mut [u8]) -> bool + '_>> {
+        let address = *self.images.get(path)?;
+        MachO::mapped(
+            move |address, buf: &mut [u8]| self.read(address, buf),
+            address,
+        )
+    }
+
+    /// Read at an unslid address, from whichever file of the cache maps it.
+    pub fn read(&self, address: u64, buf: &mut [u8]) -> bool {
+        self.files.iter().any(|file| file.read_mapped(address, buf))
+    }
+
+    /// The local symbols of the image at `path`, at their unslid addresses.
+    pub fn local_symbols(&self, path: &str) -> Vec<macho::Symbol> {
+        let main = &self.files[0];
+        let (Some(locals), Some(first), Some(&address)) =
+            (&self.locals, main.mappings.first(), self.images.get(path))
+        else {
+            return vec![];
+        };
+        let Some(&(start, count)) = locals.entries.get(&address.wrapping_sub(first.address)) else {
+            return vec![];
+        };
+        let file = locals.file.as_ref().unwrap_or(main);
+        let offset = locals.nlists + start as u64 * mem::size_of::<macho::nlist_64>() as u64;
+        let Some(nlists) = file.table::<macho::nlist_64>(offset, count) else {
+            return vec![];
+        };
+        nlists
+            .into_iter()
+            .filter(|sym| macho::defined(sym.n_type))
+            .filter_map(|sym| {
+                let name = macho::read_str(
+                    |offset, buf: &mut [u8]| file.read(offset, buf),
+                    locals.strings + sym.n_strx as u64,
+                    locals.strings_end,
+                )?;
+                Some(macho::Symbol {
+                    name,
+                    value: sym.n_value,
+                    kind: sym.n_type,
+                })
+            })
+            .collect()
+    }
+}
+
+impl LocalSymbols {
+    /// Caches recent enough to have a `.symbols` file have 64-bit entries, even when they keep the
+    /// symbols in the main cache.
+    fn new(file: Option<CacheFile>, main: &CacheFile, entries64: bool) -> Option<Self> {
+        let f = file.as_ref().unwrap_or(main);
+        let offset = elf::read::<u64>(&f.header, LOCAL_SYMBOLS_OFFSET).filter(|&o| o != 0)?;
+        let [info] = f.table::<dyld_cache_local_symbols_info>(offset, 1)?[..] else {
+            return None;
+        };
+        let entries_offset = offset + info.entriesOffset as u64;
+        let entries = if entries64 {
+            f.table::<dyld_cache_local_symbols_entry_64>(entries_offset, info.entriesCount)?
+                .iter()
+                .map(|e| (e.dylibOffset, (e.nlistStartIndex, e.nlistCount)))
+                .collect()
+        } else {
+            f.table::<dyld_cache_local_symbols_entry>(entries_offset, info.entriesCount)?
+                .iter()
+                .map(|e| (e.dylibOffset as u64, (e.nlistStartIndex, e.nlistCount)))
+                .collect()
+        };
+        let strings = offset + info.stringsOffset as u64;
+        Some(Self {
+            nlists: offset + info.nlistOffset as u64,
+            strings,
+            strings_end: strings + info.stringsSize as u64,
+            entries,
+            file,
+        })
+    }
+}
+
+/// A UUID such as `sharedCacheUUID`, in hex with or without dashes.
+pub fn parse_uuid(s: &str) -> Result<[u8; 16], String> {
+    let hex: String = s.chars().filter(|&c| c != '-').collect();
+    let mut uuid = [0; 16];
+    if hex.len() != 32 || !hex.is_ascii() {
+        return Err(format!("invalid UUID `{s}`"));
+    }
+    for (i, b) in uuid.iter_mut().enumerate() {
+        *b = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16)
+            .map_err(|_| format!("invalid UUID `{s}`"))?;
+    }
+    Ok(uuid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    const TEXT: u64 = 0x7ff8_0000_1000;
+
+    /// Generated by make.py next to it.
+    fn dir() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/dyld_cache")
+    }
+
+    fn names(symbols: Vec<macho::Symbol>) -> Vec<(String, u64)> {
+        symbols
+            .into_iter()
+            .map(|sym| (sym.name, sym.value))
+            .collect()
+    }
+
+    #[test]
+    fn split_cache() {
+        // The sub-cache and the .symbols file are parts of the main cache, not caches of their own.
+        let caches = DyldCache::find(&dir(), None);
+        assert_eq!(caches.len(), 1);
+        let cache = &caches[0];
+
+        let image = cache.image("/usr/lib/libfixture.dylib").unwrap();
+        assert_eq!(image.slide(), 0);
+        let (code_id, _) = macho::ids(&image.uuid().unwrap());
+        assert_eq!(code_id, "0DDBA11C0FFEE5EEDBA5EBA11CAFE0C3");
+        // The exported symbol from the image's table in the sub-cache, the local one from the
+        // .symbols file, without the debugging entry next to it.
+        assert_eq!(
+            names(image.symbols()),
+            [("_fixture_exported".to_owned(), TEXT + 0x100)]
+        );
+        assert_eq!(
+            names(cache.local_symbols("/usr/lib/libfixture.dylib")),
+            [("_fixture_local".to_owned(), TEXT + 0x200)]
+        );
+        assert!(cache.image("/usr/lib/libmissing.dylib").is_none());
+    }
+
+    #[test]
+    fn by_uuid() {
+        let uuid = parse_uuid("0DDBA11C-0FFE-E5EE-DBA5-EBA11CAFE0C0").unwrap();
+        assert_eq!(DyldCache::find(&dir(), Some(uuid)).len(), 1);
+        assert!(DyldCache::find(&dir(), Some([0; 16])).is_empty());
+        assert!(parse_uuid("0DDBA11C").is_err());
+    }
+}
@@ -0,0 +1,294 @@
+use std::{fs::File, io::Read, mem, ptr, str};
+
+pub const ET_CORE: u16 = 4;
+pub const EM_386: u16 = 3;
+pub const EM_ARM: u16 = 40;
+pub const EM_X86_64: u16 = 62;
+pub const EM_AARCH64: u16 = 183;
+pub const PT_LOAD: u32 = 1;
+pub const PT_DYNAMIC: u32 = 2;
+pub const PT_NOTE: u32 = 4;
+pub const PF_W: u32 = 2;
+pub const PF_R: u32 = 4;
+pub const SHT_SYMTAB: u32 = 2;
+pub const SHT_NOTE: u32 = 7;
+pub const SHT_NOBITS: u32 = 8;
+pub const SHT_DYNSYM: u32 = 11;
+pub const SHF_COMPRESSED: u64 = 0x800;
+pub const SHN_UNDEF: u16 = 0;
+pub const STT_OBJECT: u8 = 1;
+pub const STT_FUNC: u8 = 2;
+pub const NT_PRSTATUS: u32 = 1;
+pub const NT_FPREGSET: u32 = 2;
+pub const NT_PRPSINFO: u32 = 3;
+pub const NT_AUXV: u32 = 6;
+pub const NT_GNU_BUILD_ID: u32 = 3;
+pub const NT_SIGINFO: u32 = 0x5349_4749;
+pub const NT_FILE: u32 = 0x4649_4c45;
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct Elf64_Ehdr {
+    pub e_ident: [u8; 16],
+    pub e_type: u16,
+    pub e_machine: u16,
+    pub e_version: u32,
+    pub e_entry: u64,
+    pub e_phoff: u64,
+    pub e_shoff: u64,
+    pub e_flags: u32,
+    pub e_ehsize: u16,
+    pub e_phentsize: u16,
+    pub e_phnum: u16,
+    pub e_shentsize: u16,
+    pub e_shnum: u16,
+    pub e_shstrndx: u16,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct Elf64_Phdr {
+    pub p_type: u32,
+    pub p_flags: u32,
+    pub p_offset: u64,
+    pub p_vaddr: u64,
+    pub p_paddr: u64,
+    pub p_filesz: u64,
+    pub p_memsz: u64,
+    pub p_align: u64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct Elf64_Shdr {
+    pub sh_name: u32,
+    pub sh_type: u32,
+    pub sh_flags: u64,
+    pub sh_addr: u64,
+    pub sh_offset: u64,
+    pub sh_size: u64,
+    pub sh_link: u32,
+    pub sh_info: u32,
+    pub sh_addralign: u64,
+    pub sh_entsize: u64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct Elf64_Sym {
+    pub st_name: u32,
+    pub st_info: u8,
+    pub st_other: u8,
+    pub st_shndx: u16,
+    pub st_value: u64,
+    pub st_size: u64,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct Elf64_Nhdr {
+    pub n_namesz: u32,
+    pub n_descsz: u32,
+    pub n_type: u32,
+}
+
+/// A little-endian ELF64 image held in memory.
+pub struct Elf<'a> {
+    data: &'a [u8],
+    pub header: Elf64_Ehdr,
+}
+
+pub struct Symbol<'a> {
+    pub name: &'a str,
+    pub value: u64,
+    pub size: u64,
+    pub kind: u8,
+}
+
+impl<'a> Elf<'a> {
+    pub fn parse(data: &'a [u8]) -> Option<Self> {
+        let header: Elf64_Ehdr = read(data, 0)?;
+        if header.e_ident[..4] != *b"\x7fELF" || header.e_ident[4] != 2 || header.e_ident[5] != 1 {
+            return None;
+        }
+        Some(Self { data, header })
+    }
+
+    pub fn program_headers(&self) -> Vec<Elf64_Phdr> {
+        (0..self.header.e_phnum as usize)
+            .filter_map(|i| {
+                read(
+                    self.data,
+                    self.header.e_phoff as usize + i * self.header.e_phentsize as usize,
+                )
+            })
+            .collect()
+    }
+
+    pub fn section_headers(&self) -> Vec<Elf64_Shdr> {
+        (0..self.header.e_shnum as usize)
+            .filter_map(|i| {
+                read(
+                    self.data,
+                    self.header.e_shoff as usize + i * self.header.e_shentsize as usize,
+                )
+            })
+            .collect()
+    }
+
+    /// The lowest virtual address of the loadable segments, which is mapped at the load address.
+    pub fn base_address(&self) -> u64 {
+        self.program_headers()
+            .iter()
+            .filter(|ph| ph.p_type == PT_LOAD)
+            .map(|ph| ph.p_vaddr.wrapping_sub(ph.p_offset))
+            .min()
+            .unwrap_or_default()
+    }
+
+    /// The file contents at a virtual address, in a read-only loadable segment, which the process
+    /// sees unchanged.
+    pub fn read_only_data(&self, address: u64, len: usize) -> Option<&'a [u8]> {
+        let ph = self.program_headers().into_iter().find(|ph| {
+            ph.p_type == PT_LOAD
+                && ph.p_flags & PF_W == 0
+                && ph.p_vaddr <= address
+                && address + len as u64 <= ph.p_vaddr + ph.p_filesz
+        })?;
+        let start = (ph.p_offset + address - ph.p_vaddr) as usize;
+        self.data.get(start..start + len)
+    }
+
+    pub fn section_data(&self, sh: &Elf64_Shdr) -> &'a [u8] {
+        let start = sh.sh_offset as usize;
+        self.data
+            .get(start..start.saturating_add(sh.sh_size as usize))
+            .unwrap_or_default()
+    }
+
+    /// The section with the given name, e.g. `.debug_line`.
+    pub fn section(&self, name: &str) -> Option<Elf64_Shdr> {
+        let sections = self.section_headers();
+        let names = self.section_data(sections.get(self.header.e_shstrndx as usize)?);
+        sections
+            .into_iter()
+            .find(|sh| c_str(names, sh.sh_name as usize) == Some(name))
+    }
+
+    /// Defined symbols of `.symtab` and `.dynsym`.
+    pub fn symbols(&self) -> Vec<Symbol<'a>> {
+        let sections = self.section_headers();
+        let mut symbols = vec![];
+        for sh in sections
+            .iter()
+            .filter(|sh| sh.sh_type == SHT_SYMTAB || sh.sh_type == SHT_DYNSYM)
+        {
+            let Some(strtab) = sections.get(sh.sh_link as usize) else {
+                continue;
+            };
+            let strtab = self.section_data(strtab);
+            let syms = self.section_data(sh);
+            for i in 0..syms.len() / mem::size_of::<Elf64_Sym>() {
+                let Some(sym) = read::<Elf64_Sym>(syms, i * mem::size_of::<Elf64_Sym>()) else {
+                    break;
+                };
+                if sym.st_shndx == SHN_UNDEF || sym.st_value == 0 {
+                    continue;
+                }
+                if let Some(name) = c_str(strtab, sym.st_name as usize) {
+                    symbols.push(Symbol {
+                        name,
+                        value: sym.st_value,
+                        size: sym.st_size,
+                        kind: sym.st_info & 0xf,
+                    });
+                }
+            }
+        }
+        symbols
+    }
+
+    /// The `NT_GNU_BUILD_ID` note, which the linker puts at the start of the image.
+    pub fn build_id(&self) -> Option<&'a [u8]> {
+        let segments = self
+            .program_headers()
+            .into_iter()
+            .filter(|ph| ph.p_type == PT_NOTE)
+            .map(|ph| (ph.p_offset, ph.p_filesz));
+        let sections = self
+            .section_headers()
+            .into_iter()
+            .filter(|sh| sh.sh_type == SHT_NOTE)
+            .map(|sh| (sh.sh_offset, sh.sh_size));
+        segments.chain(sections).find_map(|(offset, size)| {
+            let data = self.data.get(offset as usize..)?;
+            let data = &data[..data.len().min(size as usize)];
+            notes(data)
+                .into_iter()
+                .find(|note| note.name == "GNU" && note.kind == NT_GNU_BUILD_ID)
+                .map(|note| note.desc)
+        })
+    }
+}
+
+pub fn arch(machine: u16) -> Option<&'static str> {
+    match machine {
+        EM_386 => Some("x86"),
+        EM_ARM => Some("arm"),
+        EM_X86_64 => Some("x86_64"),
+        EM_AARCH64 => Some("arm64"),
+        _ => None,
+    }
+}
+
+/// The start of an image file, enough for `Elf::parse` to find its header and build ID without
+/// reading it whole.
+pub fn headers(path: &str) -> Vec<u8> {
+    let mut data = vec![];
+    if let Ok(file) = File::open(path) {
+        let _ = file.take(16 * 1024).read_to_end(&mut data);
+    }
+    data
+}
+
+pub struct Note<'a> {
+    pub name: &'a str,
+    pub kind: u32,
+    pub desc: &'a [u8],
+}
+
+/// Notes of a `PT_NOTE` segment, each name and descriptor padded to 4 bytes.
+pub fn notes(data: &[u8]) -> Vec<Note<'_>> {
+    let mut notes = vec![];
+    let mut offset = 0;
+    while let Some(nhdr) = read::<Elf64_Nhdr>(data, offset) {
+        let name = offset + mem::size_of::<Elf64_Nhdr>();
+        let desc = name + (nhdr.n_namesz as usize).next_multiple_of(4);
+        let Some(bytes) = data.get(desc..desc + nhdr.n_descsz as usize) else {
+            break;
+        };
+        notes.push(Note {
+            name: c_str(&data[..desc], name).unwrap_or_default(),
+            kind: nhdr.n_type,
+            desc: bytes,
+        });
+        offset = desc + (nhdr.n_descsz as usize).next_multiple_of(4);
+    }
+    notes
+}
+
+pub fn read<T: Copy>(data: &[u8], offset: usize) -> Option<T> {
+    let bytes = data.get(offset..offset.checked_add(mem::size_of::<T>())?)?;
+    Some(unsafe { ptr::read_unaligned(bytes.as_ptr() as *const T) })
+}
+
+/// The bytes of a `repr(C)` record without implicit padding.
+pub fn bytes<T: Copy>(v: &T) -> &[u8] {
+    unsafe { std::slice::from_raw_parts(v as *const T as *const u8, mem::size_of::<T>()) }
+}
+
+pub fn c_str(data: &[u8], offset: usize) -> Option<&str> {
+    let bytes = data.get(offset..)?;
+    let end = bytes.iter().position(|&c| c == 0)?;
+    str::from_utf8(&bytes[..end]).ok().filter(|s| !s.is_empty())
+}
@@ -0,0 +1,741 @@
+use crate::{
+    elf::{self, Elf},
+    macho::{self, MachO},
+    minidump::{self, Context, Minidump},
+    pe::Pe,
+    reason,
+};
+use std::{
+    fmt::Write as _,
+    io::{self, BufRead, BufReader, Write},
+    net::{TcpListener, TcpStream},
+};
+
+struct Register {
+    feature: &'static str,
+    name: String,
+    kind: &'static str,
+    value: Vec<u8>,
+}
+
+struct Thread {
+    id: u32,
+    /// In the order of the target description.
+    registers: Vec<Register>,
+}
+
+/// A minidump seen as a stopped remote target.
+struct Target<'a> {
+    architecture: Option<&'static str>,
+    osabi: &'static str,
+    /// Threads, without registers when there is no target description for the architecture.
+    threads: Vec<Thread>,
+    /// The thread reported as stopped, the exception thread if any.
+    current: usize,
+    signal: u8,
+    memory: Vec<(u64, &'a [u8])>,
+    modules: Vec<(String, u64)>,
+}
+
+/// Serve the dump to one debugger connection after another.
+pub fn serve(data: &[u8], listen: &str) {
+    let dump = Minidump::parse(data).expect("not a minidump");
+    let target = Target::new(&dump);
+    if let Some(reason) = reason::minidump(&dump) {
+        println!("exception: {reason}");
+    }
+    if target.architecture.is_none() {
+        println!("unsupported architecture, only memory can be read");
+    }
+    let listener = TcpListener::bind(listen).unwrap();
+    println!("listening on {}", listener.local_addr().unwrap());
+    for stream in listener.incoming() {
+        let Ok(stream) = stream else {
+            continue;
+        };
+        println!("debugger connected: {}", stream.peer_addr().unwrap());
+        if let Err(e) = target.session(stream) {
+            println!("debugger disconnected: {e}");
+        }
+    }
+}
+
+impl<'a> Target<'a> {
+    fn new(dump: &Minidump<'a>) -> Self {
+        let info = dump.system_info().expect("missing system info");
+        let architecture = match info.architecture {
+            minidump::PROCESSOR_ARCHITECTURE_INTEL => Some("i386"),
+            minidump::PROCESSOR_ARCHITECTURE_AMD64 => Some("i386:x86-64"),
+            minidump::PROCESSOR_ARCHITECTURE_ARM64 | minidump::PROCESSOR_ARCHITECTURE_ARM64_OLD => {
+                Some("aarch64")
+            }
+            _ => None,
+        };
+        let osabi = match info.platform {
+            minidump::PLATFORM_LINUX => "GNU/Linux",
+            // gdb reads Darwin's images from dyld in memory, and only takes the `qXfer:libraries`
+            // list without an OS ABI.
+            minidump::PLATFORM_MAC_OS_X | minidump::PLATFORM_IOS => "none",
+            minidump::PLATFORM_WIN32_NT => "Windows",
+            _ => "none",
+        };
+
+        let exception = dump.exception();
+        let threads: Vec<Thread> = dump
+            .threads()
+            .iter()
+            .filter_map(|thread| {
+                // The exception context is the one at the fault, the thread list may only hold the
+                // handler's.
+                let location = match &exception {
+                    Some(e) if e.thread_id == thread.id => e.context,
+                    _ => thread.context,
+                };
+                Some(Thread {
+                    id: thread.id,
+                    registers: match architecture {
+                        Some(_) => registers(&dump.context(location)?),
+                        None => vec![],
+                    },
+                })
+            })
+            .collect();
+        assert!(!threads.is_empty(), "no thread with a readable context");
+        let current = exception
+            .as_ref()
+            .and_then(|e| threads.iter().position(|t| t.id == e.thread_id))
+            .unwrap_or_default();
+        let signal = exception.map_or(5, |e| signal(info.platform, e.code));
+
+        Self {
+            architecture,
+            osabi,
+            threads,
+            current,
+            signal,
+            memory: dump.memory(),
+            modules: dump
+                .modules()
+                .iter()
+                .map(|m| (dump.string(m.name_rva).unwrap_or_default(), m.base))
+                .collect(),
+        }
+    }
+
+    fn session(&self, stream: TcpStream) -> io::Result<()> {
+        let mut reader = BufReader::new(stream.try_clone()?);
+        let mut writer = stream;
+        let mut session = Session::new(self);
+        while let Some(packet) = read_packet(&mut reader)? {
+            if session.ack {
+                writer.write_all(b"+")?;
+            }
+            if let Some(reply) = session.reply(&packet) {
+                send(&mut writer, reply.as_bytes())?;
+            }
+            if session.closed {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    fn thread(&self, id: &str) -> Option<usize> {
+        let id = u32::from_str_radix(id, 16).ok()?;
+        self.threads.iter().position(|t| t.id == id)
+    }
+
+    /// The captured bytes from `address`, up to `len` and the end of its memory range.
+    fn read(&self, address: u64, len: usize) -> Option<&'a [u8]> {
+        self.memory.iter().find_map(|&(start, data)| {
+            let bytes = data.get(address.checked_sub(start)? as usize..)?;
+            (!bytes.is_empty()).then(|| &bytes[..len.min(bytes.len())])
+        })
+    }
+
+    fn target_xml(&self, architecture: &str) -> String {
+        let mut xml = format!(
+            "<?xml version=\"1.0\"?><!DOCTYPE target SYSTEM \"gdb-target.dtd\">\
+            <target version=\"1.0\"><architecture>{architecture}</architecture><osabi>{}</osabi>",
+            self.osabi
+        );
+        let mut feature = "";
+        for (n, r) in self.threads[0].registers.iter().enumerate() {
+            if r.feature != feature {
+                if !feature.is_empty() {
+                    xml += "</feature>";
+                }
+                let _ = write!(xml, "<feature name=\"{}\">", r.feature);
+                feature = r.feature;
+            }
+            let _ = write!(
+                xml,
+                "<reg name=\"{}\" bitsize=\"{}\" type=\"{}\" regnum=\"{n}\"/>",
+                r.name,
+                r.value.len() * 8,
+                r.kind
+            );
+        }
+        xml + "</feature></target>"
+    }
+
+    /// How far the module loaded at `base` is from the addresses it was linked at, read from its
+    /// headers in the dump.
+    fn slide(&self, base: u64) -> Option<u64> {
+        let headers = self.read(base, usize::MAX)?;
+        let link = if let Some(elf) = Elf::parse(headers) {
+            let program_headers = elf.program_headers();
+            let loaded = program_headers.iter().any(|ph| ph.p_type == elf::PT_LOAD);
+            loaded.then(|| elf.base_address())?
+        } else if let Some(image) = MachO::file(macho::slice(headers), None) {
+            let text = image.segments().iter().find(|seg| seg.name == "__TEXT")?;
+            text.vmaddr
+        } else {
+            Pe::parse(headers)?.image_base
+        };
+        Some(base.wrapping_sub(link))
+    }
+
+    /// Where the dynamic section of the ELF image loaded at `base` is, `l_ld` in its `link_map`.
+    fn dynamic(&self, base: u64) -> Option<u64> {
+        let elf = Elf::parse(self.read(base, usize::MAX)?)?;
+        let dynamic = elf
+            .program_headers()
+            .into_iter()
+            .find(|ph| ph.p_type == elf::PT_DYNAMIC)?;
+        Some(self.slide(base)?.wrapping_add(dynamic.p_vaddr))
+    }
+
+    /// Every module but the main executable, which gdb relocates through `qOffsets`.
+    fn libraries_xml(&self) -> String {
+        let mut xml = "<library-list>".to_owned();
+        for (name, base) in self.modules.iter().skip(1) {
+            let _ = write!(
+                xml,
+                "<library name=\"{}\"><segment address=\"{base:#x}\"/></library>",
+                escape(name)
+            );
+        }
+        xml + "</library-list>"
+    }
+
+    /// The same modules as the `link_map` list gdb reads for GNU/Linux, which has no use for the
+    /// other one. The dump has no `link_map`s, their addresses are left 0.
+    fn libraries_svr4_xml(&self) -> String {
+        let mut xml = "<library-list-svr4 version=\"1.0\">".to_owned();
+        for &(ref name, base) in self.modules.iter().skip(1) {
+            // Libraries are linked at 0, when their headers aren't in the dump.
+            let l_addr = self.slide(base).unwrap_or(base);
+            let l_ld = self.dynamic(base).unwrap_or_default();
+            let _ = write!(
+                xml,
+                "<library name=\"{}\" lm=\"0x0\" l_addr=\"{l_addr:#x}\" l_ld=\"{l_ld:#x}\"/>",
+                escape(name)
+            );
+        }
+        xml + "</library-list-svr4>"
+    }
+}
+
+/// One debugger connection, answering its packets as a stopped target would.
+struct Session<'t, 'a> {
+    target: &'t Target<'a>,
+    /// The thread whose registers `g` and `p` read, set by `Hg`.
+    selected: usize,
+    ack: bool,
+    closed: bool,
+}
+
+impl<'t, 'a> Session<'t, 'a> {
+    fn new(target: &'t Target<'a>) -> Self {
+        Self {
+            target,
+            selected: target.current,
+            ack: true,
+            closed: false,
+        }
+    }
+
+    /// The reply to a packet, none to `k` which ends the session.
+    fn reply(&mut self, packet: &str) -> Option<String> {
+        let target = self.target;
+        let reply = match packet {
+            "QStartNoAckMode" => {
+                self.ack = false;
+                "OK".to_owned()
+            }
+            "?" => format!(
+                "T{:02x}thread:{:x};",
+                target.signal, target.threads[target.current].id
+            ),
+            "qC" => format!("QC{:x}", target.threads[target.current].id),
+            "qfThreadInfo" => {
+                let ids: Vec<String> = self
+                    .target
+                    .threads
+                    .iter()
+                    .map(|t| format!("{:x}", t.id))
+                    .collect();
+                format!("m{}", ids.join(","))
+            }
+            "qsThreadInfo" => "l".to_owned(),
+            "qAttached" => "1".to_owned(),
+            "qSymbol::" => "OK".to_owned(),
+            "qOffsets" => match target
+                .modules
+                .first()
+                .and_then(|&(_, base)| target.slide(base))
+            {
+                Some(slide) => format!("TextSeg={slide:x}"),
+                None => String::new(),
+            },
+            "g" if target.architecture.is_none() => "E01".to_owned(),
+            "g" => hex(&target.threads[self.selected]
+                .registers
+                .iter()
+                .flat_map(|r| r.value.iter().copied())
+                .collect::<Vec<_>>()),
+            "k" => {
+                self.closed = true;
+                return None;
+            }
+            "D" => {
+                self.closed = true;
+                "OK".to_owned()
+            }
+            "c" | "s" => "E01".to_owned(),
+            packet => {
+                if packet.starts_with("qSupported") {
+                    "PacketSize=4000;QStartNoAckMode+;qXfer:features:read+;\
+                    qXfer:libraries:read+;qXfer:libraries-svr4:read+"
+                        .to_owned()
+                } else if let Some(n) = packet.strip_prefix('p') {
+                    usize::from_str_radix(n, 16)
+                        .ok()
+                        .and_then(|n| target.threads[self.selected].registers.get(n))
+                        .map_or("E01".to_owned(), |r| hex(&r.value))
+                } else if let Some(thread) = packet.strip_prefix('H').and_then(|h| h.get(1..)) {
+                    match target.thread(thread) {
+                        Some(i) => {
+                            self.selected = i;
+                            "OK".to_owned()
+                        }
+                        None if matches!(thread, "0" | "-1") => "OK".to_owned(),
+                        None => "E01".to_owned(),
+                    }
+                } else if let Some(thread) = packet.strip_prefix('T') {
+                    match target.thread(thread) {
+                        Some(_) => "OK".to_owned(),
+                        None => "E01".to_owned(),
+                    }
+                } else if let Some(args) = packet.strip_prefix('m') {
+                    args.split_once(',')
+                        .and_then(|(address, len)| {
+                            let address = u64::from_str_radix(address, 16).ok()?;
+                            let len = usize::from_str_radix(len, 16).ok()?;
+                            target.read(address, len)
+                        })
+                        .map_or("E14".to_owned(), hex)
+                } else if let Some(args) = packet.strip_prefix("qXfer:features:read:target.xml:") {
+                    match target.architecture {
+                        Some(architecture) => xfer(&target.target_xml(architecture), args),
+                        None => "E00".to_owned(),
+                    }
+                } else if let Some(args) = packet.strip_prefix("qXfer:libraries:read::") {
+                    xfer(&target.libraries_xml(), args)
+                } else if let Some(args) = packet.strip_prefix("qXfer:libraries-svr4:read::") {
+                    xfer(&target.libraries_svr4_xml(), args)
+                } else if packet.starts_with("vCont;") || packet.starts_with(['C', 'S']) {
+                    "E01".to_owned()
+                } else {
+                    String::new()
+                }
+            }
+        };
+        Some(reply)
+    }
+}
+
+fn registers(context: &Context) -> Vec<Register> {
+    let mut registers = vec![];
+    let mut add = |feature, name: String, kind, value: &[u8]| {
+        registers.push(Register {
+            feature,
+            name,
+            kind,
+            value: value.to_vec(),
+        })
+    };
+    match context {
+        Context::X86(c) => {
+            const CORE: &str = "org.gnu.gdb.i386.core";
+            // In the order of the instruction encoding, as the context holds them.
+            for (i, name) in ["eax", "ecx", "edx", "ebx", "esp", "ebp", "esi", "edi"]
+                .into_iter()
+                .enumerate()
+            {
+                let kind = if i == 4 || i == 5 {
+                    "data_ptr"
+                } else {
+                    "int32"
+                };
+                add(CORE, name.to_owned(), kind, &c.gpr[i].to_le_bytes());
+            }
+            add(CORE, "eip".to_owned(), "code_ptr", &c.eip.to_le_bytes());
+            add(CORE, "eflags".to_owned(), "int32", &c.eflags.to_le_bytes());
+            segments(&mut add, CORE, c.segments);
+            fxsave(&mut add, CORE, "org.gnu.gdb.i386.sse", &c.fxsave, 8);
+        }
+        Context::Amd64(c) => {
+            const CORE: &str = "org.gnu.gdb.i386.core";
+            // The context holds them in the order of the instruction encoding.
+            let gprs = [
+                ("rax", 0),
+                ("rbx", 3),
+                ("rcx", 1),
+                ("rdx", 2),
+                ("rsi", 6),
+                ("rdi", 7),
+                ("rbp", 5),
+                ("rsp", 4),
+            ];
+            for (name, i) in gprs {
+                let kind = if i == 4 || i == 5 {
+                    "data_ptr"
+                } else {
+                    "int64"
+                };
+                add(CORE, name.to_owned(), kind, &c.gpr[i].to_le_bytes());
+            }
+            for i in 8..16 {
+                add(CORE, format!("r{i}"), "int64", &c.gpr[i].to_le_bytes());
+            }
+            add(CORE, "rip".to_owned(), "code_ptr", &c.rip.to_le_bytes());
+            add(CORE, "eflags".to_owned(), "int32", &c.eflags.to_le_bytes());
+            segments(&mut add, CORE, c.segments);
+            fxsave(&mut add, CORE, "org.gnu.gdb.i386.sse", &c.fxsave, 16);
+        }
+        Context::Arm64(c) => {
+            const CORE: &str = "org.gnu.gdb.aarch64.core";
+            const FPU: &str = "org.gnu.gdb.aarch64.fpu";
+            for (i, x) in c.x.iter().enumerate() {
+                add(CORE, format!("x{i}"), "int", &x.to_le_bytes());
+            }
+            add(CORE, "sp".to_owned(), "data_ptr", &c.sp.to_le_bytes());
+            add(CORE, "pc".to_owned(), "code_ptr", &c.pc.to_le_bytes());
+            add(CORE, "cpsr".to_owned(), "int", &c.cpsr.to_le_bytes());
+            for (i, v) in c.v.iter().enumerate() {
+                add(FPU, format!("v{i}"), "uint128", &v.to_le_bytes());
+            }
+            add(FPU, "fpsr".to_owned(), "int", &c.fpsr.to_le_bytes());
+            add(FPU, "fpcr".to_owned(), "int", &c.fpcr.to_le_bytes());
+        }
+    }
+    registers
+}
+
+/// The segment registers, from the context's `cs`, `ds`, `es`, `fs`, `gs` and `ss`.
+fn segments(
+    add: &mut impl FnMut(&'static str, String, &'static str, &[u8]),
+    feature: &'static str,
+    [cs, ds, es, fs, gs, ss]: [u16; 6],
+) {
+    for (name, segment) in [
+        ("cs", cs),
+        ("ss", ss),
+        ("ds", ds),
+        ("es", es),
+        ("fs", fs),
+        ("gs", gs),
+    ] {
+        add(
+            feature,
+            name.to_owned(),
+            "int32",
+            &(segment as u32).to_le_bytes(),
+        );
+    }
+}
+
+/// The x87 registers, then `xmm` ones of the SSE feature and `mxcsr`, from an `FXSAVE` area.
+fn fxsave(
+    add: &mut impl FnMut(&'static str, String, &'static str, &[u8]),
+    core: &'static str,
+    sse: &'static str,
+    fx: &[u8; 512],
+    xmm: usize,
+) {
+    for i in 0..8 {
+        add(
+            core,
+            format!("st{i}"),
+            "i387_ext",
+            &fx[32 + i * 16..42 + i * 16],
+        );
+    }
+    let u16_at = |offset: usize| u16::from_le_bytes([fx[offset], fx[offset + 1]]) as u32;
+    let u32_at = |offset: usize| u32::from_le_bytes(fx[offset..offset + 4].try_into().unwrap());
+    // FXSAVE abridges the tag word to a bit per register, set when it is not empty.
+    let ftag = (0..8)
+        .filter(|i| fx[4] & 1 << i == 0)
+        .fold(0, |tag, i| tag | 3 << (i * 2));
+    for (name, value) in [
+        ("fctrl", u16_at(0)),
+        ("fstat", u16_at(2)),
+        ("ftag", ftag),
+        ("fiseg", u16_at(12)),
+        ("fioff", u32_at(8)),
+        ("foseg", u16_at(20)),
+        ("fooff", u32_at(16)),
+        ("fop", u16_at(6)),
+    ] {
+        add(core, name.to_owned(), "int", &value.to_le_bytes());
+    }
+    for i in 0..xmm {
+        add(
+            sse,
+            format!("xmm{i}"),
+            "uint128",
+            &fx[160 + i * 16..176 + i * 16],
+        );
+    }
+    add(sse, "mxcsr".to_owned(), "int", &fx[24..28]);
+}
+
+/// The gdb signal number for the exception code of the dump's platform.
+fn signal(platform: u32, code: u32) -> u8 {
+    const SIGILL: u8 = 4;
+    const SIGTRAP: u8 = 5;
+    const SIGABRT: u8 = 6;
+    const SIGFPE: u8 = 8;
+    const SIGBUS: u8 = 10;
+    const SIGSEGV: u8 = 11;
+    match platform {
+        // gdb numbers signals as the original Unix did, which Linux follows for most of them.
+        minidump::PLATFORM_LINUX => match code {
+            7 => SIGBUS,
+            10 => 30,
+            12 => 31,
+            31 => 12,
+            1..=15 => code as u8,
+            _ => SIGTRAP,
+        },
+        minidump::PLATFORM_MAC_OS_X | minidump::PLATFORM_IOS => match code {
+            1 => SIGSEGV,
+            2 => SIGILL,
+            3 => SIGFPE,
+            10 => SIGABRT,
+            _ => SIGTRAP,
+        },
+        minidump::PLATFORM_WIN32_NT => match code {
+            0xc000_0005 | 0xc000_00fd => SIGSEGV,
+            0xc000_001d | 0xc000_0096 => SIGILL,
+            0xc000_008e | 0xc000_0094 => SIGFPE,
+            0xc000_0409 | 0x4000_0015 => SIGABRT,
+            _ => SIGTRAP,
+        },
+        _ => SIGTRAP,
+    }
+}
+
+/// Read the next `$<data>#<checksum>` packet, skipping acknowledgements and interrupts.
+fn read_packet(reader: &mut impl BufRead) -> io::Result<Option<String>> {
+    let mut byte = [0];
+    loop {
+        if reader.read(&mut byte)? == 0 {
+            return Ok(None);
+        }
+        if byte[0] == b'$' {
+            break;
+        }
+    }
+    let mut data = vec![];
+    reader.read_until(b'#', &mut data)?;
+    data.pop();
+    let mut checksum = [0; 2];
+    reader.read_exact(&mut checksum)?;
+    Ok(Some(String::from_utf8_lossy(&data).into_owned()))
+}
+
+fn send(writer: &mut impl Write, data: &[u8]) -> io::Result<()> {
+    let mut packet = vec![b'$'];
+    for &b in data {
+        if matches!(b, b'#' | b'$' | b'}' | b'*') {
+            packet.extend([b'}', b ^ 0x20]);
+        } else {
+            packet.push(b);
+        }
+    }
+    let checksum = packet[1..].iter().fold(0u8, |sum, &b| sum.wrapping_add(b));
+    packet.extend(format!("#{checksum:02x}").bytes());
+    writer.write_all(&packet)
+}
+
+/// The `offset,length` window of an object for a `qXfer` read, `l` marking its last part.
+fn xfer(object: &str, args: &str) -> String {
+    let Some((offset, len)) = args.split_once(',') else {
+        return "E00".to_owned();
+    };
+    let (Ok(offset), Ok(mut len)) = (
+        usize::from_str_radix(offset, 16),
+        usize::from_str_radix(len, 16),
+    ) else {
+        return "E00".to_owned();
+    };
+    let rest = object.get(offset..).unwrap_or_default();
+    if rest.len() <= len {
+        return format!("l{rest}");
+    }
+    while !rest.is_char_boundary(len) {
+        len -= 1;
+    }
+    format!("m{}", &rest[..len])
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use minidump::ContextX86;
+
+    fn target() -> Target<'static> {
+        let thread = |id, eax| Thread {
+            id,
+            registers: registers(&Context::X86(ContextX86 {
+                gpr: [eax, 0, 0, 0, 0xffe0, 0xfff0, 0, 0],
+                eip: 0x0804_9000,
+                eflags: 0x246,
+                segments: [0x23, 0x2b, 0x2b, 0, 0x63, 0x2b],
+                fxsave: [0; 512],
+            })),
+        };
+        Target {
+            architecture: Some("i386"),
+            osabi: "GNU/Linux",
+            threads: vec![thread(0x10, 1), thread(0x11, 2)],
+            current: 1,
+            signal: 11,
+            memory: vec![(0x1000, b"\x01\x02\x03\x04\x05\x06\x07\x08")],
+            modules: vec![
+                ("/bin/app".to_owned(), 0x0804_8000),
+                ("/lib/libc.so.6".to_owned(), 0xf7d0_0000),
+            ],
+        }
+    }
+
+    #[test]
+    fn packets() {
+        let target = target();
+        let mut session = Session::new(&target);
+        let mut reply = |packet| session.reply(packet);
+        assert_eq!(reply("?").as_deref(), Some("T0bthread:11;"));
+        assert_eq!(reply("qC").as_deref(), Some("QC11"));
+        assert_eq!(reply("qfThreadInfo").as_deref(), Some("m10,11"));
+        assert_eq!(reply("qsThreadInfo").as_deref(), Some("l"));
+        assert_eq!(reply("T10").as_deref(), Some("OK"));
+        assert_eq!(reply("T12").as_deref(), Some("E01"));
+
+        // eax, then eip and eflags, of the selected thread.
+        assert_eq!(reply("p0").as_deref(), Some("02000000"));
+        assert_eq!(reply("p8").as_deref(), Some("00900408"));
+        assert_eq!(reply("p9").as_deref(), Some("46020000"));
+        assert_eq!(reply("Hg10").as_deref(), Some("OK"));
+        assert_eq!(reply("p0").as_deref(), Some("01000000"));
+        assert_eq!(reply("Hg12").as_deref(), Some("E01"));
+        assert_eq!(reply("p999").as_deref(), Some("E01"));
+        // 16 registers of 4 bytes, 8 x87 ones of 10 and 8 of 4, 8 xmm ones of 16 and mxcsr.
+        let g = reply("g").unwrap();
+        assert_eq!(g.len(), (16 * 4 + 8 * 10 + 8 * 4 + 8 * 16 + 4) * 2);
+        assert!(g.starts_with("01000000"));
+
+        assert_eq!(reply("m1002,4").as_deref(), Some("03040506"));
+        assert_eq!(reply("m1006,10").as_deref(), Some("0708"));
+        assert_eq!(reply("m2000,4").as_deref(), Some("E14"));
+        assert_eq!(reply("mzz").as_deref(), Some("E14"));
+        // Nothing runs.
+        assert_eq!(reply("c").as_deref(), Some("E01"));
+        assert_eq!(reply("vCont;c").as_deref(), Some("E01"));
+        assert_eq!(reply("qOffsets").as_deref(), Some(""));
+        assert_eq!(reply("vMustReplyEmpty").as_deref(), Some(""));
+    }
+
+    #[test]
+    fn session_state() {
+        let target = target();
+        let mut session = Session::new(&target);
+        assert!(session.ack);
+        assert_eq!(session.reply("QStartNoAckMode").as_deref(), Some("OK"));
+        assert!(!session.ack);
+        assert_eq!(session.reply("D").as_deref(), Some("OK"));
+        assert!(session.closed);
+
+        let mut session = Session::new(&target);
+        assert_eq!(session.reply("k"), None);
+        assert!(session.closed);
+    }
+
+    #[test]
+    fn objects() {
+        let target = target();
+        let mut session = Session::new(&target);
+        let supported = session.reply("qSupported:multiprocess+").unwrap();
+        assert!(supported.contains("qXfer:libraries-svr4:read+"));
+
+        let xml = target.target_xml("i386");
+        assert!(xml.contains("<architecture>i386</architecture><osabi>GNU/Linux</osabi>"));
+        assert!(xml.contains("<feature name=\"org.gnu.gdb.i386.core\">"));
+        assert!(xml.contains("<reg name=\"eip\" bitsize=\"32\" type=\"code_ptr\" regnum=\"8\"/>"));
+        assert!(xml.contains("<reg name=\"xmm7\" bitsize=\"128\" type=\"uint128\" regnum=\"39\"/>"));
+        let part = session
+            .reply("qXfer:features:read:target.xml:0,10")
+            .unwrap();
+        assert_eq!(part, format!("m{}", &xml[..16]));
+        let rest = session.reply(&format!(
+            "qXfer:features:read:target.xml:10,{:x}",
+            xml.len()
+        ));
+        assert_eq!(rest, Some(format!("l{}", &xml[16..])));
+        assert_eq!(
+            session
+                .reply("qXfer:features:read:target.xml:zz")
+                .as_deref(),
+            Some("E00")
+        );
+
+        // Libraries without their headers in the dump are taken as linked at 0.
+        assert_eq!(
+            session.reply("qXfer:libraries-svr4:read::0,fff").as_deref(),
+            Some(
+                "l<library-list-svr4 version=\"1.0\"><library name=\"/lib/libc.so.6\" lm=\"0x0\" \
+                l_addr=\"0xf7d00000\" l_ld=\"0x0\"/></library-list-svr4>"
+            )
+        );
+        assert_eq!(
+            session.reply("qXfer:libraries:read::0,fff").as_deref(),
+            Some(
+                "l<library-list><library name=\"/lib/libc.so.6\">\
+                <segment address=\"0xf7d00000\"/></library></library-list>"
+            )
+        );
+    }
+
+    #[test]
+    fn escaping() {
+        let mut packet = vec![];
+        send(&mut packet, b"a#b").unwrap();
+        assert_eq!(packet, b"$a}\x03b#43");
+        let mut reader = &b"+$qC#b4"[..];
+        assert_eq!(read_packet(&mut reader).unwrap().as_deref(), Some("qC"));
+        assert_eq!(read_packet(&mut reader).unwrap(), None);
+    }
+}
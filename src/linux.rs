@@ -0,0 +1,1598 @@
+use crate::{
+    analysis,
+    condition::{self, Condition},
+    core::{self, Core},
+    elf::{self, Elf},
+    message, minidump, reason,
+    sample::{self, Stacks},
+    symbolizer::Symbolizer,
+    Format,
+};
+use std::{
+    collections::{HashMap, HashSet},
+    fs::{self, File, OpenOptions},
+    io::{self, Seek, Write},
+    mem,
+    os::unix::fs::FileExt,
+    path::{Path, PathBuf},
+    ptr,
+    str::FromStr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc, Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+const INT3: u8 = 0xcc;
+const AT_ENTRY: u64 = 9;
+
+#[allow(clippy::too_many_arguments)]
+pub unsafe fn inspect<W: Write + Seek>(
+    pid: i32,
+    session: bool,
+    catch_exc: bool,
+    catch_exit: bool,
+    catch_exec: bool,
+    follow_forks: bool,
+    breaks: &[String],
+    watches: &[Watch],
+    format: Format,
+    dumps: mpsc::Receiver<()>,
+    mut output_f: impl FnMut(i32) -> W,
+) -> Result<(), String> {
+    if !session {
+        return snapshot(pid, pid, None, &[], format, &mut output_f(pid));
+    }
+
+    let targets = Targets {
+        exit: catch_exit,
+        exec: catch_exec,
+        breaks,
+        watches,
+    };
+    let (stops, watchpoints) = targets.resolve(pid, true)?;
+    let mut options = libc::PTRACE_O_TRACECLONE | libc::PTRACE_O_TRACEEXEC;
+    if follow_forks {
+        options |= libc::PTRACE_O_TRACEFORK | libc::PTRACE_O_TRACEVFORK;
+    }
+
+    let mut tracee = Tracee::new(pid, options, stops, watchpoints);
+    tracee.attach();
+    tracee.insert_breakpoints();
+    tracee.set_watchpoints();
+    let mut tracees = HashMap::from([(pid, tracee)]);
+    if follow_forks {
+        for child in descendants(pid) {
+            let (stops, watchpoints) = targets.resolve(child, false).unwrap_or_default();
+            let mut tracee = Tracee::new(child, options, stops, watchpoints);
+            tracee.attach();
+            tracee.insert_breakpoints();
+            tracee.set_watchpoints();
+            tracees.insert(child, tracee);
+        }
+    }
+    // New threads and processes can report their first stop before the event creating them.
+    let mut unknown = HashSet::new();
+
+    let requested = Arc::new(AtomicBool::new(false));
+    {
+        let requested = requested.clone();
+        thread::spawn(move || {
+            for () in dumps {
+                requested.store(true, Ordering::SeqCst);
+                libc::kill(pid, libc::SIGSTOP);
+            }
+        });
+    }
+
+    println!("inspecting process: {}", pid);
+    loop {
+        adopt(&mut tracees, &mut unknown);
+        let Some(ev) = wait(pid, None) else {
+            break;
+        };
+        let (tid, status) = match ev {
+            Wait::Exited(tid) => {
+                if tid == pid {
+                    break;
+                }
+                for tracee in tracees.values_mut() {
+                    tracee.threads.remove(&tid);
+                }
+                if tracees.contains_key(&tid) {
+                    adopt(&mut tracees, &mut unknown);
+                    tracees.remove(&tid);
+                }
+                continue;
+            }
+            Wait::Stopped(tid, status) => (tid, status),
+        };
+        let Some(owner) = tracees
+            .values()
+            .find(|t| t.threads.contains(&tid))
+            .map(|t| t.pid)
+        else {
+            unknown.insert(tid);
+            continue;
+        };
+        let tracee = tracees.get_mut(&owner).unwrap();
+
+        let sig = libc::WSTOPSIG(status);
+        match status >> 16 {
+            0 => {}
+            event @ (libc::PTRACE_EVENT_CLONE
+            | libc::PTRACE_EVENT_FORK
+            | libc::PTRACE_EVENT_VFORK) => {
+                let new_tid = event_msg(tid) as i32;
+                if event == libc::PTRACE_EVENT_CLONE && tgid(new_tid) == owner {
+                    tracee.threads.insert(new_tid);
+                    if unknown.remove(&new_tid) {
+                        tracee.set_debug_registers(new_tid);
+                        cont(new_tid, 0);
+                    }
+                } else {
+                    tracee.forks.push(new_tid);
+                }
+                cont(tid, 0);
+                continue;
+            }
+            libc::PTRACE_EVENT_EXEC => {
+                tracee.exec();
+                cont(tid, 0);
+                continue;
+            }
+            libc::PTRACE_EVENT_STOP
+                if matches!(
+                    sig,
+                    libc::SIGSTOP | libc::SIGTSTP | libc::SIGTTIN | libc::SIGTTOU
+                ) =>
+            {
+                libc::ptrace(
+                    libc::PTRACE_LISTEN,
+                    tid,
+                    ptr::null_mut::<libc::c_void>(),
+                    ptr::null_mut::<libc::c_void>(),
+                );
+                continue;
+            }
+            _ => {
+                // Debug registers are not inherited by new threads.
+                tracee.set_debug_registers(tid);
+                cont(tid, 0);
+                continue;
+            }
+        }
+
+        let mut regs = regs(tid);
+        let stop = (sig == libc::SIGTRAP)
+            .then(|| {
+                tracee
+                    .breakpoints
+                    .get(&(regs.rip - 1))
+                    .map(|&(_, stop)| stop)
+            })
+            .flatten();
+        match stop {
+            Some(Stop::Entry) => {
+                regs.rip -= 1;
+                set_regs(tid, &regs);
+                let (stops, watchpoints) = targets.resolve(owner, false).unwrap_or_default();
+                tracee.entered(tid, stops, watchpoints);
+                cont(tid, 0);
+                continue;
+            }
+            Some(Stop::Exit) => {
+                regs.rip -= 1;
+                set_regs(tid, &regs);
+                tracee.dump(tid, None, &[], format, &mut output_f(owner));
+                libc::kill(owner, libc::SIGCONT);
+                adopt(&mut tracees, &mut unknown);
+                tracees.remove(&owner);
+                if owner == pid {
+                    break;
+                }
+                continue;
+            }
+            Some(Stop::Break | Stop::Exec) => {
+                regs.rip -= 1;
+                set_regs(tid, &regs);
+                let context = crash_context(owner, tid, &regs, &siginfo(tid));
+                tracee.dump(tid, Some(context), &[], format, &mut output_f(owner));
+                tracee.resume_over(tid, &regs);
+                continue;
+            }
+            None => {}
+        }
+
+        if sig == libc::SIGTRAP && tracee.watchpoint_hit(tid) {
+            let context = crash_context(owner, tid, &regs, &siginfo(tid));
+            tracee.dump(tid, Some(context), &[], format, &mut output_f(owner));
+            tracee.resume();
+        } else if sig == libc::SIGSTOP && owner == pid && requested.swap(false, Ordering::SeqCst) {
+            tracee.dump(tid, None, &[], format, &mut output_f(owner));
+            tracee.resume();
+        } else if catch_exc
+            && matches!(
+                sig,
+                libc::SIGSEGV
+                    | libc::SIGBUS
+                    | libc::SIGILL
+                    | libc::SIGFPE
+                    | libc::SIGABRT
+                    | libc::SIGSYS
+                    | libc::SIGTRAP
+            )
+        {
+            let info = siginfo(tid);
+            println!(
+                "exception: {}",
+                reason::signal(sig, info.si_code, info.si_addr() as u64)
+            );
+            let symbolizer = Symbolizer::new(&modules(owner));
+            let mem = &tracee.mem;
+            let read = |address, buf: &mut [u8]| mem.read_exact_at(buf, address).is_ok();
+            // `analyze` finds the message again when the dump keeps the memory it was read from.
+            let (message, memory) = message::locate(&symbolizer, regs.rsp, read).unzip();
+            if let Some(message) = message {
+                println!("{message}");
+            }
+            let context = crash_context(owner, tid, &regs, &info);
+            let memory = memory.unwrap_or_default();
+            tracee.dump(tid, Some(context), &memory, format, &mut output_f(owner));
+            // Faults raise again once the thread resumes, signals sent by a process don't.
+            if info.si_code <= 0 {
+                libc::syscall(libc::SYS_tgkill, owner, tid, sig);
+            }
+            libc::kill(owner, libc::SIGCONT);
+            adopt(&mut tracees, &mut unknown);
+            tracees.remove(&owner);
+            if owner == pid {
+                break;
+            }
+        } else {
+            cont(tid, sig);
+        }
+    }
+
+    // The monitoring ends with the process, leave its descendants running untraced.
+    adopt(&mut tracees, &mut unknown);
+    tracees.remove(&pid);
+    for tracee in tracees.values_mut() {
+        tracee.release();
+    }
+    Ok(())
+}
+
+#[derive(Clone, Copy)]
+enum Stop {
+    /// The entry point of a new image, where the other stops get resolved.
+    Entry,
+    Exit,
+    Exec,
+    Break,
+}
+
+/// Symbols and addresses to stop at, resolved against the images loaded in a process.
+struct Targets<'a> {
+    exit: bool,
+    exec: bool,
+    breaks: &'a [String],
+    watches: &'a [Watch],
+}
+
+impl Targets<'_> {
+    /// Missing or misaligned symbols are an error when `strict`, and are skipped otherwise.
+    #[allow(clippy::type_complexity)]
+    fn resolve(
+        &self,
+        pid: i32,
+        strict: bool,
+    ) -> Result<(HashMap<u64, Stop>, Vec<(u64, u64)>), String> {
+        let symbolizer = Symbolizer::new(&modules(pid));
+        let found = |name: &str, address: Option<_>| match address {
+            None if strict => Err(format!("symbol not found: {name}")),
+            address => Ok(address),
+        };
+
+        let mut stops = HashMap::new();
+        if self.exit {
+            stops.extend(found("_exit", symbolizer.lookup("_exit"))?.map(|a| (a, Stop::Exit)));
+        }
+        if self.exec {
+            stops.extend(found("execve", symbolizer.lookup("execve"))?.map(|a| (a, Stop::Exec)));
+            stops.extend(symbolizer.lookup("execveat").map(|a| (a, Stop::Exec)));
+        }
+        for name in self.breaks {
+            stops.extend(found(name, symbolizer.lookup(name))?.map(|a| (a, Stop::Break)));
+        }
+
+        let mut watchpoints = vec![];
+        for watch in self.watches {
+            let address = match watch.address() {
+                Some(address) => Some(address),
+                None => found(&watch.target, symbolizer.lookup(&watch.target))?,
+            };
+            match address {
+                Some(address) if address % watch.len == 0 => watchpoints.push((address, watch.len)),
+                Some(_) if strict => {
+                    return Err(format!(
+                        "{} is not aligned to the watch length {}",
+                        watch.target, watch.len
+                    ))
+                }
+                _ => {}
+            }
+        }
+        Ok((stops, watchpoints))
+    }
+}
+
+/// Trace the processes forked by the tracees, continuing those that already reported their first
+/// stop.
+fn adopt(tracees: &mut HashMap<i32, Tracee>, unknown: &mut HashSet<i32>) {
+    let children: Vec<Tracee> = tracees
+        .values_mut()
+        .flat_map(|t| {
+            mem::take(&mut t.forks)
+                .into_iter()
+                .map(|pid| t.fork(pid))
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    for child in children {
+        if unknown.remove(&child.pid) {
+            child.set_debug_registers(child.pid);
+            cont(child.pid, 0);
+        }
+        tracees.insert(child.pid, child);
+    }
+}
+
+/// Request a dump each time one of the conditions on the process's memory becomes true.
+pub fn poll(
+    pid: i32,
+    conditions: &[Condition],
+    interval: Duration,
+    dump_tx: mpsc::Sender<()>,
+) -> Result<(), String> {
+    let symbolizer = Symbolizer::new(&modules(pid));
+    let conditions = conditions
+        .iter()
+        .map(|c| {
+            let address = c
+                .address()
+                .or_else(|| symbolizer.lookup(&c.target))
+                .ok_or_else(|| format!("symbol not found: {}", c.target))?;
+            Ok((address, c.clone()))
+        })
+        .collect::<Result<_, String>>()?;
+    let mem = File::open(format!("/proc/{pid}/mem")).unwrap();
+    condition::poll(
+        conditions,
+        interval,
+        move |address, buf| mem.read_exact_at(buf, address).is_ok(),
+        dump_tx,
+    );
+    Ok(())
+}
+
+/// Sample the stacks of every thread, each stopped only while its own frames are walked.
+pub fn sample(pid: i32, hz: u32, duration: Duration) -> Stacks {
+    let mem = File::open(format!("/proc/{pid}/mem")).unwrap();
+    let read = |address, buf: &mut [u8]| mem.read_exact_at(buf, address).is_ok();
+    let mut loaded = modules(pid);
+    let mut symbolizer = Symbolizer::new(&loaded);
+    let mut refreshed = Instant::now();
+    let mut threads = HashSet::new();
+
+    let stacks = sample::sample(hz, duration, || {
+        for tid in tasks(pid) {
+            if !threads.contains(&tid) && seize(tid) {
+                threads.insert(tid);
+            }
+        }
+        // Pick up the libraries loaded since.
+        if refreshed.elapsed() >= Duration::from_secs(1) {
+            let latest = modules(pid);
+            if latest.len() != loaded.len()
+                || latest
+                    .iter()
+                    .zip(&loaded)
+                    .any(|(a, b)| a.path != b.path || a.load_address != b.load_address)
+            {
+                symbolizer = Symbolizer::new(&latest);
+                loaded = latest;
+            }
+            refreshed = Instant::now();
+        }
+
+        let mut backtraces = vec![];
+        threads.retain(|&tid| {
+            let Some(sig) = interrupt(pid, tid) else {
+                return false;
+            };
+            let regs = regs(tid);
+            backtraces.push(analysis::backtrace(
+                regs.rip,
+                regs.rsp,
+                regs.rbp,
+                8,
+                read,
+                &loaded,
+                &symbolizer,
+            ));
+            if sig == libc::SIGTRAP {
+                cont(tid, 0);
+            } else {
+                // Stopped by job control, leave it so.
+                unsafe {
+                    libc::ptrace(
+                        libc::PTRACE_LISTEN,
+                        tid,
+                        ptr::null_mut::<libc::c_void>(),
+                        ptr::null_mut::<libc::c_void>(),
+                    );
+                }
+            }
+            true
+        });
+        backtraces
+    });
+
+    for tid in threads {
+        unsafe {
+            libc::ptrace(
+                libc::PTRACE_DETACH,
+                tid,
+                ptr::null_mut::<libc::c_void>(),
+                ptr::null_mut::<libc::c_void>(),
+            );
+        }
+    }
+    stacks
+}
+
+/// Dump the process and its descendants, frozen together so that the dumps are consistent with
+/// each other.
+pub fn tree(
+    pid: i32,
+    format: Format,
+    mut output_path: impl FnMut(i32) -> PathBuf,
+) -> Result<super::Manifest, String> {
+    let freezer = Freezer::freeze(pid);
+    let time = chrono::Local::now().to_rfc3339();
+    let processes = freezer
+        .pids()
+        .iter()
+        .map(|&pid| {
+            let file = output_path(pid);
+            let mut output = File::create(&file).map_err(|e| format!("{}: {e}", file.display()))?;
+            snapshot(pid, pid, None, &[], format, &mut output)?;
+            Ok(super::Process {
+                pid,
+                ppid: ppid(pid),
+                file,
+            })
+        })
+        .collect::<Result<_, String>>()?;
+    Ok(super::Manifest {
+        time,
+        freezer: freezer.name(),
+        processes,
+    })
+}
+
+/// Breakpad's Linux streams of the process, readable while the kernel waits for the core handler
+/// to consume the core.
+pub fn proc_streams(pid: i32) -> Vec<(u32, Vec<u8>)> {
+    [
+        (minidump::LINUX_CPU_INFO, "/proc/cpuinfo".to_owned()),
+        (minidump::LINUX_PROC_STATUS, format!("/proc/{pid}/status")),
+        (minidump::LINUX_LSB_RELEASE, "/etc/lsb-release".to_owned()),
+        (minidump::LINUX_CMD_LINE, format!("/proc/{pid}/cmdline")),
+        (minidump::LINUX_ENVIRON, format!("/proc/{pid}/environ")),
+        (minidump::LINUX_MAPS, format!("/proc/{pid}/maps")),
+    ]
+    .into_iter()
+    .filter_map(|(kind, path)| Some((kind, fs::read(path).ok()?)))
+    .collect()
+}
+
+/// Kernel release, version and machine, as in `uname -rvm`.
+pub fn os_version() -> String {
+    let read = |name| fs::read_to_string(format!("/proc/sys/kernel/{name}")).unwrap_or_default();
+    format!(
+        "{} {} x86_64",
+        read("osrelease").trim(),
+        read("version").trim()
+    )
+}
+
+/// Keeps a process tree from running, through the cgroup v2 freezer if the tree has a cgroup of
+/// its own that can be frozen, or by stopping each process otherwise. Dropping it thaws the tree.
+enum Freezer {
+    Cgroup { path: PathBuf, pids: Vec<i32> },
+    Signal { pids: Vec<i32>, stopped: Vec<i32> },
+}
+
+impl Freezer {
+    fn freeze(pid: i32) -> Self {
+        let pids = [vec![pid], descendants(pid)].concat();
+        // Writing the freezer fails without the permission to, in a cgroup owned by root.
+        let cgroup =
+            cgroup(&pids).filter(|path| fs::write(path.join("cgroup.freeze"), "1").is_ok());
+        if let Some(path) = cgroup {
+            for _ in 0..100 {
+                let events = fs::read_to_string(path.join("cgroup.events")).unwrap_or_default();
+                if events.lines().any(|line| line == "frozen 1") {
+                    return Freezer::Cgroup { path, pids };
+                }
+                thread::sleep(Duration::from_millis(10));
+            }
+            let _ = fs::write(path.join("cgroup.freeze"), "0");
+        }
+
+        // Stop from the top down, until no process forked in the meantime is left running.
+        let mut pids = vec![];
+        let mut stopped = vec![];
+        loop {
+            let new: Vec<i32> = [vec![pid], descendants(pid)]
+                .concat()
+                .into_iter()
+                .filter(|pid| !pids.contains(pid))
+                .collect();
+            if new.is_empty() {
+                break;
+            }
+            for &pid in &new {
+                if !frozen(pid) {
+                    unsafe {
+                        libc::kill(pid, libc::SIGSTOP);
+                    }
+                    stopped.push(pid);
+                }
+            }
+            for &pid in &new {
+                for _ in 0..100 {
+                    if frozen(pid) {
+                        break;
+                    }
+                    thread::sleep(Duration::from_millis(10));
+                }
+            }
+            pids.extend(new);
+        }
+        Freezer::Signal { pids, stopped }
+    }
+
+    fn pids(&self) -> &[i32] {
+        match self {
+            Freezer::Cgroup { pids, .. } | Freezer::Signal { pids, .. } => pids,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Freezer::Cgroup { .. } => "cgroup",
+            Freezer::Signal { .. } => "signal",
+        }
+    }
+}
+
+impl Drop for Freezer {
+    /// Let the processes run again, leaving those that were stopped already.
+    fn drop(&mut self) {
+        match self {
+            Freezer::Cgroup { path, .. } => {
+                let _ = fs::write(path.join("cgroup.freeze"), "0");
+            }
+            Freezer::Signal { stopped, .. } => {
+                for &pid in stopped.iter() {
+                    unsafe {
+                        libc::kill(pid, libc::SIGCONT);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The cgroup v2 directory holding exactly these processes, and no child cgroup.
+fn cgroup(pids: &[i32]) -> Option<PathBuf> {
+    let mountinfo = fs::read_to_string("/proc/self/mountinfo").ok()?;
+    let mount = mountinfo.lines().find_map(|line| {
+        let (fields, fs) = line.split_once(" - ")?;
+        fs.starts_with("cgroup2 ")
+            .then(|| fields.split(' ').nth(4))
+            .flatten()
+    })?;
+    let cgroups = pids
+        .iter()
+        .map(|pid| {
+            let cgroup = fs::read_to_string(format!("/proc/{pid}/cgroup")).ok()?;
+            cgroup
+                .lines()
+                .find_map(|line| line.strip_prefix("0::"))
+                .map(str::to_owned)
+        })
+        .collect::<Option<HashSet<_>>>()?;
+    if cgroups.len() != 1 {
+        return None;
+    }
+    let cgroup = cgroups.into_iter().next()?;
+    let path = Path::new(mount).join(cgroup.trim_start_matches('/'));
+
+    let procs: HashSet<i32> = fs::read_to_string(path.join("cgroup.procs"))
+        .ok()?
+        .lines()
+        .filter_map(|line| line.parse().ok())
+        .collect();
+    let stat = fs::read_to_string(path.join("cgroup.stat")).ok()?;
+    let leaf = stat.lines().any(|line| line == "nr_descendants 0");
+    (path.join("cgroup.freeze").exists() && leaf && procs == pids.iter().copied().collect())
+        .then_some(path)
+}
+
+/// `<addr|symbol>[:len]`, the bytes a watchpoint covers.
+#[derive(Clone)]
+pub struct Watch {
+    target: String,
+    len: u64,
+}
+
+impl Watch {
+    /// The address of an `0x` prefixed target.
+    fn address(&self) -> Option<u64> {
+        let hex = self.target.strip_prefix("0x")?;
+        u64::from_str_radix(hex, 16).ok()
+    }
+}
+
+impl FromStr for Watch {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (target, len) = s
+            .rsplit_once(':')
+            .and_then(|(target, len)| Some((target, len.parse().ok()?)))
+            .unwrap_or((s, 8));
+        if !matches!(len, 1 | 2 | 4 | 8) {
+            return Err(format!("watch length must be 1, 2, 4 or 8, not {len}"));
+        }
+        if target.is_empty() {
+            return Err("missing symbol or address".to_owned());
+        }
+        let watch = Self {
+            target: target.to_owned(),
+            len,
+        };
+        if target.starts_with("0x") {
+            let address = watch
+                .address()
+                .ok_or_else(|| format!("invalid address: {target}"))?;
+            if address % len != 0 {
+                return Err(format!("{target} is not aligned to the watch length {len}"));
+            }
+        }
+        Ok(watch)
+    }
+}
+
+/// Threads of a process traced with `PTRACE_SEIZE`, and the breakpoints and watchpoints
+/// inserted into it.
+struct Tracee {
+    pid: i32,
+    options: libc::c_int,
+    mem: File,
+    threads: HashSet<i32>,
+    breakpoints: HashMap<u64, (u8, Stop)>,
+    watchpoints: Vec<(u64, u64)>,
+    /// Processes forked since, waiting to be traced on their own.
+    forks: Vec<i32>,
+}
+
+impl Tracee {
+    fn new(
+        pid: i32,
+        options: libc::c_int,
+        stops: HashMap<u64, Stop>,
+        watchpoints: Vec<(u64, u64)>,
+    ) -> Self {
+        let mut tracee = Self {
+            pid,
+            options,
+            mem: open_mem(pid),
+            threads: HashSet::new(),
+            breakpoints: HashMap::new(),
+            watchpoints,
+            forks: vec![],
+        };
+        tracee.set_breakpoints(stops);
+        tracee
+    }
+
+    /// A process forked by this one, which inherited its breakpoints.
+    fn fork(&self, pid: i32) -> Self {
+        Self {
+            pid,
+            options: self.options,
+            mem: open_mem(pid),
+            threads: HashSet::from([pid]),
+            breakpoints: self.breakpoints.clone(),
+            watchpoints: self.watchpoints.clone(),
+            forks: vec![],
+        }
+    }
+
+    /// Start over after exec replaced the image, stopping at its entry point once the loader is
+    /// done.
+    fn exec(&mut self) {
+        self.mem = open_mem(self.pid);
+        self.threads = HashSet::from([self.pid]);
+        self.watchpoints.clear();
+        self.breakpoints.clear();
+        self.set_breakpoints(HashMap::from([(entry(self.pid), Stop::Entry)]));
+        self.insert_breakpoints();
+    }
+
+    /// Replace the entry point breakpoint of a new image with the stops resolved against it.
+    fn entered(&mut self, tid: i32, stops: HashMap<u64, Stop>, watchpoints: Vec<(u64, u64)>) {
+        self.remove_breakpoints();
+        self.breakpoints.clear();
+        self.set_breakpoints(stops);
+        self.insert_breakpoints();
+        self.watchpoints = watchpoints;
+        self.set_debug_registers(tid);
+    }
+
+    fn set_breakpoints(&mut self, stops: HashMap<u64, Stop>) {
+        for (address, stop) in stops {
+            let mut orig = [0];
+            self.mem.read_exact_at(&mut orig, address).unwrap();
+            self.breakpoints.insert(address, (orig[0], stop));
+        }
+    }
+
+    /// Re-attach after a dump and let the process continue.
+    fn resume(&mut self) {
+        self.attach();
+        self.insert_breakpoints();
+        self.set_watchpoints();
+        unsafe {
+            libc::kill(self.pid, libc::SIGCONT);
+        }
+    }
+
+    /// Resume after a dump at the breakpoint `regs` stopped at, stepping `tid` over it while the
+    /// breakpoints are out and the other threads still stopped.
+    fn resume_over(&mut self, tid: i32, regs: &libc::user_regs_struct) {
+        self.attach();
+        self.step_over(tid, regs);
+        self.insert_breakpoints();
+        // Stepping took the stop the other threads report on attaching, `SIGCONT` alone would
+        // leave it stopped.
+        cont(tid, 0);
+        self.set_watchpoints();
+        unsafe {
+            libc::kill(self.pid, libc::SIGCONT);
+        }
+    }
+
+    /// Remove the breakpoints and watchpoints, and detach from the process.
+    fn release(&mut self) {
+        let pending = self.stop(0);
+        self.remove_breakpoints();
+        for tid in self.threads.drain() {
+            if !self.watchpoints.is_empty() {
+                poke_user(tid, debug_register(7), 0);
+            }
+            unsafe {
+                libc::ptrace(
+                    libc::PTRACE_DETACH,
+                    tid,
+                    ptr::null_mut::<libc::c_void>(),
+                    ptr::null_mut::<libc::c_void>(),
+                );
+            }
+        }
+        for (tid, sig) in pending {
+            unsafe {
+                libc::syscall(libc::SYS_tgkill, self.pid, tid, sig);
+            }
+        }
+    }
+
+    /// Seize every thread, until no new thread shows up.
+    fn attach(&mut self) {
+        let mut seen = HashSet::new();
+        loop {
+            let tids: Vec<i32> = tasks(self.pid)
+                .into_iter()
+                .filter(|&tid| seen.insert(tid))
+                .collect();
+            if tids.is_empty() {
+                break;
+            }
+            for tid in tids {
+                let r = unsafe {
+                    libc::ptrace(
+                        libc::PTRACE_SEIZE,
+                        tid,
+                        ptr::null_mut::<libc::c_void>(),
+                        self.options as libc::c_ulong,
+                    )
+                };
+                if r == 0 {
+                    self.threads.insert(tid);
+                }
+            }
+        }
+        assert!(self.threads.contains(&self.pid), "failed to attach");
+    }
+
+    fn insert_breakpoints(&self) {
+        for &address in self.breakpoints.keys() {
+            self.mem.write_all_at(&[INT3], address).unwrap();
+        }
+    }
+
+    fn remove_breakpoints(&self) {
+        for (&address, &(orig, _)) in &self.breakpoints {
+            self.mem.write_all_at(&[orig], address).unwrap();
+        }
+    }
+
+    /// Program the debug registers of every thread, which have to be stopped for it.
+    fn set_watchpoints(&mut self) {
+        if self.watchpoints.is_empty() {
+            return;
+        }
+        let pending: HashMap<i32, i32> = self.stop(0).into_iter().collect();
+        for &tid in &self.threads {
+            self.set_debug_registers(tid);
+            cont(tid, pending.get(&tid).copied().unwrap_or_default());
+        }
+    }
+
+    fn set_debug_registers(&self, tid: i32) {
+        if self.watchpoints.is_empty() {
+            return;
+        }
+        let mut dr7 = 0;
+        for (i, &(address, len)) in self.watchpoints.iter().enumerate() {
+            poke_user(tid, debug_register(i), address);
+            // Break on data writes only.
+            let rw_len = 0b01
+                | match len {
+                    1 => 0b00,
+                    2 => 0b01,
+                    8 => 0b10,
+                    _ => 0b11,
+                } << 2;
+            dr7 |= 1 << (i * 2) | rw_len << (16 + i * 4);
+        }
+        poke_user(tid, debug_register(7), dr7);
+    }
+
+    /// Check and clear the status of the debug registers after a `SIGTRAP`.
+    fn watchpoint_hit(&self, tid: i32) -> bool {
+        if self.watchpoints.is_empty() {
+            return false;
+        }
+        let dr6 = peek_user(tid, debug_register(6));
+        poke_user(tid, debug_register(6), 0);
+        dr6 & 0xf != 0
+    }
+
+    /// Execute the original instruction under the breakpoint `regs` stopped at, the breakpoints
+    /// being removed. Signals arriving meanwhile are queued again, to be delivered when the thread
+    /// is next continued.
+    fn step_over(&self, tid: i32, regs: &libc::user_regs_struct) {
+        set_regs(tid, regs);
+        let mut pending = vec![];
+        unsafe {
+            loop {
+                libc::ptrace(
+                    libc::PTRACE_SINGLESTEP,
+                    tid,
+                    ptr::null_mut::<libc::c_void>(),
+                    ptr::null_mut::<libc::c_void>(),
+                );
+                let mut status = 0;
+                if libc::waitpid(tid, &mut status, libc::__WALL) == -1 || !libc::WIFSTOPPED(status)
+                {
+                    return;
+                }
+                // A signal stops the thread before the instruction, and must not be delivered
+                // while the breakpoint is out.
+                match (libc::WSTOPSIG(status), status >> 16) {
+                    (libc::SIGTRAP, 0) => break,
+                    (sig, 0) => pending.push(sig),
+                    _ => {}
+                }
+            }
+        }
+        for sig in pending {
+            unsafe {
+                libc::syscall(libc::SYS_tgkill, self.pid, tid, sig);
+            }
+        }
+    }
+
+    /// Interrupt every thread but `tid`, which is already stopped.
+    fn stop(&mut self, tid: i32) -> Vec<(i32, i32)> {
+        let mut waiting: HashSet<i32> =
+            self.threads.iter().copied().filter(|&t| t != tid).collect();
+        for &t in &waiting {
+            unsafe {
+                libc::ptrace(
+                    libc::PTRACE_INTERRUPT,
+                    t,
+                    ptr::null_mut::<libc::c_void>(),
+                    ptr::null_mut::<libc::c_void>(),
+                );
+            }
+        }
+
+        let mut pending = vec![];
+        while let Some(&t) = waiting.iter().next() {
+            waiting.remove(&t);
+            match wait(self.pid, Some(t)) {
+                Some(Wait::Stopped(_, status)) => {
+                    let sig = libc::WSTOPSIG(status);
+                    match status >> 16 {
+                        0 if sig != libc::SIGSTOP => pending.push((t, sig)),
+                        event @ (libc::PTRACE_EVENT_CLONE
+                        | libc::PTRACE_EVENT_FORK
+                        | libc::PTRACE_EVENT_VFORK) => {
+                            let new_tid = event_msg(t) as i32;
+                            if event == libc::PTRACE_EVENT_CLONE && tgid(new_tid) == self.pid {
+                                self.threads.insert(new_tid);
+                                waiting.insert(new_tid);
+                            } else {
+                                self.forks.push(new_tid);
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                Some(Wait::Exited(_)) | None => {
+                    self.threads.remove(&t);
+                }
+            }
+        }
+        pending
+    }
+
+    /// Detach from every thread, leaving the process stopped, so that the minidump writer can
+    /// attach to it.
+    fn detach(&mut self, pending: &[(i32, i32)]) {
+        for tid in self.threads.drain() {
+            unsafe {
+                libc::ptrace(
+                    libc::PTRACE_DETACH,
+                    tid,
+                    ptr::null_mut::<libc::c_void>(),
+                    libc::SIGSTOP as libc::c_ulong,
+                );
+            }
+        }
+        for &(tid, sig) in pending {
+            unsafe {
+                libc::syscall(libc::SYS_tgkill, self.pid, tid, sig);
+            }
+        }
+
+        for _ in 0..100 {
+            let stopped = states(self.pid)
+                .into_iter()
+                .all(|state| state != 'R' && state != 'S');
+            if stopped {
+                break;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    fn dump<W: Write + Seek>(
+        &mut self,
+        tid: i32,
+        context: Option<crash_context::CrashContext>,
+        memory: &[(u64, usize)],
+        format: Format,
+        output: &mut W,
+    ) {
+        let pending = self.stop(tid);
+        self.remove_breakpoints();
+        self.detach(&pending);
+        // The process is resumed either way, monitoring goes on.
+        if let Err(error) = snapshot(self.pid, tid, context, memory, format, output) {
+            eprintln!("failed to write dump: {error}");
+        }
+    }
+}
+
+/// `memory` are ranges to dump besides the stacks and what the format keeps of the process.
+fn snapshot<W: Write + Seek>(
+    pid: i32,
+    blamed_thread: i32,
+    context: Option<crash_context::CrashContext>,
+    memory: &[(u64, usize)],
+    format: Format,
+    output: &mut W,
+) -> Result<(), String> {
+    match format {
+        Format::Minidump => {
+            let mut writer =
+                minidump_writer::minidump_writer::MinidumpWriter::new(pid, blamed_thread);
+            if let Some(inner) = context {
+                writer.set_crash_context(minidump_writer::crash_context::CrashContext { inner });
+            }
+            writer.set_app_memory(
+                memory
+                    .iter()
+                    .map(|&(ptr, length)| minidump_writer::app_memory::AppMemory {
+                        ptr: ptr as usize,
+                        length,
+                    })
+                    .collect(),
+            );
+            writer.dump(output).map_err(|e| e.to_string())?;
+        }
+        Format::Core => {
+            let core = live_core(pid, blamed_thread, context, memory);
+            core.write(io::BufWriter::new(output))
+                .map_err(|e| e.to_string())?;
+        }
+    }
+    Ok(())
+}
+
+/// Read an ELF core out of the process, the blamed thread first and the memory selected by its
+/// `coredump_filter`, as the kernel would, plus the mappings holding any of `wanted`.
+fn live_core(
+    pid: i32,
+    blamed_thread: i32,
+    context: Option<crash_context::CrashContext>,
+    wanted: &[(u64, usize)],
+) -> Core {
+    let mut tids = tasks(pid);
+    tids.sort_by_key(|&tid| tid != blamed_thread);
+    let mut threads = vec![];
+    for tid in tids {
+        unsafe {
+            libc::ptrace(
+                libc::PTRACE_SEIZE,
+                tid,
+                ptr::null_mut::<libc::c_void>(),
+                ptr::null_mut::<libc::c_void>(),
+            );
+            libc::ptrace(
+                libc::PTRACE_INTERRUPT,
+                tid,
+                ptr::null_mut::<libc::c_void>(),
+                ptr::null_mut::<libc::c_void>(),
+            );
+        }
+        if !matches!(wait(pid, Some(tid)), Some(Wait::Stopped(..))) {
+            continue;
+        }
+        let mut fxsave = [0u8; 512];
+        unsafe {
+            libc::ptrace(
+                libc::PTRACE_GETFPREGS,
+                tid,
+                ptr::null_mut::<libc::c_void>(),
+                fxsave.as_mut_ptr(),
+            );
+        }
+        threads.push(core::Thread {
+            tid,
+            regs: unsafe { mem::transmute::<libc::user_regs_struct, core::UserRegs>(regs(tid)) },
+            fxsave,
+        });
+    }
+
+    let mut signal = None;
+    if let (Some(context), Some(thread)) = (context, threads.first_mut()) {
+        let gregs = &context.context.uc_mcontext.gregs;
+        let r = &mut thread.regs;
+        for (reg, value) in [
+            (libc::REG_R8, &mut r.r8),
+            (libc::REG_R9, &mut r.r9),
+            (libc::REG_R10, &mut r.r10),
+            (libc::REG_R11, &mut r.r11),
+            (libc::REG_R12, &mut r.r12),
+            (libc::REG_R13, &mut r.r13),
+            (libc::REG_R14, &mut r.r14),
+            (libc::REG_R15, &mut r.r15),
+            (libc::REG_RDI, &mut r.rdi),
+            (libc::REG_RSI, &mut r.rsi),
+            (libc::REG_RBP, &mut r.rbp),
+            (libc::REG_RBX, &mut r.rbx),
+            (libc::REG_RDX, &mut r.rdx),
+            (libc::REG_RAX, &mut r.rax),
+            (libc::REG_RCX, &mut r.rcx),
+            (libc::REG_RSP, &mut r.rsp),
+            (libc::REG_RIP, &mut r.rip),
+            (libc::REG_EFL, &mut r.eflags),
+        ] {
+            *value = gregs[reg as usize] as u64;
+        }
+        thread.fxsave =
+            unsafe { mem::transmute::<crash_context::fpregset_t, [u8; 512]>(context.float_state) };
+        signal = Some(core::Signal {
+            signo: context.siginfo.ssi_signo as i32,
+            code: context.siginfo.ssi_code,
+            address: context.siginfo.ssi_addr,
+        });
+    }
+
+    let filter = fs::read_to_string(format!("/proc/{pid}/coredump_filter"))
+        .ok()
+        .and_then(|filter| u32::from_str_radix(filter.trim(), 16).ok())
+        .unwrap_or(0x33);
+    let mem = open_mem(pid);
+    let maps = fs::read_to_string(format!("/proc/{pid}/maps")).unwrap_or_default();
+    let mut mappings = vec![];
+    let mut memory = vec![];
+    for line in maps.lines() {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let [range, perms, offset, _, inode, ..] = fields[..] else {
+            continue;
+        };
+        let (Some((start, end)), Ok(offset)) =
+            (range.split_once('-'), u64::from_str_radix(offset, 16))
+        else {
+            continue;
+        };
+        let (Ok(start), Ok(end)) = (u64::from_str_radix(start, 16), u64::from_str_radix(end, 16))
+        else {
+            continue;
+        };
+        let path = fields.get(5).copied().unwrap_or_default();
+        if path.starts_with('/') {
+            mappings.push(core::Mapping {
+                start,
+                end,
+                offset,
+                path: path.to_owned(),
+            });
+        }
+
+        let perms = perms.as_bytes();
+        if perms[0] != b'r' || path == "[vvar]" {
+            continue;
+        }
+        // Bits of `coredump_filter`: anonymous private and shared, file-backed private and
+        // shared, then ELF headers. Written private file mappings count as anonymous.
+        let file = inode != "0";
+        let bit = match (file, perms[3] == b's') {
+            (false, false) => 0,
+            (false, true) => 1,
+            (true, false) if perms[1] == b'w' => 0,
+            (true, false) => 2,
+            (true, true) => 3,
+        };
+        let wanted = wanted
+            .iter()
+            .any(|&(address, len)| address < end && address + len as u64 > start);
+        let size = if filter & 1 << bit != 0 || wanted {
+            end - start
+        } else if file && offset == 0 && filter & 1 << 4 != 0 {
+            core::HEADER_SIZE.min(end - start)
+        } else {
+            continue;
+        };
+        let mut data = vec![0; size as usize];
+        if mem.read_exact_at(&mut data, start).is_ok() {
+            memory.push((start, data));
+        }
+    }
+
+    let cmdline = fs::read(format!("/proc/{pid}/cmdline")).unwrap_or_default();
+    for thread in &threads {
+        unsafe {
+            libc::ptrace(
+                libc::PTRACE_DETACH,
+                thread.tid,
+                ptr::null_mut::<libc::c_void>(),
+                ptr::null_mut::<libc::c_void>(),
+            );
+        }
+    }
+    Core {
+        pid,
+        args: String::from_utf8_lossy(&cmdline)
+            .trim_end_matches('\0')
+            .replace('\0', " "),
+        threads,
+        signal,
+        auxv: fs::read(format!("/proc/{pid}/auxv")).unwrap_or_default(),
+        mappings,
+        memory,
+    }
+}
+
+enum Wait {
+    Exited(i32),
+    Stopped(i32, i32),
+}
+
+/// Wait for the next event of the traced thread `tid`, or of any if `None`. The exit of `pid` is
+/// left for its parent to reap.
+fn wait(pid: i32, tid: Option<i32>) -> Option<Wait> {
+    let (idtype, id) = match tid {
+        Some(tid) => (libc::P_PID, tid as libc::id_t),
+        None => (libc::P_ALL, 0),
+    };
+    unsafe {
+        loop {
+            let mut info: libc::siginfo_t = mem::zeroed();
+            let r = libc::waitid(
+                idtype,
+                id,
+                &mut info,
+                libc::WEXITED | libc::WSTOPPED | libc::WNOWAIT | libc::__WALL,
+            );
+            if r == -1 {
+                if io::Error::last_os_error().kind() == io::ErrorKind::Interrupted {
+                    continue;
+                }
+                return None;
+            }
+
+            let tid = info.si_pid();
+            let exited = matches!(
+                info.si_code,
+                libc::CLD_EXITED | libc::CLD_KILLED | libc::CLD_DUMPED
+            );
+            if tid == pid && exited {
+                return Some(Wait::Exited(tid));
+            }
+
+            let mut status = 0;
+            libc::waitpid(tid, &mut status, libc::__WALL);
+            if exited {
+                return Some(Wait::Exited(tid));
+            }
+            return Some(Wait::Stopped(tid, status));
+        }
+    }
+}
+
+fn seize(tid: i32) -> bool {
+    unsafe {
+        libc::ptrace(
+            libc::PTRACE_SEIZE,
+            tid,
+            ptr::null_mut::<libc::c_void>(),
+            ptr::null_mut::<libc::c_void>(),
+        ) == 0
+    }
+}
+
+/// Stop a seized thread, passing on the signals it receives meanwhile, and return the signal of
+/// the stop: `SIGTRAP` for the interrupt, or the signal of a group stop. `None` once it's gone.
+fn interrupt(pid: i32, tid: i32) -> Option<i32> {
+    let r = unsafe {
+        libc::ptrace(
+            libc::PTRACE_INTERRUPT,
+            tid,
+            ptr::null_mut::<libc::c_void>(),
+            ptr::null_mut::<libc::c_void>(),
+        )
+    };
+    if r == -1 {
+        return None;
+    }
+    loop {
+        let Some(Wait::Stopped(_, status)) = wait(pid, Some(tid)) else {
+            return None;
+        };
+        let sig = libc::WSTOPSIG(status);
+        match status >> 16 {
+            libc::PTRACE_EVENT_STOP => return Some(sig),
+            0 => cont(tid, sig),
+            _ => cont(tid, 0),
+        }
+    }
+}
+
+fn event_msg(tid: i32) -> u64 {
+    let mut msg: libc::c_ulong = 0;
+    unsafe {
+        libc::ptrace(
+            libc::PTRACE_GETEVENTMSG,
+            tid,
+            ptr::null_mut::<libc::c_void>(),
+            &mut msg,
+        );
+    }
+    msg
+}
+
+fn cont(tid: i32, sig: i32) {
+    unsafe {
+        libc::ptrace(
+            libc::PTRACE_CONT,
+            tid,
+            ptr::null_mut::<libc::c_void>(),
+            sig as libc::c_ulong,
+        );
+    }
+}
+
+fn debug_register(i: usize) -> usize {
+    mem::offset_of!(libc::user, u_debugreg) + i * mem::size_of::<u64>()
+}
+
+fn peek_user(tid: i32, offset: usize) -> u64 {
+    unsafe {
+        libc::ptrace(
+            libc::PTRACE_PEEKUSER,
+            tid,
+            offset as *mut libc::c_void,
+            ptr::null_mut::<libc::c_void>(),
+        ) as u64
+    }
+}
+
+fn poke_user(tid: i32, offset: usize, value: u64) {
+    unsafe {
+        let r = libc::ptrace(
+            libc::PTRACE_POKEUSER,
+            tid,
+            offset as *mut libc::c_void,
+            value as *mut libc::c_void,
+        );
+        assert_ne!(r, -1);
+    }
+}
+
+fn regs(tid: i32) -> libc::user_regs_struct {
+    unsafe {
+        let mut regs = mem::zeroed();
+        let r = libc::ptrace(
+            libc::PTRACE_GETREGS,
+            tid,
+            ptr::null_mut::<libc::c_void>(),
+            &mut regs as *mut libc::user_regs_struct,
+        );
+        assert_ne!(r, -1);
+        regs
+    }
+}
+
+fn set_regs(tid: i32, regs: &libc::user_regs_struct) {
+    unsafe {
+        let r = libc::ptrace(
+            libc::PTRACE_SETREGS,
+            tid,
+            ptr::null_mut::<libc::c_void>(),
+            regs as *const libc::user_regs_struct,
+        );
+        assert_ne!(r, -1);
+    }
+}
+
+fn siginfo(tid: i32) -> libc::siginfo_t {
+    unsafe {
+        let mut info = mem::zeroed();
+        let r = libc::ptrace(
+            libc::PTRACE_GETSIGINFO,
+            tid,
+            ptr::null_mut::<libc::c_void>(),
+            &mut info as *mut libc::siginfo_t,
+        );
+        assert_ne!(r, -1);
+        info
+    }
+}
+
+fn crash_context(
+    pid: i32,
+    tid: i32,
+    regs: &libc::user_regs_struct,
+    info: &libc::siginfo_t,
+) -> crash_context::CrashContext {
+    unsafe {
+        let mut fpregs: libc::user_fpregs_struct = mem::zeroed();
+        libc::ptrace(
+            libc::PTRACE_GETFPREGS,
+            tid,
+            ptr::null_mut::<libc::c_void>(),
+            &mut fpregs as *mut libc::user_fpregs_struct,
+        );
+
+        let mut context: crash_context::CrashContext = mem::zeroed();
+        let gregs = &mut context.context.uc_mcontext.gregs;
+        for (reg, value) in [
+            (libc::REG_R8, regs.r8),
+            (libc::REG_R9, regs.r9),
+            (libc::REG_R10, regs.r10),
+            (libc::REG_R11, regs.r11),
+            (libc::REG_R12, regs.r12),
+            (libc::REG_R13, regs.r13),
+            (libc::REG_R14, regs.r14),
+            (libc::REG_R15, regs.r15),
+            (libc::REG_RDI, regs.rdi),
+            (libc::REG_RSI, regs.rsi),
+            (libc::REG_RBP, regs.rbp),
+            (libc::REG_RBX, regs.rbx),
+            (libc::REG_RDX, regs.rdx),
+            (libc::REG_RAX, regs.rax),
+            (libc::REG_RCX, regs.rcx),
+            (libc::REG_RSP, regs.rsp),
+            (libc::REG_RIP, regs.rip),
+            (libc::REG_EFL, regs.eflags),
+            (libc::REG_CSGSFS, regs.cs | regs.gs << 16 | regs.fs << 32),
+        ] {
+            gregs[reg as usize] = value as i64;
+        }
+        context.float_state =
+            mem::transmute::<libc::user_fpregs_struct, crash_context::fpregset_t>(fpregs);
+        context.siginfo.ssi_signo = info.si_signo as _;
+        context.siginfo.ssi_errno = info.si_errno;
+        context.siginfo.ssi_code = info.si_code;
+        context.siginfo.ssi_addr = info.si_addr() as u64;
+        context.pid = pid;
+        context.tid = tid;
+        context
+    }
+}
+
+fn open_mem(pid: i32) -> File {
+    OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open(format!("/proc/{pid}/mem"))
+        .unwrap()
+}
+
+/// Entry point of the image, from the auxiliary vector.
+fn entry(pid: i32) -> u64 {
+    let auxv = fs::read(format!("/proc/{pid}/auxv")).unwrap();
+    auxv.chunks_exact(16)
+        .map(|kv| {
+            let (k, v) = kv.split_at(8);
+            (
+                u64::from_ne_bytes(k.try_into().unwrap()),
+                u64::from_ne_bytes(v.try_into().unwrap()),
+            )
+        })
+        .find(|&(k, _)| k == AT_ENTRY)
+        .map(|(_, v)| v)
+        .unwrap()
+}
+
+/// Thread group of a thread, 0 once it is gone.
+fn tgid(tid: i32) -> i32 {
+    fs::read_to_string(format!("/proc/{tid}/status"))
+        .ok()
+        .and_then(|status| {
+            status
+                .lines()
+                .find_map(|line| line.strip_prefix("Tgid:"))
+                .and_then(|tgid| tgid.trim().parse().ok())
+        })
+        .unwrap_or_default()
+}
+
+/// Processes forked by the process and its descendants, recursively.
+fn descendants(pid: i32) -> Vec<i32> {
+    let mut children: Vec<i32> = tasks(pid)
+        .into_iter()
+        .filter_map(|tid| fs::read_to_string(format!("/proc/{pid}/task/{tid}/children")).ok())
+        .flat_map(|children| {
+            children
+                .split_whitespace()
+                .filter_map(|child| child.parse().ok())
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    for child in children.clone() {
+        children.extend(descendants(child));
+    }
+    children
+}
+
+/// Scheduling state of each thread, as in `/proc/<pid>/task/<tid>/stat`.
+fn states(pid: i32) -> Vec<char> {
+    tasks(pid)
+        .into_iter()
+        .filter_map(|tid| {
+            let stat = fs::read_to_string(format!("/proc/{pid}/task/{tid}/stat")).ok()?;
+            stat.rsplit_once(')')?.1.trim_start().chars().next()
+        })
+        .collect()
+}
+
+/// Whether no thread of the process can run.
+fn frozen(pid: i32) -> bool {
+    states(pid)
+        .into_iter()
+        .all(|state| matches!(state, 'T' | 't' | 'Z' | 'X'))
+}
+
+fn ppid(pid: i32) -> i32 {
+    fs::read_to_string(format!("/proc/{pid}/stat"))
+        .ok()
+        .and_then(|stat| {
+            let (_, fields) = stat.rsplit_once(')')?;
+            fields.split_whitespace().nth(1)?.parse().ok()
+        })
+        .unwrap_or_default()
+}
+
+fn tasks(pid: i32) -> Vec<i32> {
+    fs::read_dir(format!("/proc/{pid}/task"))
+        .map(|dir| {
+            dir.filter_map(|entry| entry.ok()?.file_name().to_str()?.parse().ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// File backed mappings of `/proc/<pid>/maps`, grouped by path.
+pub fn modules(pid: i32) -> Vec<super::Module> {
+    let maps = fs::read_to_string(format!("/proc/{pid}/maps")).unwrap_or_default();
+    let mut modules: Vec<super::Module> = vec![];
+    for line in maps.lines() {
+        let mut fields = line.split_whitespace();
+        let (Some(range), Some(perms), Some(offset), Some(path)) =
+            (fields.next(), fields.next(), fields.next(), fields.nth(2))
+        else {
+            continue;
+        };
+        if !path.starts_with('/') {
+            continue;
+        }
+        let Some((start, end)) = range.split_once('-') else {
+            continue;
+        };
+        let (Ok(start), Ok(end), Ok(offset)) = (
+            u64::from_str_radix(start, 16),
+            u64::from_str_radix(end, 16),
+            u64::from_str_radix(offset, 16),
+        ) else {
+            continue;
+        };
+
+        let module = match modules.iter_mut().find(|m| m.path == path) {
+            Some(module) => module,
+            None if offset == 0 => {
+                modules.push(super::Module {
+                    path: path.to_owned(),
+                    load_address: start,
+                    ..Default::default()
+                });
+                modules.last_mut().unwrap()
+            }
+            None => continue,
+        };
+        module.size = Some(
+            end.saturating_sub(module.load_address)
+                .max(module.size.unwrap_or(0)),
+        );
+        if perms.contains('x') {
+            module.text_segment = Some(super::Range { start, end });
+        }
+    }
+
+    for module in &mut modules {
+        let headers = elf::headers(&module.path);
+        let Some(image) = Elf::parse(&headers) else {
+            continue;
+        };
+        module.arch = elf::arch(image.header.e_machine).map(ToOwned::to_owned);
+        if let Some(build_id) = image.build_id() {
+            let (code_id, debug_id) = minidump::elf_ids(build_id);
+            module.code_id = Some(code_id);
+            module.debug_id = Some(debug_id);
+        }
+    }
+    modules
+}
@@ -0,0 +1,1000 @@
+use crate::elf;
+use std::{collections::HashSet, mem};
+
+pub const MH_MAGIC: u32 = 0xfeed_face;
+pub const MH_MAGIC_64: u32 = 0xfeed_facf;
+pub const FAT_MAGIC: u32 = 0xcafe_babe;
+pub const FAT_MAGIC_64: u32 = 0xcafe_babf;
+pub const CPU_TYPE_X86: i32 = 7;
+pub const CPU_TYPE_ARM: i32 = 12;
+pub const CPU_TYPE_X86_64: i32 = 0x0100_0007;
+pub const CPU_TYPE_ARM64: i32 = 0x0100_000c;
+pub const LC_SEGMENT: u32 = 0x1;
+pub const LC_SYMTAB: u32 = 0x2;
+#[cfg(any(target_os = "macos", test))]
+pub const LC_ID_DYLIB: u32 = 0xd;
+pub const LC_SEGMENT_64: u32 = 0x19;
+pub const LC_UUID: u32 = 0x1b;
+pub const LC_DYLD_INFO: u32 = 0x22;
+pub const LC_DYLD_INFO_ONLY: u32 = 0x8000_0022;
+pub const LC_FUNCTION_STARTS: u32 = 0x26;
+pub const LC_DYLD_EXPORTS_TRIE: u32 = 0x8000_0033;
+pub const N_STAB: u8 = 0xe0;
+#[cfg(target_os = "macos")]
+pub const N_PEXT: u8 = 0x10;
+pub const N_TYPE: u8 = 0x0e;
+pub const N_SECT: u8 = 0x0e;
+pub const N_EXT: u8 = 0x01;
+pub const EXPORT_SYMBOL_FLAGS_KIND_MASK: u64 = 0x03;
+pub const EXPORT_SYMBOL_FLAGS_KIND_REGULAR: u64 = 0x00;
+pub const EXPORT_SYMBOL_FLAGS_REEXPORT: u64 = 0x08;
+
+/// Longest symbol name read.
+const MAX_NAME: usize = 4096;
+/// Most slices of a fat file, Java class files share its magic with a version in place of the
+/// count.
+const MAX_FAT_ARCHS: u32 = 32;
+
+/// `fat_header`, big-endian as the rest of a fat file's header.
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct fat_header {
+    pub magic: u32,
+    pub nfat_arch: u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct fat_arch {
+    pub cputype: i32,
+    pub cpusubtype: i32,
+    pub offset: u32,
+    pub size: u32,
+    pub align: u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct fat_arch_64 {
+    pub cputype: i32,
+    pub cpusubtype: i32,
+    pub offset: u64,
+    pub size: u64,
+    pub align: u32,
+    pub reserved: u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct mach_header {
+    pub magic: u32,
+    pub cputype: i32,
+    pub cpusubtype: i32,
+    pub filetype: u32,
+    pub ncmds: u32,
+    pub sizeofcmds: u32,
+    pub flags: u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct mach_header_64 {
+    pub magic: u32,
+    pub cputype: i32,
+    pub cpusubtype: i32,
+    pub filetype: u32,
+    pub ncmds: u32,
+    pub sizeofcmds: u32,
+    pub flags: u32,
+    pub reserved: u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct load_command {
+    pub cmd: u32,
+    pub cmdsize: u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct segment_command {
+    pub cmd: u32,
+    pub cmdsize: u32,
+    pub segname: [u8; 16],
+    pub vmaddr: u32,
+    pub vmsize: u32,
+    pub fileoff: u32,
+    pub filesize: u32,
+    pub maxprot: i32,
+    pub initprot: i32,
+    pub nsects: u32,
+    pub flags: u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct segment_command_64 {
+    pub cmd: u32,
+    pub cmdsize: u32,
+    pub segname: [u8; 16],
+    pub vmaddr: u64,
+    pub vmsize: u64,
+    pub fileoff: u64,
+    pub filesize: u64,
+    pub maxprot: i32,
+    pub initprot: i32,
+    pub nsects: u32,
+    pub flags: u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct section {
+    pub sectname: [u8; 16],
+    pub segname: [u8; 16],
+    pub addr: u32,
+    pub size: u32,
+    pub offset: u32,
+    pub align: u32,
+    pub reloff: u32,
+    pub nreloc: u32,
+    pub flags: u32,
+    pub reserved1: u32,
+    pub reserved2: u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct section_64 {
+    pub sectname: [u8; 16],
+    pub segname: [u8; 16],
+    pub addr: u64,
+    pub size: u64,
+    pub offset: u32,
+    pub align: u32,
+    pub reloff: u32,
+    pub nreloc: u32,
+    pub flags: u32,
+    pub reserved1: u32,
+    pub reserved2: u32,
+    pub reserved3: u32,
+}
+
+#[cfg(any(target_os = "macos", test))]
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct dylib_command {
+    pub cmd: u32,
+    pub cmdsize: u32,
+    pub name: u32,
+    pub timestamp: u32,
+    pub current_version: u32,
+    pub compatibility_version: u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct uuid_command {
+    pub cmd: u32,
+    pub cmdsize: u32,
+    pub uuid: [u8; 16],
+}
+
+/// `LC_FUNCTION_STARTS`, `LC_DYLD_EXPORTS_TRIE` and the other commands pointing into
+/// `__LINKEDIT`.
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct linkedit_data_command {
+    pub cmd: u32,
+    pub cmdsize: u32,
+    pub dataoff: u32,
+    pub datasize: u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct dyld_info_command {
+    pub cmd: u32,
+    pub cmdsize: u32,
+    pub rebase_off: u32,
+    pub rebase_size: u32,
+    pub bind_off: u32,
+    pub bind_size: u32,
+    pub weak_bind_off: u32,
+    pub weak_bind_size: u32,
+    pub lazy_bind_off: u32,
+    pub lazy_bind_size: u32,
+    pub export_off: u32,
+    pub export_size: u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct symtab_command {
+    pub cmd: u32,
+    pub cmdsize: u32,
+    pub symoff: u32,
+    pub nsyms: u32,
+    pub stroff: u32,
+    pub strsize: u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct nlist {
+    pub n_strx: u32,
+    pub n_type: u8,
+    pub n_sect: u8,
+    pub n_desc: u16,
+    pub n_value: u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct nlist_64 {
+    pub n_strx: u32,
+    pub n_type: u8,
+    pub n_sect: u8,
+    pub n_desc: u16,
+    pub n_value: u64,
+}
+
+/// A little-endian Mach-O image, 32 or 64-bit, read through `read` from a file, a buffer or the
+/// memory of another process.
+pub struct MachO<R> {
+    read: R,
+    /// Where the header is: the address of an image loaded in memory, or the offset of its slice
+    /// in a file.
+    base: u64,
+    mapped: bool,
+    is64: bool,
+    /// The header, widened for a 32-bit image.
+    pub header: mach_header_64,
+    /// Load commands, with their offset from the header.
+    commands: Vec<(load_command, u64)>,
+    segments: Vec<Segment>,
+}
+
+#[derive(Clone)]
+pub struct Segment {
+    pub name: String,
+    pub vmaddr: u64,
+    pub vmsize: u64,
+    pub fileoff: u64,
+    pub filesize: u64,
+}
+
+pub struct Symbol {
+    pub name: String,
+    pub value: u64,
+    // Only macOS reads it, to leave out private externs.
+    #[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+    pub kind: u8,
+}
+
+impl<R: Fn(u64, &mut [u8]) -> bool> MachO<R> {
+    /// An image laid out as in its file, with `read` taking file offsets. In a fat file, the
+    /// slice for `cputype`, or the first one without.
+    pub fn file(read: R, cputype: Option<i32>) -> Option<Self> {
+        let base = fat_slice(&read, cputype)?;
+        Self::new(read, base, false)
+    }
+
+    /// An image loaded at `address`, with `read` taking addresses.
+    pub fn mapped(read: R, address: u64) -> Option<Self> {
+        Self::new(read, address, true)
+    }
+
+    fn new(read: R, base: u64, mapped: bool) -> Option<Self> {
+        let (header, is64) = match get::<u32>(&read, base)? {
+            MH_MAGIC_64 => (get::<mach_header_64>(&read, base)?, true),
+            MH_MAGIC => {
+                let header: mach_header = get(&read, base)?;
+                let header = mach_header_64 {
+                    magic: header.magic,
+                    cputype: header.cputype,
+                    cpusubtype: header.cpusubtype,
+                    filetype: header.filetype,
+                    ncmds: header.ncmds,
+                    sizeofcmds: header.sizeofcmds,
+                    flags: header.flags,
+                    reserved: 0,
+                };
+                (header, false)
+            }
+            _ => return None,
+        };
+        let mut commands = vec![];
+        let mut offset = if is64 {
+            mem::size_of::<mach_header_64>()
+        } else {
+            mem::size_of::<mach_header>()
+        } as u64;
+        for _ in 0..header.ncmds {
+            let lc: load_command = get(&read, base + offset)?;
+            if lc.cmdsize < mem::size_of::<load_command>() as u32 {
+                break;
+            }
+            commands.push((lc, offset));
+            offset += lc.cmdsize as u64;
+        }
+        let mut image = Self {
+            read,
+            base,
+            mapped,
+            is64,
+            header,
+            commands,
+            segments: vec![],
+        };
+        image.segments = image
+            .commands
+            .iter()
+            .filter_map(|&(lc, offset)| match lc.cmd {
+                LC_SEGMENT_64 => {
+                    let seg: segment_command_64 = image.command(offset)?;
+                    Some(Segment {
+                        name: name(&seg.segname),
+                        vmaddr: seg.vmaddr,
+                        vmsize: seg.vmsize,
+                        fileoff: seg.fileoff,
+                        filesize: seg.filesize,
+                    })
+                }
+                LC_SEGMENT => {
+                    let seg: segment_command = image.command(offset)?;
+                    Some(Segment {
+                        name: name(&seg.segname),
+                        vmaddr: seg.vmaddr as u64,
+                        vmsize: seg.vmsize as u64,
+                        fileoff: seg.fileoff as u64,
+                        filesize: seg.filesize as u64,
+                    })
+                }
+                _ => None,
+            })
+            .collect();
+        Some(image)
+    }
+
+    /// The load command at `offset` from the header.
+    fn command<T: Copy>(&self, offset: u64) -> Option<T> {
+        get(&self.read, self.base + offset)
+    }
+
+    /// The first load command of a kind.
+    fn find_command<T: Copy>(&self, cmd: u32) -> Option<T> {
+        let &(_, offset) = self.commands.iter().find(|(lc, _)| lc.cmd == cmd)?;
+        self.command(offset)
+    }
+
+    pub fn uuid(&self) -> Option<[u8; 16]> {
+        self.find_command::<uuid_command>(LC_UUID).map(|lc| lc.uuid)
+    }
+
+    /// The current version of a dylib, as `major.minor.patch`.
+    #[cfg(any(target_os = "macos", test))]
+    pub fn version(&self) -> Option<String> {
+        let v = self
+            .find_command::<dylib_command>(LC_ID_DYLIB)?
+            .current_version;
+        Some(format!("{}.{}.{}", v >> 16, (v >> 8) & 0xff, v & 0xff))
+    }
+
+    /// The size of the image in memory, from its first segment to the end of its last, leaving
+    /// out the `__PAGEZERO` of executables.
+    #[cfg(any(target_os = "macos", test))]
+    pub fn size(&self) -> u64 {
+        let segments = self
+            .segments
+            .iter()
+            .filter(|seg| seg.name != "__PAGEZERO" && seg.vmsize > 0);
+        let start = segments
+            .clone()
+            .map(|seg| seg.vmaddr)
+            .min()
+            .unwrap_or_default();
+        let end = segments
+            .map(|seg| seg.vmaddr + seg.vmsize)
+            .max()
+            .unwrap_or_default();
+        end - start
+    }
+
+    pub fn segments(&self) -> &[Segment] {
+        &self.segments
+    }
+
+    /// How far the image was moved from the addresses of its file, 0 for the file itself.
+    pub fn slide(&self) -> u64 {
+        let text = self.segments.iter().find(|seg| seg.name == "__TEXT");
+        match text {
+            Some(text) if self.mapped => self.base.wrapping_sub(text.vmaddr),
+            _ => 0,
+        }
+    }
+
+    /// Read at a file offset, through the segment mapping it in a loaded image.
+    fn read_file(&self, offset: u64, buf: &mut [u8]) -> bool {
+        let address = match self.mapped {
+            false => self.base + offset,
+            true => {
+                let holds = |seg: &&Segment| {
+                    seg.fileoff <= offset && offset + buf.len() as u64 <= seg.fileoff + seg.filesize
+                };
+                // In a split shared cache, the offsets of __LINKEDIT, which the symbol table is
+                // in, are in another file than those of the other segments.
+                let Some(seg) = self
+                    .segments
+                    .iter()
+                    .filter(holds)
+                    .find(|seg| seg.name == "__LINKEDIT")
+                    .or_else(|| self.segments.iter().find(holds))
+                else {
+                    return false;
+                };
+                (seg.vmaddr + offset - seg.fileoff).wrapping_add(self.slide())
+            }
+        };
+        (self.read)(address, buf)
+    }
+
+    /// Symbols defined in a section of the image, at their unslid addresses, leaving out the
+    /// debugging entries.
+    pub fn symbols(&self) -> Vec<Symbol> {
+        let Some(symtab) = self.find_command::<symtab_command>(LC_SYMTAB) else {
+            return vec![];
+        };
+        let size = if self.is64 {
+            mem::size_of::<nlist_64>()
+        } else {
+            mem::size_of::<nlist>()
+        };
+        // A corrupt count is caught by the table ending past the file, before allocating it.
+        let len = symtab.nsyms as u64 * size as u64;
+        if len == 0 || !self.read_file(symtab.symoff as u64 + len - 1, &mut [0]) {
+            return vec![];
+        }
+        let mut syms = vec![0; len as usize];
+        if !self.read_file(symtab.symoff as u64, &mut syms) {
+            return vec![];
+        }
+        syms.chunks_exact(size)
+            .filter_map(|sym| {
+                let sym: nlist_64 = if self.is64 {
+                    elf::read(sym, 0)?
+                } else {
+                    let sym: nlist = elf::read(sym, 0)?;
+                    nlist_64 {
+                        n_strx: sym.n_strx,
+                        n_type: sym.n_type,
+                        n_sect: sym.n_sect,
+                        n_desc: sym.n_desc,
+                        n_value: sym.n_value as u64,
+                    }
+                };
+                if !defined(sym.n_type) {
+                    return None;
+                }
+                let strtab_end = symtab.stroff as u64 + symtab.strsize as u64;
+                let name = read_str(
+                    |offset, buf: &mut [u8]| self.read_file(offset, buf),
+                    symtab.stroff as u64 + sym.n_strx as u64,
+                    strtab_end,
+                )?;
+                Some(Symbol {
+                    name,
+                    value: sym.n_value,
+                    kind: sym.n_type,
+                })
+            })
+            .collect()
+    }
+
+    /// The start of each function, from `LC_FUNCTION_STARTS`, at unslid addresses. Stripped
+    /// images keep these, whereas their symbol table has little left.
+    pub fn function_starts(&self) -> Vec<u64> {
+        let Some(text) = self.segments.iter().find(|seg| seg.name == "__TEXT") else {
+            return vec![];
+        };
+        let Some(data) = self.linkedit_data(LC_FUNCTION_STARTS) else {
+            return vec![];
+        };
+        // Each start is a delta from the previous one, the first from the header, up to a 0.
+        let mut starts = vec![];
+        let (mut address, mut offset) = (text.vmaddr, 0);
+        while let Some(delta) = uleb128(&data, &mut offset).filter(|&delta| delta != 0) {
+            address = address.wrapping_add(delta);
+            starts.push(address);
+        }
+        starts
+    }
+
+    /// The symbols the image exports, from the export trie of `LC_DYLD_EXPORTS_TRIE` or
+    /// `LC_DYLD_INFO`, at unslid addresses, leaving out re-exports and absolute and thread-local
+    /// symbols.
+    pub fn exports(&self) -> Vec<Symbol> {
+        let Some(text) = self.segments.iter().find(|seg| seg.name == "__TEXT") else {
+            return vec![];
+        };
+        let trie = self.linkedit_data(LC_DYLD_EXPORTS_TRIE).or_else(|| {
+            let info = self
+                .find_command::<dyld_info_command>(LC_DYLD_INFO_ONLY)
+                .or_else(|| self.find_command(LC_DYLD_INFO))?;
+            let mut data = vec![0; info.export_size as usize];
+            self.read_file(info.export_off as u64, &mut data)
+                .then_some(data)
+        });
+        let Some(trie) = trie.filter(|trie| !trie.is_empty()) else {
+            return vec![];
+        };
+
+        // Each node holds what is exported under the prefix it is reached by, if anything, then
+        // edges labelled with the rest of the names below it.
+        let mut exports = vec![];
+        let mut nodes = vec![(0, vec![])];
+        let mut visited = HashSet::new();
+        while let Some((node, prefix)) = nodes.pop() {
+            if !visited.insert(node) {
+                continue;
+            }
+            let mut offset = node;
+            let Some(size) = uleb128(&trie, &mut offset) else {
+                continue;
+            };
+            let edges = offset.saturating_add(size as usize);
+            if size > 0 {
+                if let Some(flags) = uleb128(&trie, &mut offset) {
+                    let regular = flags & EXPORT_SYMBOL_FLAGS_KIND_MASK
+                        == EXPORT_SYMBOL_FLAGS_KIND_REGULAR
+                        && flags & EXPORT_SYMBOL_FLAGS_REEXPORT == 0;
+                    // A resolver's stub comes first, and is where calls land.
+                    if let Some(address) = uleb128(&trie, &mut offset).filter(|_| regular) {
+                        exports.push(Symbol {
+                            name: String::from_utf8_lossy(&prefix).into_owned(),
+                            value: text.vmaddr.wrapping_add(address),
+                            kind: N_SECT | N_EXT,
+                        });
+                    }
+                }
+            }
+            let mut offset = edges;
+            let Some(&count) = trie.get(offset) else {
+                continue;
+            };
+            offset += 1;
+            let mut children = vec![];
+            for _ in 0..count {
+                let Some(len) = trie
+                    .get(offset..)
+                    .and_then(|rest| rest.iter().position(|&c| c == 0))
+                else {
+                    break;
+                };
+                let mut name = prefix.clone();
+                name.extend_from_slice(&trie[offset..offset + len]);
+                offset += len + 1;
+                let Some(child) = uleb128(&trie, &mut offset) else {
+                    break;
+                };
+                children.push((child as usize, name));
+            }
+            // Depth first, in the order of the trie.
+            nodes.extend(children.into_iter().rev());
+        }
+        exports
+    }
+
+    /// `syms`, then the exports and the starts of the functions, as `func_<address>`, where `syms`
+    /// has nothing, sorted and each with its size.
+    pub fn sized_symbols(&self, mut syms: Vec<Symbol>) -> Vec<(Symbol, u64)> {
+        let Some(text) = self.segments.iter().find(|seg| seg.name == "__TEXT") else {
+            return vec![];
+        };
+        let mut named: HashSet<u64> = syms.iter().map(|sym| sym.value).collect();
+        for export in self.exports() {
+            if named.insert(export.value) {
+                syms.push(export);
+            }
+        }
+        for start in self.function_starts() {
+            if named.insert(start) {
+                syms.push(Symbol {
+                    name: format!("func_{start:x}"),
+                    value: start,
+                    kind: N_SECT,
+                });
+            }
+        }
+        syms.sort_by_key(|sym| sym.value);
+        // Mach-O symbols have no size, each runs up to the next one.
+        let ends: Vec<u64> = syms
+            .iter()
+            .skip(1)
+            .map(|sym| sym.value)
+            .chain([text.vmaddr + text.vmsize])
+            .collect();
+        syms.into_iter()
+            .zip(ends)
+            .map(|(sym, end)| {
+                let size = end.saturating_sub(sym.value);
+                (sym, size)
+            })
+            .collect()
+    }
+
+    /// The unslid address and contents of a section, such as `__TEXT,__eh_frame` or the
+    /// `__DWARF` sections of a dSYM.
+    pub fn section(&self, segname: &str, sectname: &str) -> Option<(u64, Vec<u8>)> {
+        for &(lc, offset) in &self.commands {
+            // Sections follow the command of their segment.
+            let (nsects, first) = match lc.cmd {
+                LC_SEGMENT_64 => {
+                    let seg: segment_command_64 = self.command(offset)?;
+                    (
+                        seg.nsects,
+                        offset + mem::size_of::<segment_command_64>() as u64,
+                    )
+                }
+                LC_SEGMENT => {
+                    let seg: segment_command = self.command(offset)?;
+                    (
+                        seg.nsects,
+                        offset + mem::size_of::<segment_command>() as u64,
+                    )
+                }
+                _ => continue,
+            };
+            for i in 0..nsects as u64 {
+                let sect = match self.is64 {
+                    true => self.command(first + i * mem::size_of::<section_64>() as u64)?,
+                    false => {
+                        let sect: section =
+                            self.command(first + i * mem::size_of::<section>() as u64)?;
+                        section_64 {
+                            sectname: sect.sectname,
+                            segname: sect.segname,
+                            addr: sect.addr as u64,
+                            size: sect.size as u64,
+                            offset: sect.offset,
+                            ..Default::default()
+                        }
+                    }
+                };
+                if name(&sect.segname) != segname || name(&sect.sectname) != sectname {
+                    continue;
+                }
+                // Sections without contents in the file, as those a dSYM copies the headers of.
+                if sect.offset == 0 {
+                    return None;
+                }
+                return self
+                    .file_data(sect.offset as u64, sect.size as usize)
+                    .map(|data| (sect.addr, data));
+            }
+        }
+        None
+    }
+
+    /// The `__LINKEDIT` data a `linkedit_data_command` points to.
+    fn linkedit_data(&self, cmd: u32) -> Option<Vec<u8>> {
+        let lc = self.find_command::<linkedit_data_command>(cmd)?;
+        self.file_data(lc.dataoff as u64, lc.datasize as usize)
+    }
+
+    /// `len` bytes of the file at `offset`, allocated once their last one is known to be there, as
+    /// a corrupt size may be huge.
+    fn file_data(&self, offset: u64, len: usize) -> Option<Vec<u8>> {
+        let last = offset.checked_add(len as u64 - 1);
+        if len > 0 && !last.is_some_and(|last| self.read_file(last, &mut [0])) {
+            return None;
+        }
+        let mut data = vec![0; len];
+        self.read_file(offset, &mut data).then_some(data)
+    }
+}
+
+/// An unsigned LEB128 number, moving `offset` past it.
+fn uleb128(data: &[u8], offset: &mut usize) -> Option<u64> {
+    let mut value = 0;
+    for shift in (0..64).step_by(7) {
+        let byte = *data.get(*offset)?;
+        *offset += 1;
+        value |= ((byte & 0x7f) as u64) << shift;
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+    }
+    None
+}
+
+/// Whether a symbol table entry is a symbol defined in a section, rather than a debugging entry
+/// or an undefined or absolute symbol.
+pub fn defined(n_type: u8) -> bool {
+    n_type & N_STAB == 0 && n_type & N_TYPE == N_SECT
+}
+
+/// The string at `offset`, read a piece at a time as the string table of an image in the shared
+/// cache is shared by all of them.
+pub fn read_str(
+    read: impl Fn(u64, &mut [u8]) -> bool,
+    mut offset: u64,
+    end: u64,
+) -> Option<String> {
+    let mut s = vec![];
+    while offset < end && s.len() < MAX_NAME {
+        let mut piece = vec![0; (end - offset).min(64) as usize];
+        if !read(offset, &mut piece) {
+            return None;
+        }
+        if let Some(nul) = piece.iter().position(|&c| c == 0) {
+            s.extend_from_slice(&piece[..nul]);
+            return String::from_utf8(s).ok().filter(|s| !s.is_empty());
+        }
+        s.extend_from_slice(&piece);
+        offset += piece.len() as u64;
+    }
+    None
+}
+
+/// Where the image for `cputype`, or the first one without, starts in a file: past the header of
+/// a fat file, at 0 in a thin one.
+fn fat_slice(read: impl Fn(u64, &mut [u8]) -> bool, cputype: Option<i32>) -> Option<u64> {
+    let header: fat_header = get(&read, 0)?;
+    let (magic, count) = (u32::from_be(header.magic), u32::from_be(header.nfat_arch));
+    let slices: Vec<(i32, u64)> = match magic {
+        FAT_MAGIC if count <= MAX_FAT_ARCHS => (0..count as usize)
+            .filter_map(|i| {
+                let offset = mem::size_of::<fat_header>() + i * mem::size_of::<fat_arch>();
+                let arch: fat_arch = get(&read, offset as u64)?;
+                Some((i32::from_be(arch.cputype), u32::from_be(arch.offset) as u64))
+            })
+            .collect(),
+        FAT_MAGIC_64 if count <= MAX_FAT_ARCHS => (0..count as usize)
+            .filter_map(|i| {
+                let offset = mem::size_of::<fat_header>() + i * mem::size_of::<fat_arch_64>();
+                let arch: fat_arch_64 = get(&read, offset as u64)?;
+                Some((i32::from_be(arch.cputype), u64::from_be(arch.offset)))
+            })
+            .collect(),
+        _ => return Some(0),
+    };
+    slices
+        .into_iter()
+        .find(|&(slice, _)| cputype.is_none_or(|cputype| slice == cputype))
+        .map(|(_, offset)| offset)
+}
+
+pub fn arch(cputype: i32) -> Option<&'static str> {
+    match cputype {
+        CPU_TYPE_X86 => Some("x86"),
+        CPU_TYPE_ARM => Some("arm"),
+        CPU_TYPE_X86_64 => Some("x86_64"),
+        CPU_TYPE_ARM64 => Some("arm64"),
+        _ => None,
+    }
+}
+
+pub fn cputype(arch: &str) -> Option<i32> {
+    match arch {
+        "x86" => Some(CPU_TYPE_X86),
+        "arm" => Some(CPU_TYPE_ARM),
+        "x86_64" => Some(CPU_TYPE_X86_64),
+        "arm64" => Some(CPU_TYPE_ARM64),
+        _ => None,
+    }
+}
+
+/// The code and debug identifiers of a Mach-O image, its UUID without and with an age.
+pub fn ids(uuid: &[u8; 16]) -> (String, String) {
+    let code_id: String = uuid.iter().map(|b| format!("{b:02X}")).collect();
+    let debug_id = code_id.clone() + "0";
+    (code_id, debug_id)
+}
+
+/// A reader of `data` by offset, for `MachO::file`.
+pub fn slice(data: &[u8]) -> impl Fn(u64, &mut [u8]) -> bool + '_ {
+    move |offset, buf| {
+        let Ok(start) = usize::try_from(offset) else {
+            return false;
+        };
+        start
+            .checked_add(buf.len())
+            .and_then(|end| data.get(start..end))
+            .map(|data| buf.copy_from_slice(data))
+            .is_some()
+    }
+}
+
+pub fn get<T: Copy>(read: impl Fn(u64, &mut [u8]) -> bool, address: u64) -> Option<T> {
+    let mut buf = vec![0; mem::size_of::<T>()];
+    read(address, &mut buf).then(|| elf::read(&buf, 0))?
+}
+
+pub fn name(bytes: &[u8]) -> String {
+    let end = bytes.iter().position(|&c| c == 0).unwrap_or(bytes.len());
+    String::from_utf8_lossy(&bytes[..end]).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DYLIB: &[u8] = include_bytes!("../tests/fixtures/macho/libfixture.dylib");
+    const DYLIB32: &[u8] = include_bytes!("../tests/fixtures/macho/libfixture32.dylib");
+    /// The i386 and x86_64 images in one file, with 32 and 64-bit slice headers.
+    const FAT: &[u8] = include_bytes!("../tests/fixtures/macho/libfixture_fat.dylib");
+    const FAT64: &[u8] = include_bytes!("../tests/fixtures/macho/libfixture_fat64.dylib");
+    const STRIPPED: &[u8] = include_bytes!("../tests/fixtures/macho/libfixture_stripped.dylib");
+    const LOAD_ADDRESS: u64 = 0x7ff8_1234_0000;
+
+    fn symbols<R: Fn(u64, &mut [u8]) -> bool>(image: &MachO<R>) -> Vec<(String, u64, u8)> {
+        image
+            .symbols()
+            .into_iter()
+            .map(|sym| (sym.name, sym.value, sym.kind))
+            .collect()
+    }
+
+    fn expected() -> Vec<(String, u64, u8)> {
+        vec![
+            ("_answer".to_owned(), 0xf00, 0x0f),
+            ("_caller".to_owned(), 0xf10, 0x0f),
+            ("_helper".to_owned(), 0xf20, 0x0e),
+        ]
+    }
+
+    #[test]
+    fn file() {
+        let image = MachO::file(slice(DYLIB), None).unwrap();
+        let segments: Vec<_> = image
+            .segments()
+            .iter()
+            .map(|seg| (seg.name.as_str(), seg.vmaddr, seg.fileoff))
+            .collect();
+        assert_eq!(
+            segments,
+            [
+                ("__TEXT", 0, 0),
+                ("__DATA", 0x1000, 0x1000),
+                ("__LINKEDIT", 0x4000, 0x2000)
+            ]
+        );
+        assert_eq!(image.slide(), 0);
+        assert_eq!(symbols(&image), expected());
+    }
+
+    #[test]
+    fn mapped() {
+        // The segments laid out at their addresses, as dyld maps them.
+        let file = MachO::file(slice(DYLIB), None).unwrap();
+        let mut memory = vec![0; 0x5000];
+        for seg in file.segments() {
+            let data = &DYLIB[seg.fileoff as usize..(seg.fileoff + seg.filesize) as usize];
+            memory[seg.vmaddr as usize..][..data.len()].copy_from_slice(data);
+        }
+        let read = |address: u64, buf: &mut [u8]| {
+            let Some(offset) = address.checked_sub(LOAD_ADDRESS) else {
+                return false;
+            };
+            slice(&memory)(offset, buf)
+        };
+
+        let image = MachO::mapped(read, LOAD_ADDRESS).unwrap();
+        assert_eq!(image.slide(), LOAD_ADDRESS);
+        assert_eq!(symbols(&image), expected());
+    }
+
+    #[test]
+    fn identity() {
+        let image = MachO::file(slice(DYLIB), None).unwrap();
+        assert_eq!(arch(image.header.cputype), Some("x86_64"));
+        assert_eq!(image.size(), 0x5000);
+        assert_eq!(image.version().as_deref(), Some("1.2.3"));
+        let (code_id, debug_id) = ids(&image.uuid().unwrap());
+        assert_eq!(code_id, "0DDBA11C0FFEE5EEDBA5EBA11CAFE000");
+        assert_eq!(debug_id, "0DDBA11C0FFEE5EEDBA5EBA11CAFE0000");
+    }
+
+    #[test]
+    fn thirty_two_bit() {
+        let image = MachO::file(slice(DYLIB32), None).unwrap();
+        assert_eq!(arch(image.header.cputype), Some("x86"));
+        assert_eq!(image.segments().len(), 3);
+        // `_printf` is left out, undefined.
+        assert_eq!(
+            symbols(&image),
+            [
+                ("_answer".to_owned(), 0xf00, 0x0f),
+                ("_helper".to_owned(), 0xf0a, 0x0e),
+            ]
+        );
+        let (code_id, _) = ids(&image.uuid().unwrap());
+        assert_eq!(code_id, "0DDBA11C0FFEE5EEDBA5EBA11CAFE032");
+    }
+
+    #[test]
+    fn fat() {
+        for data in [FAT, FAT64] {
+            let image = MachO::file(slice(data), Some(CPU_TYPE_X86_64)).unwrap();
+            assert_eq!(image.size(), 0x5000);
+            assert_eq!(symbols(&image), expected());
+
+            let image = MachO::file(slice(data), Some(CPU_TYPE_X86)).unwrap();
+            assert_eq!(arch(image.header.cputype), Some("x86"));
+            assert_eq!(symbols(&image).len(), 2);
+
+            // The first slice without a cputype, none for one not in the file.
+            let image = MachO::file(slice(data), None).unwrap();
+            assert_eq!(image.header.cputype, CPU_TYPE_X86);
+            assert!(MachO::file(slice(data), Some(CPU_TYPE_ARM64)).is_none());
+        }
+    }
+
+    #[test]
+    fn stripped() {
+        let image = MachO::file(slice(STRIPPED), None).unwrap();
+        assert!(image.symbols().is_empty());
+        let exports: Vec<_> = image
+            .exports()
+            .into_iter()
+            .map(|sym| (sym.name, sym.value, sym.kind))
+            .collect();
+        assert_eq!(
+            exports,
+            [
+                ("_answer".to_owned(), 0xf00, 0x0f),
+                ("_caller".to_owned(), 0xf10, 0x0f)
+            ]
+        );
+        assert_eq!(image.function_starts(), [0xf00, 0xf10, 0xf20]);
+    }
+
+    #[test]
+    fn not_mach_o() {
+        assert!(MachO::file(slice(b"\x7fELF\x02\x01\x01\0\0\0\0\0\0\0\0\0"), None).is_none());
+        assert!(MachO::file(slice(&DYLIB[..16]), None).is_none());
+        assert!(MachO::file(slice(&DYLIB[..0x100]), None).is_none());
+        // A file cut before its symbol table.
+        let image = MachO::file(slice(&DYLIB[..0x2000]), None).unwrap();
+        assert_eq!(image.segments().len(), 3);
+        assert!(image.symbols().is_empty());
+
+        // A symbol count, then the size of the function starts, running past the end of the file.
+        let patched = |file: &[u8], cmd, field| {
+            let image = MachO::file(slice(file), None).unwrap();
+            let (_, offset) = image.commands.iter().find(|(lc, _)| lc.cmd == cmd).unwrap();
+            let field = *offset as usize + field;
+            let mut data = file.to_vec();
+            data[field..field + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+            data
+        };
+        let data = patched(DYLIB, LC_SYMTAB, mem::offset_of!(symtab_command, nsyms));
+        let image = MachO::file(slice(&data), None).unwrap();
+        assert!(image.symbols().is_empty());
+        let data = patched(
+            STRIPPED,
+            LC_FUNCTION_STARTS,
+            mem::offset_of!(linkedit_data_command, datasize),
+        );
+        let image = MachO::file(slice(&data), None).unwrap();
+        assert!(image.function_starts().is_empty());
+
+        // A section size past the end of the file.
+        let image = MachO::file(slice(DYLIB), None).unwrap();
+        assert!(image.section("__TEXT", "__text").is_some());
+        let (_, offset) = image
+            .commands
+            .iter()
+            .find(|(lc, _)| lc.cmd == LC_SEGMENT_64)
+            .unwrap();
+        let size = *offset as usize
+            + mem::size_of::<segment_command_64>()
+            + mem::offset_of!(section_64, size);
+        let mut data = DYLIB.to_vec();
+        data[size..size + 8].copy_from_slice(&u64::MAX.to_le_bytes());
+        let image = MachO::file(slice(&data), None).unwrap();
+        assert!(image.section("__TEXT", "__text").is_none());
+    }
+}
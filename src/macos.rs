@@ -1,13 +1,23 @@
+use crate::{
+    condition::{self, Condition},
+    macho::{self, MachO},
+    minidump, reason,
+    sample::{self, Stacks},
+    symbolizer::Symbolizer,
+};
 use mach2::{
     exc, exception_types, kern_return, mach_port, mach_types, message, port, structs, task,
     task_info, thread_act, thread_status, traps, vm, vm_types,
 };
 use std::{
     ffi,
-    io::{Seek, Write},
+    io::{self, Seek, Write},
     mem,
     os::fd::{self, AsRawFd, FromRawFd},
-    ptr, thread,
+    ptr,
+    sync::mpsc,
+    thread,
+    time::{Duration, Instant},
 };
 
 #[allow(
@@ -17,26 +27,14 @@ use std::{
     non_camel_case_types
 )]
 mod dyld_images;
-#[allow(
-    non_snake_case,
-    non_upper_case_globals,
-    dead_code,
-    non_camel_case_types
-)]
-mod loader;
-#[allow(
-    non_snake_case,
-    non_upper_case_globals,
-    dead_code,
-    non_camel_case_types
-)]
-mod nlist;
 
 pub unsafe fn inspect<W: Write + Seek>(
     pid: i32,
-    catch_exc: bool,
+    session: bool,
     catch_exit: bool,
-    output_f: impl FnOnce() -> W,
+    breaks: &[String],
+    dumps: mpsc::Receiver<()>,
+    mut output_f: impl FnMut(i32) -> W,
 ) {
     if libc::getuid() != 0 {
         println!("root privilege required");
@@ -44,28 +42,10 @@ pub unsafe fn inspect<W: Write + Seek>(
     }
     let mut r;
 
-    let task = {
-        let mut task_name = 0;
-        r = traps::task_for_pid(traps::mach_task_self(), pid, &mut task_name);
-        assert_eq!(r, kern_return::KERN_SUCCESS);
-        Task {
-            port: Port { name: task_name },
-        }
-    };
+    let task = Task::for_pid(pid);
 
-    if !catch_exc && !catch_exit {
-        task.suspend();
-        let mw_r = minidump_writer::minidump_writer::MinidumpWriter::with_crash_context(
-            crash_context::CrashContext {
-                task: task.port.name,
-                thread: port::MACH_PORT_NULL,
-                handler_thread: port::MACH_PORT_NULL,
-                exception: None,
-            },
-        )
-        .dump(&mut output_f());
-        task.resume();
-        mw_r.unwrap();
+    if !session {
+        task.snapshot(&mut output_f(pid));
         return;
     }
 
@@ -111,6 +91,27 @@ pub unsafe fn inspect<W: Write + Seek>(
         });
     }
 
+    if !breaks.is_empty() {
+        let mut dt = dtrace::Dtrace::new().unwrap();
+        dt.setopt_c(c"strsize", c"4096").unwrap();
+        dt.setopt_c(c"bufsize", c"4m").unwrap();
+        dt.setopt_c(c"destructive", c"true").unwrap();
+        let program: String = breaks
+            .iter()
+            .map(|name| format!("pid{}::{}:entry{{stop(); trace(probefunc)}}\n", pid, name))
+            .collect();
+        dt.exec_program(&program).unwrap();
+        dt.go().unwrap();
+
+        let mon = mon.clone();
+        thread::spawn(move || loop {
+            match dt.work(|_| mon.request_break()) {
+                Ok(false) => {}
+                _ => break,
+            }
+        });
+    }
+
     let _old_exc_port = {
         let mut old_mask = 0;
         let mut old_mask_cnt = 1;
@@ -135,8 +136,26 @@ pub unsafe fn inspect<W: Write + Seek>(
         })
     };
 
+    {
+        let mon = mon.clone();
+        thread::spawn(move || {
+            for () in dumps {
+                mon.request_dump();
+            }
+        });
+    }
+
     println!("inspecting process: {}", pid);
-    let wait_r = mon.wait(pid, &exc_port);
+    let wait_r = loop {
+        match mon.wait(pid, &exc_port) {
+            Event::Dump => task.snapshot(&mut output_f(pid)),
+            Event::Break => {
+                task.snapshot(&mut output_f(pid));
+                let _ = libc::kill(pid, libc::SIGCONT);
+            }
+            ev => break ev,
+        }
+    };
 
     match wait_r {
         Event::Exit(code) => {
@@ -159,6 +178,16 @@ pub unsafe fn inspect<W: Write + Seek>(
             );
             assert_eq!(r, kern_return::KERN_SUCCESS);
 
+            println!(
+                "exception: {}",
+                reason::mach(
+                    msg.exception as u32,
+                    msg.code[0] as u64,
+                    (msg.codeCnt > 1).then_some(msg.code[1] as u64),
+                    cfg!(target_arch = "aarch64"),
+                )
+            );
+
             let exc_task_port = Port {
                 name: msg.task.name,
             };
@@ -166,7 +195,16 @@ pub unsafe fn inspect<W: Write + Seek>(
                 name: msg.thread.name,
             };
 
-            minidump_writer::minidump_writer::MinidumpWriter::with_crash_context(
+            let symbolizer = Symbolizer::new(&task.modules(&[]));
+            let read = |address, buf: &mut [u8]| task.read_bytes(address, buf);
+            // `analyze` finds the message again when the dump keeps the memory it was read from.
+            let (message, memory) =
+                crate::message::locate(&symbolizer, stack_pointer(exc_thread_port.name), read)
+                    .unzip();
+            if let Some(message) = message {
+                println!("{message}");
+            }
+            let mut dump = minidump_writer::minidump_writer::MinidumpWriter::with_crash_context(
                 crash_context::CrashContext {
                     task: exc_task_port.name,
                     thread: exc_thread_port.name,
@@ -178,8 +216,19 @@ pub unsafe fn inspect<W: Write + Seek>(
                     }),
                 },
             )
-            .dump(&mut output_f())
+            .dump(&mut io::Cursor::new(vec![]))
             .unwrap();
+            // The writer only keeps stacks and the memory around registers.
+            let memory: Vec<(u64, Vec<u8>)> = memory
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|(address, len)| {
+                    let mut buf = vec![0; len];
+                    task.read_bytes(address, &mut buf).then_some((address, buf))
+                })
+                .collect();
+            minidump::add_memory(&mut dump, &memory);
+            output_f(pid).write_all(&dump).unwrap();
         }
         Event::Stop => {
             minidump_writer::minidump_writer::MinidumpWriter::with_crash_context(
@@ -190,10 +239,103 @@ pub unsafe fn inspect<W: Write + Seek>(
                     exception: None,
                 },
             )
-            .dump(&mut output_f())
+            .dump(&mut output_f(pid))
             .unwrap();
             let _ = libc::kill(pid, libc::SIGCONT);
         }
+        Event::Dump | Event::Break => unreachable!(),
+    }
+}
+
+/// Request a dump each time one of the conditions on the task's memory becomes true.
+pub unsafe fn poll(
+    pid: i32,
+    conditions: &[Condition],
+    interval: Duration,
+    dump_tx: mpsc::Sender<()>,
+) -> Result<(), String> {
+    let task = Task::for_pid(pid);
+    let names: Vec<_> = conditions
+        .iter()
+        .filter(|c| c.address().is_none())
+        .map(|c| format!("_{}", c.target))
+        .collect();
+    let wanted: Vec<_> = names.iter().map(String::as_str).collect();
+    let modules = task.modules(&wanted);
+    let symbolizer = Symbolizer::new(&modules);
+    let conditions = conditions
+        .iter()
+        .map(|c| {
+            let name = format!("_{}", c.target);
+            let address = c
+                .address()
+                .or_else(|| symbolizer.lookup(&c.target))
+                // Images only in the shared cache have no file to read symbols from.
+                .or_else(|| {
+                    let mut symbols = modules.iter().flat_map(|m| &m.symbols);
+                    symbols.find(|(sym, _)| *sym == name).map(|&(_, a)| a)
+                })
+                .ok_or_else(|| format!("symbol not found: {}", c.target))?;
+            Ok((address, c.clone()))
+        })
+        .collect::<Result<_, String>>()?;
+    condition::poll(
+        conditions,
+        interval,
+        move |address, buf| task.read_bytes(address, buf),
+        dump_tx,
+    );
+    Ok(())
+}
+
+/// Sample the stacks of every thread, with the task suspended while they are walked.
+pub unsafe fn sample(pid: i32, hz: u32, duration: Duration) -> Stacks {
+    let task = Task::for_pid(pid);
+    let mut modules = task.modules(&[]);
+    let mut refreshed = Instant::now();
+    sample::sample(hz, duration, || {
+        if libc::kill(pid, 0) != 0 {
+            return vec![];
+        }
+        // Pick up the images loaded since.
+        if refreshed.elapsed() >= Duration::from_secs(1) {
+            modules = task.modules(&[]);
+            refreshed = Instant::now();
+        }
+        task.suspend();
+        let threads = task.threads(&modules);
+        task.resume();
+        threads.into_iter().map(|t| t.backtrace).collect()
+    })
+}
+
+/// The stack pointer of a thread.
+unsafe fn stack_pointer(thread: mach_types::thread_act_t) -> u64 {
+    #[cfg(target_arch = "aarch64")]
+    {
+        let mut state = structs::arm_thread_state64_t::new();
+        let mut count = structs::arm_thread_state64_t::count();
+        let r = thread_act::thread_get_state(
+            thread,
+            thread_status::ARM_THREAD_STATE64,
+            &mut state as *mut _ as _,
+            &mut count,
+        );
+        assert_eq!(r, kern_return::KERN_SUCCESS);
+        state.__sp
+    }
+    #[cfg(target_arch = "x86_64")]
+    {
+        let mut state = structs::x86_thread_state64_t::new();
+        let mut count = structs::x86_thread_state64_t::count();
+        let r = thread_act::thread_get_state(
+            thread,
+            thread_status::x86_THREAD_STATE64,
+            &mut state as *mut _ as _,
+            &mut count,
+        );
+        assert_eq!(r, kern_return::KERN_SUCCESS);
+        state.__rsp
     }
 }
 
@@ -215,6 +357,15 @@ struct Task {
 }
 
 impl Task {
+    fn for_pid(pid: i32) -> Self {
+        let mut task_name = 0;
+        let r = unsafe { traps::task_for_pid(traps::mach_task_self(), pid, &mut task_name) };
+        assert_eq!(r, kern_return::KERN_SUCCESS);
+        Task {
+            port: Port { name: task_name },
+        }
+    }
+
     fn suspend(&self) {
         let r = unsafe { task::task_suspend(self.port.name) };
         assert_eq!(r, kern_return::KERN_SUCCESS);
@@ -225,6 +376,21 @@ impl Task {
         assert_eq!(r, kern_return::KERN_SUCCESS);
     }
 
+    fn snapshot<W: Write + Seek>(&self, output: &mut W) {
+        self.suspend();
+        let mw_r = minidump_writer::minidump_writer::MinidumpWriter::with_crash_context(
+            crash_context::CrashContext {
+                task: self.port.name,
+                thread: port::MACH_PORT_NULL,
+                handler_thread: port::MACH_PORT_NULL,
+                exception: None,
+            },
+        )
+        .dump(output);
+        self.resume();
+        mw_r.unwrap();
+    }
+
     unsafe fn read<T>(&self, ptr: *const T) -> T {
         let mut v = mem::zeroed();
         let mut cnt = 0;
@@ -239,6 +405,18 @@ impl Task {
         v
     }
 
+    unsafe fn read_bytes(&self, address: u64, buf: &mut [u8]) -> bool {
+        let mut cnt = 0;
+        let r = vm::mach_vm_read_overwrite(
+            self.port.name,
+            address,
+            buf.len() as _,
+            buf.as_mut_ptr() as _,
+            &mut cnt,
+        );
+        r == kern_return::KERN_SUCCESS
+    }
+
     unsafe fn read_str(&self, addr: *const ffi::c_char) -> String {
         let mut s = vec![];
         let mut c: ffi::c_char = 0;
@@ -266,7 +444,6 @@ impl Task {
             .into_owned()
     }
 
-    #[allow(dead_code)]
     fn threads(&self, modules: &[super::Module]) -> Vec<super::Thread> {
         unsafe {
             let mut threads_ptr: mach_types::thread_act_array_t = ptr::null_mut();
@@ -337,8 +514,10 @@ impl Task {
                         .backtrace
                         .push(super::Backtrace::new(depth, pc, modules));
 
-                    while fp > 0 {
-                        pc = self.read((fp as *const u64).offset(1));
+                    // A thread caught in code without frame pointers may point anywhere.
+                    let mut word = [0; 8];
+                    while fp > 0 && self.read_bytes(fp + 8, &mut word) {
+                        pc = u64::from_le_bytes(word);
                         if pc == 0 {
                             break;
                         }
@@ -346,7 +525,10 @@ impl Task {
                         thread
                             .backtrace
                             .push(super::Backtrace::new(depth, pc, &modules));
-                        fp = self.read(fp as *const u64);
+                        if !self.read_bytes(fp, &mut word) {
+                            break;
+                        }
+                        fp = u64::from_le_bytes(word);
                     }
                     thread
                 })
@@ -364,8 +546,8 @@ impl Task {
         }
     }
 
-    #[allow(dead_code)]
-    unsafe fn modules(&self) -> Vec<super::Module> {
+    /// Loaded images, along with the addresses of any of the `wanted` symbols they define.
+    unsafe fn modules(&self, wanted: &[&str]) -> Vec<super::Module> {
         let mut info = task_info::task_dyld_info::default();
         let mut info_cnt = (mem::size_of_val(&info) / mem::size_of::<ffi::c_int>())
             as message::mach_msg_type_number_t;
@@ -383,59 +565,49 @@ impl Task {
         let parse_module =
             |path_ptr: *const ffi::c_char, load_address: *const dyld_images::mach_header| {
                 let path = self.read_str(path_ptr);
+                let load_address = load_address as u64;
+                let mut module = super::Module {
+                    path,
+                    load_address,
+                    ..Default::default()
+                };
+                let Some(image) = MachO::mapped(
+                    |address, buf: &mut [u8]| self.read_bytes(address, buf),
+                    load_address,
+                ) else {
+                    return module;
+                };
 
-                let mut slide = 0;
-                let mut text_segment = None;
-
-                let header_ptr = load_address as *const loader::mach_header_64;
-                let header = self.read(header_ptr);
-                let mut lc_ptr = header_ptr.offset(1) as *const libc::load_command;
-                let mut offset = 0;
-                let mut exit_address = None;
-
-                for _ in 0..header.sizeofcmds {
-                    let lc = self.read(lc_ptr);
-                    if lc.cmd == loader::LC_SEGMENT_64 {
-                        let seg = self.read(lc_ptr as *const libc::segment_command_64);
-                        let seg_name = ffi::CStr::from_ptr(seg.segname.as_ptr());
-                        if seg_name == c"__TEXT" {
-                            slide = load_address as u64 - seg.vmaddr;
-                            text_segment = Some(super::Range {
-                                start: slide + seg.vmaddr,
-                                end: slide + seg.vmaddr + seg.vmsize,
-                            });
-                        } else if seg_name == c"__LINKEDIT" {
-                            offset = seg.vmaddr - seg.fileoff;
-                        }
-                    } else if lc.cmd == loader::LC_SYMTAB {
-                        let sym_tab_cmd = self.read(lc_ptr as *const loader::symtab_command);
-                        let fixed = offset + slide;
-                        let sym_tab_ptr =
-                            (sym_tab_cmd.symoff as u64 + fixed) as *const nlist::nlist_64;
-                        let sym_str_ptr = (sym_tab_cmd.stroff as u64 + fixed) as *const ffi::c_char;
-                        for i in 0..sym_tab_cmd.nsyms {
-                            let sym = self.read(sym_tab_ptr.offset(i as _));
-                            if (sym.n_type as u32 & (nlist::N_STAB | nlist::N_PEXT)) > 0
-                                || (sym.n_type as u32 & nlist::N_SECT) == 0
-                            {
-                                continue;
-                            }
-                            let sym_name =
-                                self.read_str(sym_str_ptr.offset(sym.n_un.n_strx as isize));
-                            if sym_name == "__exit" {
-                                exit_address = Some(sym.n_value + slide);
-                            }
-                        }
-                    }
-                    lc_ptr = (lc_ptr as usize + lc.cmdsize as usize) as _;
+                let slide = image.slide();
+                module.text_segment = image
+                    .segments()
+                    .iter()
+                    .find(|seg| seg.name == "__TEXT")
+                    .map(|seg| super::Range {
+                        start: slide + seg.vmaddr,
+                        end: slide + seg.vmaddr + seg.vmsize,
+                    });
+                module.size = Some(image.size());
+                module.arch = macho::arch(image.header.cputype).map(ToOwned::to_owned);
+                module.version = image.version();
+                if let Some(uuid) = image.uuid() {
+                    let (code_id, debug_id) = macho::ids(&uuid);
+                    module.code_id = Some(code_id);
+                    module.debug_id = Some(debug_id);
                 }
-
-                super::Module {
-                    path,
-                    load_address: load_address as _,
-                    text_segment,
-                    exit_address,
+                if !wanted.is_empty() {
+                    // Stripped images still have their exports.
+                    module.symbols = image
+                        .symbols()
+                        .into_iter()
+                        .chain(image.exports())
+                        .filter(|sym| {
+                            sym.kind & macho::N_PEXT == 0 && wanted.contains(&sym.name.as_str())
+                        })
+                        .map(|sym| (sym.name, sym.value + slide))
+                        .collect();
                 }
+                module
             };
 
         let mut modules = Vec::with_capacity(1 + all_image_infos.infoArrayCount as usize);
@@ -475,9 +647,9 @@ impl Monitor {
             assert_ne!(fd, -1);
             let fd = fd::OwnedFd::from_raw_fd(fd);
 
-            {
+            for ident in [EV_IDENT_STOP, EV_IDENT_DUMP, EV_IDENT_BREAK] {
                 let event = libc::kevent {
-                    ident: 0,
+                    ident,
                     filter: libc::EVFILT_USER,
                     flags: libc::EV_ADD | libc::EV_CLEAR,
                     fflags: 0,
@@ -551,6 +723,11 @@ impl Monitor {
                     } else if event.filter == libc::EVFILT_MACHPORT {
                         break Event::Exception;
                     } else if event.filter == libc::EVFILT_USER {
+                        if event.ident == EV_IDENT_DUMP {
+                            break Event::Dump;
+                        } else if event.ident == EV_IDENT_BREAK {
+                            break Event::Break;
+                        }
                         break Event::Stop;
                     }
                 }
@@ -559,9 +736,21 @@ impl Monitor {
     }
 
     fn wake(&self) {
+        self.trigger(EV_IDENT_STOP);
+    }
+
+    fn request_dump(&self) {
+        self.trigger(EV_IDENT_DUMP);
+    }
+
+    fn request_break(&self) {
+        self.trigger(EV_IDENT_BREAK);
+    }
+
+    fn trigger(&self, ident: usize) {
         unsafe {
             let event = libc::kevent {
-                ident: 0,
+                ident,
                 filter: libc::EVFILT_USER,
                 flags: 0,
                 fflags: libc::NOTE_TRIGGER,
@@ -581,8 +770,14 @@ impl Monitor {
     }
 }
 
+const EV_IDENT_STOP: usize = 0;
+const EV_IDENT_DUMP: usize = 1;
+const EV_IDENT_BREAK: usize = 2;
+
 enum Event {
     Exit(i32),
     Exception,
     Stop,
+    Dump,
+    Break,
 }
@@ -1,40 +1,482 @@
-use clap::Parser;
-use serde::Serialize;
-use std::{fs::File, path::PathBuf};
+use clap::{error::ErrorKind, Args, CommandFactory, Parser, Subcommand, ValueEnum};
+use condition::Condition;
+use regex::bytes::Regex;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    ffi::OsString,
+    fs::File,
+    io::Write,
+    path::PathBuf,
+    process,
+    sync::mpsc,
+    time::{Duration, UNIX_EPOCH},
+};
 
+mod analysis;
+mod breakpad;
+mod condition;
+#[cfg(unix)]
+mod control;
+mod core;
+mod diff;
+mod dwarf;
+#[allow(non_camel_case_types, non_snake_case)]
+mod dyld_cache;
+#[allow(non_camel_case_types)]
+mod elf;
+mod gdbserver;
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+mod linux;
+#[allow(non_camel_case_types)]
+mod macho;
 #[cfg(target_os = "macos")]
 mod macos;
+mod message;
+mod minidump;
+mod pdb;
+#[allow(non_camel_case_types, non_snake_case)]
+mod pe;
+mod reason;
+mod run;
+#[cfg(any(target_os = "macos", all(target_os = "linux", target_arch = "x86_64")))]
+mod sample;
+mod signature;
+mod symbol_server;
+mod symbolizer;
 #[cfg(windows)]
 mod windows;
 
 fn main() {
     let Cli {
+        command,
         pid,
-        output,
-        exception,
-        exit,
+        #[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+        tree,
+        options,
     } = Cli::parse();
-    let output_f = || {
-        let output = output.unwrap_or_else(|| {
-            let now = chrono::Local::now();
-            PathBuf::from(format!("PID_{pid}_{}.dmp", now.format("%Y%m%d_%H%M%S")))
-        });
-        File::create(&output).unwrap()
+
+    #[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+    if tree {
+        let pid = pid.unwrap();
+        let manifest = linux::tree(pid, options.format, options.output_path(pid))
+            .unwrap_or_else(|error| Cli::command().error(ErrorKind::Io, error).exit());
+        let path = manifest.processes[0].file.with_extension("json");
+        serde_json::to_writer_pretty(File::create(&path).unwrap(), &manifest).unwrap();
+        println!("manifest: {}", path.display());
+        return;
+    }
+
+    let (options, run) = match command {
+        None => (options, None),
+        Some(Command::Convert { conversion }) => {
+            convert(conversion);
+            return;
+        }
+        Some(Command::Analyze {
+            dump,
+            signature,
+            symbols,
+        }) => {
+            let data = std::fs::read(dump).unwrap();
+            let dump = minidump::Minidump::parse(&data).expect("not a minidump");
+            let mut snapshot = analysis::snapshot(&dump, &symbols);
+            snapshot.signature = signature::signature(&snapshot, &signature);
+            serde_json::to_writer_pretty(std::io::stdout().lock(), &snapshot).unwrap();
+            println!();
+            return;
+        }
+        Some(Command::Diff {
+            before,
+            after,
+            symbols,
+        }) => {
+            let load = |path| {
+                diff::load(path, &symbols)
+                    .unwrap_or_else(|error| Cli::command().error(ErrorKind::Io, error).exit())
+            };
+            let diff = diff::diff(&load(&before), &load(&after));
+            serde_json::to_writer_pretty(std::io::stdout().lock(), &diff).unwrap();
+            println!();
+            return;
+        }
+        Some(Command::Bucket {
+            dir,
+            signature,
+            symbols,
+        }) => {
+            let buckets = signature::bucket(&dir, &signature, &symbols)
+                .unwrap_or_else(|error| Cli::command().error(ErrorKind::Io, error).exit());
+            serde_json::to_writer_pretty(std::io::stdout().lock(), &buckets).unwrap();
+            println!();
+            return;
+        }
+        #[cfg(any(target_os = "macos", all(target_os = "linux", target_arch = "x86_64")))]
+        Some(Command::Sample {
+            pid,
+            hz,
+            duration,
+            output,
+        }) => {
+            #[cfg(target_os = "macos")]
+            let stacks = unsafe { macos::sample(pid, hz, duration) };
+            #[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+            let stacks = linux::sample(pid, hz, duration);
+            let svg = output.unwrap_or_else(|| {
+                let now = chrono::Local::now().format("%Y%m%d_%H%M%S");
+                PathBuf::from(format!("PID_{pid}_{now}.svg"))
+            });
+            std::fs::write(svg.with_extension("folded"), sample::folded(&stacks)).unwrap();
+            let title = format!("PID {pid}, {} samples", stacks.values().sum::<usize>());
+            std::fs::write(&svg, sample::flamegraph(&stacks, &title)).unwrap();
+            println!("{title}: {}", svg.display());
+            return;
+        }
+        Some(Command::DumpSyms { binary, store }) => {
+            let data = std::fs::read(&binary).unwrap();
+            let name = binary.file_name().unwrap().to_string_lossy();
+            let file = breakpad::dump_syms(&data, &name)
+                .expect("not a PDB, or an ELF, Mach-O or PE image with a build ID, UUID or PDB");
+            match store {
+                Some(store) => {
+                    let path = breakpad::store_path(&store, &file.name, &file.debug_id);
+                    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+                    std::fs::write(&path, file.to_string()).unwrap();
+                    println!("{}", path.display());
+                }
+                None => print!("{file}"),
+            }
+            return;
+        }
+        Some(Command::Gdbserver { dump, listen }) => {
+            gdbserver::serve(&std::fs::read(dump).unwrap(), &listen);
+            return;
+        }
+        #[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+        Some(Command::CoreHandler {
+            dir,
+            pid,
+            signal,
+            time,
+            exe,
+            keep,
+        }) => {
+            // Read /proc first, the process is reaped once the core has been consumed.
+            let streams = linux::proc_streams(pid);
+            let mut core = core::Core::read(std::io::stdin().lock()).unwrap();
+            let reason = core
+                .signal
+                .get_or_insert(core::Signal {
+                    signo: signal,
+                    code: 0,
+                    address: 0,
+                })
+                .reason();
+            let crashed = chrono::DateTime::from_timestamp(time, 0)
+                .unwrap_or_else(chrono::Utc::now)
+                .with_timezone(&chrono::Local);
+            let cpus = std::thread::available_parallelism().map_or(1, |n| n.get().min(255) as u8);
+            let minidump = core.minidump(time as u32, cpus, &linux::os_version(), &streams);
+            // Processes of other PID namespaces crashing within the same second share a name.
+            let mut path = dir.join(dump_name(pid, crashed, "dmp"));
+            let mut n = 0;
+            let file = loop {
+                match File::options().write(true).create_new(true).open(&path) {
+                    Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+                        n += 1;
+                        path = dir.join(dump_name(pid, crashed, &format!("{n}.dmp")));
+                    }
+                    file => break file,
+                }
+            };
+            if let Err(error) = file.and_then(|mut file| file.write_all(&minidump)) {
+                eprintln!(
+                    "{exe} ({pid}) killed by {reason}: {}: {error}",
+                    path.display()
+                );
+                return;
+            }
+            println!("{exe} ({pid}) killed by {reason}: {}", path.display());
+            if let Some(keep) = keep {
+                prune(&dir, keep);
+            }
+            return;
+        }
+        Some(Command::Run {
+            options,
+            on_output,
+            command,
+        }) => (options, Some((command, on_output))),
     };
+    #[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+    if options.watches.len() > 4 {
+        Cli::command()
+            .error(
+                ErrorKind::TooManyValues,
+                "at most 4 --watch are supported, one per debug register",
+            )
+            .exit();
+    }
+
+    let (dump_tx, dump_rx) = mpsc::channel();
+    // Only sessions watching the process take dump requests, and the signal must be blocked
+    // before any other thread is spawned.
+    #[cfg(unix)]
+    if run.is_some() || options.session() {
+        control::signal(dump_tx.clone());
+    }
+    let mut run = run.map(|(command, on_output)| run::spawn(&command, on_output, &dump_tx));
+    let pid = run
+        .as_ref()
+        .map(|(child, _)| child.id() as i32)
+        .or(pid)
+        .unwrap();
+    #[cfg(unix)]
+    let control = options
+        .control
+        .as_ref()
+        .map(|path| control::listen(path, dump_tx.clone()).unwrap());
+    if !options.when.is_empty() {
+        let interval = Duration::from_millis(options.when_interval);
+        poll(pid, &options.when, interval, dump_tx.clone());
+    }
+    drop(dump_tx);
+    let mut output_f = options.output_f(pid);
+
+    match &mut run {
+        Some((child, _)) if !options.session() => {
+            while child.try_wait().unwrap().is_none() {
+                if dump_rx.recv_timeout(Duration::from_millis(100)).is_ok() {
+                    let options = Options {
+                        #[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+                        format: options.format,
+                        ..Default::default()
+                    };
+                    inspect(pid, &options, mpsc::channel().1, &mut output_f);
+                }
+            }
+        }
+        _ => inspect(pid, &options, dump_rx, &mut output_f),
+    }
+    #[cfg(unix)]
+    drop(control);
+
+    if let Some((mut child, forwards)) = run {
+        for forward in forwards {
+            let _ = forward.join();
+        }
+        let status = child.wait().unwrap();
+        process::exit(status.code().unwrap_or(1));
+    }
+}
+
+fn inspect(
+    pid: i32,
+    options: &Options,
+    dumps: mpsc::Receiver<()>,
+    output_f: impl FnMut(i32) -> File,
+) {
     #[cfg(target_os = "macos")]
     unsafe {
-        macos::inspect(pid, exception, exit, output_f)
+        macos::inspect(
+            pid,
+            options.session(),
+            options.exit,
+            &options.breaks,
+            dumps,
+            output_f,
+        )
     };
     #[cfg(windows)]
     unsafe {
-        windows::inspect(pid, exception, exit, output_f)
+        windows::inspect(pid, options.session(), options.exit, dumps, output_f)
     };
+    #[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+    if let Err(error) = unsafe {
+        linux::inspect(
+            pid,
+            options.session(),
+            options.exception,
+            options.exit,
+            options.exec,
+            options.follow_forks,
+            &options.breaks,
+            &options.watches,
+            options.format,
+            dumps,
+            output_f,
+        )
+    } {
+        Cli::command().error(ErrorKind::InvalidValue, error).exit();
+    }
+}
+
+fn convert(conversion: Conversion) {
+    match conversion {
+        Conversion::Core2md { core, dmp } => {
+            let file = File::open(&core).unwrap();
+            let time = file.metadata().and_then(|m| m.modified()).unwrap();
+            let time = time.duration_since(UNIX_EPOCH).unwrap().as_secs() as u32;
+            let core = core::Core::read(std::io::BufReader::new(file)).unwrap();
+            std::fs::write(dmp, core.minidump(time, 0, "", &[])).unwrap();
+        }
+        Conversion::Md2core { dmp, core } => {
+            let data = std::fs::read(dmp).unwrap();
+            let dump = minidump::Minidump::parse(&data).expect("not a minidump");
+            let dump = core::Core::from_minidump(&dump).expect("not a Linux x86-64 minidump");
+            dump.write(std::io::BufWriter::new(File::create(core).unwrap()))
+                .unwrap();
+        }
+    }
+}
+
+fn poll(pid: i32, conditions: &[Condition], interval: Duration, dump_tx: mpsc::Sender<()>) {
+    #[cfg(target_os = "macos")]
+    let result = unsafe { macos::poll(pid, conditions, interval, dump_tx) };
+    #[cfg(windows)]
+    let result = unsafe { windows::poll(pid, conditions, interval, dump_tx) };
+    #[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+    let result = linux::poll(pid, conditions, interval, dump_tx);
+    #[cfg(not(any(
+        target_os = "macos",
+        windows,
+        all(target_os = "linux", target_arch = "x86_64")
+    )))]
+    let result: Result<(), String> = Ok(());
+    if let Err(error) = result {
+        Cli::command().error(ErrorKind::InvalidValue, error).exit();
+    }
 }
 
 #[derive(Parser)]
-#[command(version, about, long_about = None)]
+#[command(
+    version,
+    about,
+    long_about = None,
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
 struct Cli {
-    pid: i32,
+    #[command(subcommand)]
+    command: Option<Command>,
+    #[arg(required = true)]
+    pid: Option<i32>,
+    /// Freeze the process and all its descendants, dump each of them and write a manifest linking
+    /// the dumps
+    #[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+    #[arg(
+        long,
+        default_value_t = false,
+        conflicts_with_all = [
+            "exception", "exit", "control", "breaks", "watches", "exec", "follow_forks", "when",
+        ]
+    )]
+    tree: bool,
+    #[command(flatten)]
+    options: Options,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a command and inspect it, forwarding its stdout and stderr
+    Run {
+        #[command(flatten)]
+        options: Options,
+        /// Write a dump when a line of the command's stdout or stderr matches this regex
+        #[arg(long, value_name = "REGEX")]
+        on_output: Option<Regex>,
+        #[arg(last = true, required = true)]
+        command: Vec<OsString>,
+    },
+    /// Convert between ELF core files and minidumps
+    Convert {
+        #[command(subcommand)]
+        conversion: Conversion,
+    },
+    /// Print a JSON report of a minidump's threads and modules, classifying its crash
+    Analyze {
+        dump: PathBuf,
+        #[command(flatten)]
+        signature: SignatureOptions,
+        #[command(flatten)]
+        symbols: SymbolOptions,
+    },
+    /// Compare two minidumps or JSON reports of the same process, printing the differences as JSON
+    Diff {
+        before: PathBuf,
+        after: PathBuf,
+        #[command(flatten)]
+        symbols: SymbolOptions,
+    },
+    /// Group the minidumps of a directory by crash signature, printing each group as JSON
+    Bucket {
+        dir: PathBuf,
+        #[command(flatten)]
+        signature: SignatureOptions,
+        #[command(flatten)]
+        symbols: SymbolOptions,
+    },
+    /// Sample the stacks of a running process, writing an SVG flamegraph and the collapsed stacks
+    #[cfg(any(target_os = "macos", all(target_os = "linux", target_arch = "x86_64")))]
+    Sample {
+        pid: i32,
+        /// Samples per second
+        #[arg(long, default_value_t = 99)]
+        hz: u32,
+        /// How long to sample, e.g. 30s, 500ms or 2m
+        #[arg(long, value_parser = sample::parse_duration, default_value = "10s")]
+        duration: Duration,
+        /// Output flamegraph, with the collapsed stacks next to it in a .folded file
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Write the Breakpad symbol file of an ELF, Mach-O or PE image, with the lines and unwind rules
+    /// of its DWARF, or of a PDB
+    DumpSyms {
+        binary: PathBuf,
+        /// Write into this symbol store, as <name>/<debug_id>/<name>.sym, rather than to stdout
+        #[arg(long, value_name = "DIR")]
+        store: Option<PathBuf>,
+    },
+    /// Serve a minidump over the GDB remote serial protocol, for `target remote` in gdb or lldb
+    Gdbserver {
+        dump: PathBuf,
+        #[arg(long, value_name = "ADDR", default_value = "127.0.0.1:1234")]
+        listen: String,
+    },
+    /// Convert the ELF core on stdin to a minidump, as a core_pattern handler
+    ///
+    /// Install with `|/usr/bin/pmortem core-handler %P %s %t %e` in /proc/sys/kernel/core_pattern,
+    /// and a non-zero /proc/sys/kernel/core_pipe_limit for /proc/<PID> to outlive the core.
+    #[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+    CoreHandler {
+        /// Directory to write the dumps to
+        #[arg(short = 'C', long, default_value = "/var/crash")]
+        dir: PathBuf,
+        /// PID of the crashed process, in the initial PID namespace (%P)
+        pid: i32,
+        /// Number of the signal that killed the process (%s)
+        signal: i32,
+        /// Time of the crash, in seconds since the epoch (%t)
+        time: i64,
+        /// Executable name of the process (%e)
+        exe: String,
+        /// Keep only this many dumps in the directory, removing the oldest
+        #[arg(long, value_name = "N")]
+        keep: Option<usize>,
+    },
+}
+
+#[derive(Subcommand)]
+enum Conversion {
+    /// Convert a Linux x86-64 ELF core to a minidump
+    Core2md { core: PathBuf, dmp: PathBuf },
+    /// Convert a Linux x86-64 minidump to an ELF core that gdb can load
+    Md2core { dmp: PathBuf, core: PathBuf },
+}
+
+#[derive(Args, Default)]
+struct Options {
     /// Output dump file
     #[arg(short, long)]
     output: Option<PathBuf>,
@@ -44,22 +486,290 @@ struct Cli {
     /// Write a dump when the process exit
     #[arg(long, default_value_t = false)]
     exit: bool,
+    /// Write a dump whenever a client connects to this Unix domain socket
+    #[cfg(unix)]
+    #[arg(long)]
+    control: Option<PathBuf>,
+    /// Write a dump each time the process calls this function
+    #[cfg(unix)]
+    #[arg(long = "break", value_name = "SYMBOL")]
+    breaks: Vec<String>,
+    /// Write a dump each time the process writes to this address or variable, blaming the writing
+    /// thread
+    #[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+    #[arg(long = "watch", value_name = "ADDR|SYMBOL[:LEN]")]
+    watches: Vec<linux::Watch>,
+    /// Write a dump each time the process is about to exec a new image
+    #[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+    #[arg(long, default_value_t = false)]
+    exec: bool,
+    /// Extend the monitoring to every process forked by the process and its descendants
+    #[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+    #[arg(long, default_value_t = false)]
+    follow_forks: bool,
+    /// Write a dump each time this condition on memory becomes true, e.g. `counter:u64 > 1000`
+    #[arg(long, value_name = "EXPR")]
+    when: Vec<Condition>,
+    /// How often to evaluate the --when conditions
+    #[arg(long, value_name = "MS", default_value_t = 1000)]
+    when_interval: u64,
+    /// Format of the dumps
+    #[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+}
+
+/// How crash signatures are made from the frames of the crashing thread.
+#[derive(Args)]
+struct SignatureOptions {
+    /// Number of frames in a crash signature
+    #[arg(long, value_name = "N", default_value_t = 5)]
+    frames: usize,
+    /// Leave frames matching this `*` and `?` pattern out of crash signatures, on top of abort,
+    /// raise and the panic machinery
+    #[arg(long, value_name = "PATTERN")]
+    skip: Vec<String>,
+}
+
+/// Where the symbolizer finds symbols beyond the modules' own files.
+#[derive(Args, Default)]
+struct SymbolOptions {
+    /// Directory of dyld shared caches copied from a Mac with their sub-caches and .symbols files,
+    /// to symbolize the system libraries of macOS dumps
+    #[arg(long, value_name = "DIR")]
+    dyld_cache: Option<PathBuf>,
+    /// UUID of the shared cache the process used, when the directory holds several
+    #[arg(long, value_name = "UUID", value_parser = dyld_cache::parse_uuid, requires = "dyld_cache")]
+    dyld_cache_uuid: Option<[u8; 16]>,
+    /// Breakpad symbol store laid out as <name>/<debug_id>/<name>.sym, as dump-syms --store writes
+    /// it, to symbolize and unwind modules without their binaries. PDBs stored as
+    /// <name>/<debug_id>/<name> are read too
+    #[arg(long = "symbols", value_name = "DIR")]
+    stores: Vec<PathBuf>,
+    /// debuginfod server to fetch the debug files of ELF images from by build ID, defaults to the
+    /// servers of $DEBUGINFOD_URLS
+    #[arg(long, value_name = "URL")]
+    debuginfod: Vec<String>,
+    /// Microsoft-style symbol server, laid out as <name>/<id>/<name>, to fetch Breakpad symbol
+    /// files, PDBs and PE images from
+    #[arg(long = "symbol-server", value_name = "URL")]
+    symbol_servers: Vec<String>,
+    /// Where to cache the files fetched from servers, and which ones they don't have [default:
+    /// ~/.cache/pmortem]
+    #[arg(long, value_name = "DIR")]
+    symbol_cache: Option<PathBuf>,
+}
+
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+#[derive(Clone, Copy, Default, ValueEnum)]
+enum Format {
+    #[default]
+    Minidump,
+    /// An ELF core, with the memory selected by the process's coredump_filter
+    Core,
+}
+
+impl Options {
+    /// Whether to keep watching the process after attaching to it.
+    fn session(&self) -> bool {
+        #[cfg(unix)]
+        if !self.breaks.is_empty() {
+            return true;
+        }
+        #[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+        if !self.watches.is_empty() || self.exec {
+            return true;
+        }
+        !self.when.is_empty() || self.exception || self.exit
+    }
+
+    /// Dump files of the process `root` and its descendants, each named by its own pid.
+    fn output_path(&self, root: i32) -> impl FnMut(i32) -> PathBuf {
+        let output = self.output.clone();
+        #[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+        let extension = match self.format {
+            Format::Minidump => "dmp",
+            Format::Core => "core",
+        };
+        #[cfg(not(all(target_os = "linux", target_arch = "x86_64")))]
+        let extension = "dmp";
+        let mut seqs = HashMap::new();
+        move |pid| {
+            let seq = seqs.entry(pid).or_insert(0);
+            let mut suffix = String::new();
+            let mut output = match &output {
+                Some(output) => {
+                    if pid != root {
+                        suffix += &format!("_{pid}");
+                    }
+                    output.clone()
+                }
+                None => PathBuf::from(dump_name(pid, chrono::Local::now(), extension)),
+            };
+            if *seq > 0 {
+                suffix += &format!("_{seq}");
+            }
+            if !suffix.is_empty() {
+                let stem = output.file_stem().unwrap_or_default().to_string_lossy();
+                let name = match output.extension() {
+                    Some(ext) => format!("{stem}{suffix}.{}", ext.to_string_lossy()),
+                    None => format!("{stem}{suffix}"),
+                };
+                output.set_file_name(name);
+            }
+            *seq += 1;
+            output
+        }
+    }
+
+    fn output_f(&self, root: i32) -> impl FnMut(i32) -> File {
+        let mut output_path = self.output_path(root);
+        move |pid| File::create(output_path(pid)).unwrap()
+    }
+}
+
+/// The default name of a dump of `pid` taken at `time`.
+fn dump_name(pid: i32, time: chrono::DateTime<chrono::Local>, extension: &str) -> String {
+    format!("PID_{pid}_{}.{extension}", time.format("%Y%m%d_%H%M%S"))
+}
+
+/// Remove the oldest default-named dumps in `dir` but the `keep` most recent.
+#[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+fn prune(dir: &std::path::Path, keep: usize) {
+    let mut dumps: Vec<_> = std::fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|entry| {
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            name.starts_with("PID_") && name.ends_with(".dmp")
+        })
+        .filter_map(|entry| Some((entry.metadata().ok()?.modified().ok()?, entry.path())))
+        .collect();
+    dumps.sort();
+    let excess = dumps.len().saturating_sub(keep);
+    for (_, path) in &dumps[..excess] {
+        let _ = std::fs::remove_file(path);
+    }
 }
 
-#[allow(dead_code)]
+/// Dumps of a process tree, written while it was frozen.
 #[derive(Serialize)]
+struct Manifest {
+    time: String,
+    freezer: &'static str,
+    processes: Vec<Process>,
+}
+
+#[derive(Serialize)]
+struct Process {
+    pid: i32,
+    ppid: i32,
+    file: PathBuf,
+}
+
+#[derive(Serialize, Deserialize)]
 struct Snapshot {
     threads: Vec<Thread>,
     modules: Vec<Module>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    classification: Option<Classification>,
+    /// The normalized top frames of the crashing thread.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    signature: Vec<String>,
+    /// The memory mappings of the process, when the dump has them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    regions: Vec<Region>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Region {
+    #[serde(with = "hex")]
+    start: u64,
+    #[serde(with = "hex")]
+    end: u64,
+    perms: String,
+    /// The mapped file or a pseudo-path such as `[heap]`, empty for anonymous memory.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    path: String,
+}
+
+/// How the process changed between two snapshots.
+#[derive(Serialize)]
+struct Diff {
+    threads_appeared: Vec<u64>,
+    threads_disappeared: Vec<u64>,
+    /// Threads whose backtrace changed, the others having been at the same place in both.
+    threads_changed: Vec<u64>,
+    threads_unchanged: Vec<u64>,
+    modules_loaded: Vec<String>,
+    modules_unloaded: Vec<String>,
+    /// Mappings whose size changed, grouped by path, the largest growth first.
+    regions: Vec<Growth>,
+}
+
+#[derive(Serialize)]
+struct Growth {
+    path: String,
+    before: u64,
+    after: u64,
+    growth: i64,
 }
 
+/// Dumps sharing a crash signature.
 #[derive(Serialize)]
+struct Bucket {
+    signature: Vec<String>,
+    count: usize,
+    first_seen: String,
+    last_seen: String,
+    examples: Vec<PathBuf>,
+}
+
+/// What kind of crash the exception is, guessed from the fault and the registers.
+#[derive(Serialize, Deserialize)]
+struct Classification {
+    kind: Kind,
+    explanation: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Kind {
+    NullPointerDereference,
+    StackOverflow,
+    NonExecutableJump,
+    UseAfterFree,
+    Abort,
+}
+
+#[derive(Serialize, Deserialize)]
 struct Exception {
     reason: i32,
     code: [i32; 2],
+    /// The decoded reason, e.g. `SIGSEGV SEGV_MAPERR at 0x10: address not mapped to object`.
+    description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<Message>,
 }
 
-#[derive(Serialize)]
+/// What the process said on its way to crash.
+#[derive(Serialize, Deserialize)]
+struct Message {
+    origin: Origin,
+    text: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Origin {
+    Abort,
+    Assertion,
+    Panic,
+}
+
+#[derive(Serialize, Deserialize)]
 struct Thread {
     id: u64,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -67,7 +777,7 @@ struct Thread {
     backtrace: Vec<Backtrace>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 struct Backtrace {
     depth: u32,
     #[serde(with = "hex")]
@@ -76,10 +786,26 @@ struct Backtrace {
     module: Option<Module>,
     #[serde(skip_serializing_if = "Option::is_none")]
     symbol: Option<String>,
+    /// Bytes from the start of the symbol to the address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    offset: Option<u64>,
+    /// File and line of the address, as `file:line`, from a Breakpad symbol file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source: Option<String>,
+    /// The functions inlined at the address, innermost first, `source` being the call of the
+    /// outermost one.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    inlined: Vec<Inlined>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Inlined {
+    symbol: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source: Option<String>,
 }
 
 impl Backtrace {
-    #[allow(dead_code)]
     fn new(depth: u32, address: u64, modules: &[Module]) -> Self {
         Self {
             depth,
@@ -94,23 +820,45 @@ impl Backtrace {
                 .map(ToOwned::to_owned),
             address,
             symbol: None,
+            offset: None,
+            source: None,
+            inlined: vec![],
         }
     }
 }
 
-#[derive(Serialize, Clone)]
+#[derive(Serialize, Deserialize, Clone, Default)]
 struct Module {
     path: String,
     #[serde(with = "hex")]
     load_address: u64,
+    /// Size of the image in memory.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    size: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     text_segment: Option<Range>,
+    /// The ELF build ID, Mach-O UUID, or PE timestamp and size, which symbol servers index images
+    /// by.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code_id: Option<String>,
+    /// Breakpad's identifier of the debug file: the GUID of a PDB, a Mach-O UUID or the start of an
+    /// ELF build ID, then an age.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    debug_id: Option<String>,
+    /// The name of the PDB of a PE image, which symbol stores index its symbols by.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    debug_file: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    arch: Option<String>,
+    /// The file version of a PE image, or the current version of a dylib.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<String>,
     #[allow(dead_code)]
     #[serde(skip)]
-    exit_address: Option<u64>,
+    symbols: Vec<(String, u64)>,
 }
 
-#[derive(Serialize, Clone, Copy)]
+#[derive(Serialize, Deserialize, Clone, Copy)]
 struct Range {
     #[serde(with = "hex")]
     start: u64,
@@ -125,7 +873,7 @@ impl Range {
 }
 
 mod hex {
-    use serde::Serializer;
+    use serde::{Deserialize, Deserializer, Serializer};
     use std::fmt::LowerHex;
 
     pub fn serialize<V, S>(number: &V, serializer: S) -> Result<S::Ok, S::Error>
@@ -136,4 +884,12 @@ mod hex {
         let s = format!("{number:#018x}");
         serializer.serialize_str(&s)
     }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<u64, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        u64::from_str_radix(s.trim_start_matches("0x"), 16).map_err(serde::de::Error::custom)
+    }
 }
@@ -0,0 +1,160 @@
+use crate::{symbolizer::Symbolizer, Message, Origin};
+use std::fmt;
+
+const PAGE_SIZE: u64 = 0x1000;
+/// How much of the crashing thread's stack is searched for frames and the panic payload.
+const STACK_SCAN: u64 = 64 * 1024;
+/// Shorter pairs are too often a length next to an unrelated pointer.
+const MIN_MESSAGE: usize = 4;
+const MAX_MESSAGE: usize = 4096;
+
+impl fmt::Display for Message {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let origin = match self.origin {
+            Origin::Abort => "abort",
+            Origin::Assertion => "assertion",
+            Origin::Panic => "panic",
+        };
+        write!(f, "{origin}: {}", self.text)
+    }
+}
+
+/// The message left by a Rust panic or glibc's `abort()` and `assert()`, with the memory of the
+/// process read through `read` and its stack pointer at the crash.
+pub fn find(
+    symbolizer: &Symbolizer,
+    sp: u64,
+    read: impl Fn(u64, &mut [u8]) -> bool,
+) -> Option<Message> {
+    locate(symbolizer, sp, read).map(|(message, _)| message)
+}
+
+/// The message as `find`, with the memory it was read from besides the stack, for a dump of the
+/// live process to keep.
+pub fn locate(
+    symbolizer: &Symbolizer,
+    sp: u64,
+    read: impl Fn(u64, &mut [u8]) -> bool,
+) -> Option<(Message, Vec<(u64, usize)>)> {
+    let stack = stack(sp, &read);
+    // Return addresses left on the stack, whatever the unwind information says.
+    let frames: Vec<(usize, &str)> = stack
+        .iter()
+        .enumerate()
+        .filter_map(|(i, &word)| Some((i, symbolizer.symbol(word)?)))
+        .collect();
+
+    // The payload is held by the frame calling into `std::panicking`, up the stack from
+    // `rust_panic`.
+    if let Some(mut i) = frames.iter().position(|(_, f)| f.ends_with("rust_panic")) {
+        while frames.get(i + 1).is_some_and(|(_, f)| panicking(f)) {
+            i += 1;
+        }
+        let end = frames.get(i + 1).map_or(stack.len(), |&(end, _)| end);
+        if let Some((ptr, text)) = panic_payload(&stack[frames[i].0..end], &read) {
+            let memory = vec![(ptr, text.len())];
+            let origin = Origin::Panic;
+            return Some((Message { origin, text }, memory));
+        }
+    }
+
+    // glibc stores the assertion failure and its own fatal errors in `__abort_msg`, as a
+    // `struct abort_msg_s { unsigned int size; char msg[]; }`.
+    let variable = symbolizer.lookup("__abort_msg")?;
+    let abort_msg = read_u64(&read, variable).filter(|&p| p != 0)?;
+    let size = read_u64(&read, abort_msg)? as u32 as usize;
+    let mut msg = vec![0; size.saturating_sub(4).min(MAX_MESSAGE)];
+    if !read(abort_msg + 4, &mut msg) {
+        return None;
+    }
+    let text = String::from_utf8_lossy(msg.split(|&b| b == 0).next()?)
+        .trim_end()
+        .to_owned();
+    // `__assert_fail_base` is a local symbol, gone from a stripped libc, but the text tells.
+    let origin = if frames.iter().any(|(_, f)| f.starts_with("__assert_fail"))
+        || text.ends_with("' failed.") && text.contains(": Assertion `")
+    {
+        Origin::Assertion
+    } else {
+        Origin::Abort
+    };
+    let memory = vec![(variable, 8), (abort_msg, (4 + msg.len()).max(8))];
+    (!text.is_empty()).then_some((Message { origin, text }, memory))
+}
+
+fn panicking(function: &str) -> bool {
+    function.starts_with("std::panicking::")
+        || function.starts_with("core::panicking::")
+        || function.ends_with("rust_panic")
+}
+
+/// The words of the stack above the stack pointer, up to the first unreadable page.
+fn stack(sp: u64, read: impl Fn(u64, &mut [u8]) -> bool) -> Vec<u64> {
+    let mut stack = vec![];
+    let mut address = sp & !7;
+    while address < sp + STACK_SCAN {
+        let mut page = vec![0; (PAGE_SIZE - address % PAGE_SIZE) as usize];
+        if !read(address, &mut page) {
+            break;
+        }
+        stack.extend(
+            page.chunks_exact(8)
+                .map(|c| u64::from_le_bytes(c.try_into().unwrap())),
+        );
+        address += page.len() as u64;
+    }
+    stack
+}
+
+/// The address and text of the payload of a panic, a `&str` or the formatted `String`, whose
+/// pointer and length are next to each other.
+fn panic_payload(stack: &[u64], read: impl Fn(u64, &mut [u8]) -> bool) -> Option<(u64, String)> {
+    stack.windows(2).find_map(|pair| {
+        let (ptr, len) = (pair[0], pair[1] as usize);
+        if !(MIN_MESSAGE..=MAX_MESSAGE).contains(&len) || ptr < PAGE_SIZE {
+            return None;
+        }
+        let mut text = vec![0; len];
+        if !read(ptr, &mut text) {
+            return None;
+        }
+        let text = String::from_utf8(text).ok()?;
+        let printable = text
+            .chars()
+            .all(|c| !c.is_control() || c == '\n' || c == '\t');
+        (printable && text.chars().any(char::is_alphanumeric)).then_some((ptr, text))
+    })
+}
+
+fn read_u64(read: impl Fn(u64, &mut [u8]) -> bool, address: u64) -> Option<u64> {
+    let mut buf = [0; 8];
+    read(address, &mut buf).then(|| u64::from_le_bytes(buf))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn payload() {
+        let memory: [(u64, &[u8]); 4] = [
+            (0x1000, b"x"),
+            (0x2000, b"\x17\x17\x17\x17"),
+            (0x3000, b"...."),
+            (0x4000, b"attempt to divide by zero"),
+        ];
+        let read = |address, buf: &mut [u8]| {
+            let data = memory.iter().find(|&&(a, _)| a == address).map(|m| m.1);
+            data.filter(|data| data.len() >= buf.len())
+                .map(|data| buf.copy_from_slice(&data[..buf.len()]))
+                .is_some()
+        };
+        // Too short, control characters and no words before the payload.
+        let stack = [0x1000, 1, 0x2000, 4, 0x3000, 4, 0x4000, 25];
+        assert_eq!(
+            panic_payload(&stack, read),
+            Some((0x4000, "attempt to divide by zero".to_owned()))
+        );
+        assert_eq!(panic_payload(&stack[..6], read), None);
+    }
+}
@@ -0,0 +1,842 @@
+pub const SIGNATURE: u32 = 0x504d_444d;
+pub const VERSION: u32 = 0xa793;
+
+pub const THREAD_LIST_STREAM: u32 = 3;
+pub const MODULE_LIST_STREAM: u32 = 4;
+pub const MEMORY_LIST_STREAM: u32 = 5;
+pub const EXCEPTION_STREAM: u32 = 6;
+pub const SYSTEM_INFO_STREAM: u32 = 7;
+pub const MEMORY_64_LIST_STREAM: u32 = 9;
+pub const MEMORY_INFO_LIST_STREAM: u32 = 16;
+pub const LINUX_CPU_INFO: u32 = 0x4767_0003;
+pub const LINUX_PROC_STATUS: u32 = 0x4767_0004;
+pub const LINUX_LSB_RELEASE: u32 = 0x4767_0005;
+pub const LINUX_CMD_LINE: u32 = 0x4767_0006;
+pub const LINUX_ENVIRON: u32 = 0x4767_0007;
+pub const LINUX_AUXV: u32 = 0x4767_0008;
+pub const LINUX_MAPS: u32 = 0x4767_0009;
+
+pub const PROCESSOR_ARCHITECTURE_INTEL: u16 = 0;
+pub const PROCESSOR_ARCHITECTURE_ARM: u16 = 5;
+pub const PROCESSOR_ARCHITECTURE_AMD64: u16 = 9;
+pub const PROCESSOR_ARCHITECTURE_ARM64: u16 = 12;
+pub const PROCESSOR_ARCHITECTURE_ARM64_OLD: u16 = 0x8003;
+pub const MEM_COMMIT: u32 = 0x1000;
+pub const MEM_MAPPED: u32 = 0x40000;
+pub const MEM_IMAGE: u32 = 0x100_0000;
+pub const PLATFORM_WIN32_NT: u32 = 2;
+pub const PLATFORM_MAC_OS_X: u32 = 0x8101;
+pub const PLATFORM_IOS: u32 = 0x8102;
+pub const PLATFORM_LINUX: u32 = 0x8201;
+/// `RSDS`, the CodeView record of a PDB: its GUID, age and path. Breakpad writes Mach-O UUIDs in
+/// it too.
+pub const CV_SIGNATURE_PDB70: u32 = 0x5344_5352;
+/// `BpEL`, Breakpad's CodeView record of an ELF build ID.
+pub const CV_SIGNATURE_ELF: u32 = 0x4c45_7042;
+pub const VS_FFI_SIGNATURE: u32 = 0xfeef_04bd;
+/// `CONTEXT_AMD64` with the control, integer and floating point registers.
+pub const CONTEXT_AMD64_FULL: u32 = 0x0010_000b;
+
+#[derive(Clone, Copy, Default)]
+pub struct Location {
+    pub size: u32,
+    pub rva: u32,
+}
+
+/// Builds a minidump in memory, the streams first and their directory last.
+pub struct Writer {
+    buf: Vec<u8>,
+    directory: Vec<(u32, Location)>,
+}
+
+impl Writer {
+    pub fn new() -> Self {
+        Self {
+            buf: vec![0; 32],
+            directory: vec![],
+        }
+    }
+
+    pub fn write(&mut self, data: &[u8]) -> Location {
+        self.buf.resize(self.buf.len().next_multiple_of(8), 0);
+        let rva = self.buf.len() as u32;
+        self.buf.extend_from_slice(data);
+        Location {
+            size: data.len() as u32,
+            rva,
+        }
+    }
+
+    /// A `MINIDUMP_STRING`: its byte length, then the UTF-16 units and a terminator.
+    pub fn string(&mut self, s: &str) -> u32 {
+        let units: Vec<u16> = s.encode_utf16().collect();
+        let mut data = (units.len() as u32 * 2).to_le_bytes().to_vec();
+        data.extend(units.iter().chain(&[0]).flat_map(|u| u.to_le_bytes()));
+        self.write(&data).rva
+    }
+
+    pub fn stream(&mut self, kind: u32, data: &[u8]) {
+        let location = self.write(data);
+        self.directory.push((kind, location));
+    }
+
+    pub fn finish(mut self, time: u32) -> Vec<u8> {
+        let directory: Vec<u8> = self
+            .directory
+            .iter()
+            .flat_map(|(kind, location)| {
+                [*kind, location.size, location.rva]
+                    .into_iter()
+                    .flat_map(u32::to_le_bytes)
+            })
+            .collect();
+        let directory = self.write(&directory);
+
+        let mut header = Bytes(vec![0; 32]);
+        header.u32(0, SIGNATURE);
+        header.u32(4, VERSION);
+        header.u32(8, self.directory.len() as u32);
+        header.u32(12, directory.rva);
+        header.u32(20, time);
+        self.buf[..32].copy_from_slice(&header.0);
+        self.buf
+    }
+}
+
+/// A minidump held in memory.
+pub struct Minidump<'a> {
+    data: &'a [u8],
+    directory: Vec<(u32, Location)>,
+}
+
+impl<'a> Minidump<'a> {
+    pub fn parse(data: &'a [u8]) -> Option<Self> {
+        let header = Record(data);
+        if header.u32(0)? != SIGNATURE {
+            return None;
+        }
+        let (count, rva) = (header.u32(8)? as usize, header.u32(12)? as usize);
+        let directory = (0..count)
+            .map(|i| {
+                let entry = Record(data.get(rva + i * 12..)?);
+                Some((entry.u32(0)?, entry.location(4)?))
+            })
+            .collect::<Option<_>>()?;
+        Some(Self { data, directory })
+    }
+
+    /// When the dump was written, in seconds since the epoch.
+    pub fn time(&self) -> u32 {
+        Record(self.data).u32(20).unwrap_or_default()
+    }
+
+    pub fn get(&self, location: Location) -> Option<&'a [u8]> {
+        let start = location.rva as usize;
+        self.data.get(start..start + location.size as usize)
+    }
+
+    pub fn stream(&self, kind: u32) -> Option<&'a [u8]> {
+        let &(_, location) = self.directory.iter().find(|(k, _)| *k == kind)?;
+        self.get(location)
+    }
+
+    /// Records of a list stream, a count followed by records of `size` bytes.
+    fn list(&self, kind: u32, size: usize) -> Vec<&'a [u8]> {
+        let Some(stream) = self.stream(kind) else {
+            return vec![];
+        };
+        let count = Record(stream).u32(0).unwrap_or_default() as usize;
+        (0..count)
+            .map_while(|i| stream.get(4 + i * size..4 + (i + 1) * size))
+            .collect()
+    }
+
+    pub fn threads(&self) -> Vec<Thread> {
+        self.list(THREAD_LIST_STREAM, Thread::SIZE)
+            .into_iter()
+            .filter_map(Thread::from_bytes)
+            .collect()
+    }
+
+    pub fn modules(&self) -> Vec<Module> {
+        self.list(MODULE_LIST_STREAM, Module::SIZE)
+            .into_iter()
+            .filter_map(Module::from_bytes)
+            .collect()
+    }
+
+    /// Captured memory, from the memory list or, in full dumps, the 64-bit memory list.
+    pub fn memory(&self) -> Vec<(u64, &'a [u8])> {
+        let mut memory: Vec<(u64, &[u8])> = self
+            .list(MEMORY_LIST_STREAM, MemoryDescriptor::SIZE)
+            .into_iter()
+            .filter_map(MemoryDescriptor::from_bytes)
+            .filter_map(|m| Some((m.start, self.get(m.memory)?)))
+            .collect();
+        if let Some(stream) = self.stream(MEMORY_64_LIST_STREAM) {
+            let stream = Record(stream);
+            let (count, mut rva) = (
+                stream.u64(0).unwrap_or_default(),
+                stream.u64(8).unwrap_or_default() as usize,
+            );
+            for i in 0..count as usize {
+                let (Some(start), Some(size)) = (stream.u64(16 + i * 16), stream.u64(24 + i * 16))
+                else {
+                    break;
+                };
+                let Some(data) = self.data.get(rva..rva + size as usize) else {
+                    break;
+                };
+                memory.push((start, data));
+                rva += size as usize;
+            }
+        }
+        memory
+    }
+
+    /// The regions of the address space, from the memory info list of Windows dumps.
+    pub fn memory_info(&self) -> Vec<MemoryInfo> {
+        let Some(stream) = self.stream(MEMORY_INFO_LIST_STREAM) else {
+            return vec![];
+        };
+        let r = Record(stream);
+        let (Some(header), Some(entry), Some(count)) = (r.u32(0), r.u32(4), r.u64(8)) else {
+            return vec![];
+        };
+        let (header, entry) = (header as usize, (entry as usize).max(MemoryInfo::SIZE));
+        (0..count as usize)
+            .map_while(|i| stream.get(header + i * entry..header + (i + 1) * entry))
+            .filter_map(MemoryInfo::from_bytes)
+            .collect()
+    }
+
+    pub fn exception(&self) -> Option<Exception> {
+        Exception::from_bytes(self.stream(EXCEPTION_STREAM)?)
+    }
+
+    pub fn system_info(&self) -> Option<SystemInfo> {
+        SystemInfo::from_bytes(self.stream(SYSTEM_INFO_STREAM)?)
+    }
+
+    pub fn context(&self, location: Location) -> Option<Context> {
+        let data = self.get(location)?;
+        match self.system_info()?.architecture {
+            PROCESSOR_ARCHITECTURE_INTEL => ContextX86::from_bytes(data).map(Context::X86),
+            PROCESSOR_ARCHITECTURE_AMD64 => ContextAmd64::from_bytes(data).map(Context::Amd64),
+            PROCESSOR_ARCHITECTURE_ARM64 | PROCESSOR_ARCHITECTURE_ARM64_OLD => {
+                ContextArm64::from_bytes(data).map(Context::Arm64)
+            }
+            _ => None,
+        }
+    }
+
+    /// The code and debug identifiers of a module, from its CodeView record.
+    pub fn module_ids(&self, module: &Module) -> (Option<String>, Option<String>) {
+        let Some(cv) = self.get(module.cv_record) else {
+            return (None, None);
+        };
+        let r = Record(cv);
+        match r.u32(0) {
+            Some(CV_SIGNATURE_ELF) => {
+                let (code_id, debug_id) = elf_ids(&cv[4..]);
+                (Some(code_id), Some(debug_id))
+            }
+            Some(CV_SIGNATURE_PDB70) if cv.len() >= 24 => {
+                let debug_id = debug_id(&cv[4..20], r.u32(20).unwrap());
+                let code_id = match self.system_info().map(|info| info.platform) {
+                    // What the PE header holds, as symbol servers index executables.
+                    Some(PLATFORM_WIN32_NT) => {
+                        format!("{:08X}{:x}", module.time_date_stamp, module.size)
+                    }
+                    _ => debug_id[..32].to_owned(),
+                };
+                (Some(code_id), Some(debug_id))
+            }
+            _ => (None, None),
+        }
+    }
+
+    /// The file name of the PDB of a module, from its CodeView record.
+    pub fn debug_file(&self, module: &Module) -> Option<String> {
+        let cv = self.get(module.cv_record)?;
+        if Record(cv).u32(0)? != CV_SIGNATURE_PDB70 {
+            return None;
+        }
+        let path = cv.get(24..)?;
+        let path = &path[..path.iter().position(|&c| c == 0).unwrap_or(path.len())];
+        let path = String::from_utf8_lossy(path);
+        let name = path.rsplit(['\\', '/']).next()?;
+        (!name.is_empty()).then(|| name.to_owned())
+    }
+
+    pub fn string(&self, rva: u32) -> Option<String> {
+        let rva = rva as usize;
+        let size = Record(self.data.get(rva..)?).u32(0)? as usize;
+        let units: Vec<u16> = self
+            .data
+            .get(rva + 4..rva + 4 + size)?
+            .chunks_exact(2)
+            .map(|c| u16::from_le_bytes([c[0], c[1]]))
+            .collect();
+        Some(String::from_utf16_lossy(&units))
+    }
+}
+
+/// Append `memory` to the memory list of a dump, for writers that can't be handed memory to keep.
+#[cfg(any(target_os = "macos", test))]
+pub fn add_memory(data: &mut Vec<u8>, memory: &[(u64, Vec<u8>)]) -> Option<()> {
+    let (entry, mut list) = {
+        let dump = Minidump::parse(data)?;
+        let index = dump
+            .directory
+            .iter()
+            .position(|(kind, _)| *kind == MEMORY_LIST_STREAM)?;
+        let list: Vec<MemoryDescriptor> = dump
+            .list(MEMORY_LIST_STREAM, MemoryDescriptor::SIZE)
+            .into_iter()
+            .filter_map(MemoryDescriptor::from_bytes)
+            .collect();
+        (Record(data).u32(12)? as usize + index * 12, list)
+    };
+    for (start, bytes) in memory {
+        data.resize(data.len().next_multiple_of(8), 0);
+        let rva = data.len() as u32;
+        data.extend_from_slice(bytes);
+        list.push(MemoryDescriptor {
+            start: *start,
+            memory: Location {
+                size: bytes.len() as u32,
+                rva,
+            },
+        });
+    }
+    // The new list goes at the end and the directory entry points to it, the old one stays unused.
+    data.resize(data.len().next_multiple_of(8), 0);
+    let mut stream = (list.len() as u32).to_le_bytes().to_vec();
+    stream.extend(list.into_iter().flat_map(MemoryDescriptor::to_bytes));
+    let mut location = Bytes(vec![0; 8]);
+    location.location(
+        0,
+        Location {
+            size: stream.len() as u32,
+            rva: data.len() as u32,
+        },
+    );
+    data.extend_from_slice(&stream);
+    data.get_mut(entry + 4..entry + 12)?
+        .copy_from_slice(&location.0);
+    Some(())
+}
+
+/// A Breakpad debug identifier, a GUID whose first three fields are little-endian followed by an
+/// age.
+pub fn debug_id(guid: &[u8], age: u32) -> String {
+    let r = Record(guid);
+    let mut id = format!(
+        "{:08X}{:04X}{:04X}",
+        r.u32(0).unwrap_or_default(),
+        r.u16(4).unwrap_or_default(),
+        r.u16(6).unwrap_or_default()
+    );
+    for b in guid.iter().skip(8).take(8) {
+        id += &format!("{b:02X}");
+    }
+    id + &format!("{age:X}")
+}
+
+/// The code and debug identifiers of an ELF image: its build ID, and the first 16 bytes of it as
+/// a GUID.
+pub fn elf_ids(build_id: &[u8]) -> (String, String) {
+    let code_id = build_id.iter().map(|b| format!("{b:02x}")).collect();
+    let mut guid = [0; 16];
+    let len = build_id.len().min(16);
+    guid[..len].copy_from_slice(&build_id[..len]);
+    (code_id, debug_id(&guid, 0))
+}
+
+pub fn arch(architecture: u16) -> Option<&'static str> {
+    match architecture {
+        PROCESSOR_ARCHITECTURE_INTEL => Some("x86"),
+        PROCESSOR_ARCHITECTURE_ARM => Some("arm"),
+        PROCESSOR_ARCHITECTURE_AMD64 => Some("x86_64"),
+        PROCESSOR_ARCHITECTURE_ARM64 | PROCESSOR_ARCHITECTURE_ARM64_OLD => Some("arm64"),
+        _ => None,
+    }
+}
+
+/// A fixed size record, read at offsets.
+struct Record<'a>(&'a [u8]);
+
+impl Record<'_> {
+    fn get<const N: usize>(&self, offset: usize) -> Option<[u8; N]> {
+        self.0.get(offset..offset + N)?.try_into().ok()
+    }
+
+    fn u16(&self, offset: usize) -> Option<u16> {
+        self.get(offset).map(u16::from_le_bytes)
+    }
+
+    fn u32(&self, offset: usize) -> Option<u32> {
+        self.get(offset).map(u32::from_le_bytes)
+    }
+
+    fn u64(&self, offset: usize) -> Option<u64> {
+        self.get(offset).map(u64::from_le_bytes)
+    }
+
+    fn location(&self, offset: usize) -> Option<Location> {
+        Some(Location {
+            size: self.u32(offset)?,
+            rva: self.u32(offset + 4)?,
+        })
+    }
+}
+
+/// A fixed size record, filled in at offsets.
+struct Bytes(Vec<u8>);
+
+impl Bytes {
+    fn set(&mut self, offset: usize, data: &[u8]) {
+        self.0[offset..offset + data.len()].copy_from_slice(data);
+    }
+
+    fn u16(&mut self, offset: usize, v: u16) {
+        self.set(offset, &v.to_le_bytes());
+    }
+
+    fn u32(&mut self, offset: usize, v: u32) {
+        self.set(offset, &v.to_le_bytes());
+    }
+
+    fn u64(&mut self, offset: usize, v: u64) {
+        self.set(offset, &v.to_le_bytes());
+    }
+
+    fn location(&mut self, offset: usize, location: Location) {
+        self.u32(offset, location.size);
+        self.u32(offset + 4, location.rva);
+    }
+}
+
+/// `MINIDUMP_MEMORY_DESCRIPTOR`
+#[derive(Clone, Copy, Default)]
+pub struct MemoryDescriptor {
+    pub start: u64,
+    pub memory: Location,
+}
+
+impl MemoryDescriptor {
+    pub const SIZE: usize = 16;
+
+    pub fn to_bytes(self) -> Vec<u8> {
+        let mut b = Bytes(vec![0; Self::SIZE]);
+        b.u64(0, self.start);
+        b.location(8, self.memory);
+        b.0
+    }
+
+    pub fn from_bytes(data: &[u8]) -> Option<Self> {
+        let r = Record(data);
+        Some(Self {
+            start: r.u64(0)?,
+            memory: r.location(8)?,
+        })
+    }
+}
+
+/// `MINIDUMP_MEMORY_INFO`
+pub struct MemoryInfo {
+    pub base: u64,
+    pub size: u64,
+    pub state: u32,
+    pub protect: u32,
+    pub kind: u32,
+}
+
+impl MemoryInfo {
+    pub const SIZE: usize = 48;
+
+    pub fn from_bytes(data: &[u8]) -> Option<Self> {
+        let r = Record(data);
+        Some(Self {
+            base: r.u64(0)?,
+            size: r.u64(24)?,
+            state: r.u32(32)?,
+            protect: r.u32(36)?,
+            kind: r.u32(40)?,
+        })
+    }
+}
+
+/// `MINIDUMP_THREAD`
+pub struct Thread {
+    pub id: u32,
+    pub stack: MemoryDescriptor,
+    pub context: Location,
+}
+
+impl Thread {
+    pub const SIZE: usize = 48;
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut b = Bytes(vec![0; Self::SIZE]);
+        b.u32(0, self.id);
+        b.set(24, &self.stack.to_bytes());
+        b.location(40, self.context);
+        b.0
+    }
+
+    pub fn from_bytes(data: &[u8]) -> Option<Self> {
+        let r = Record(data);
+        Some(Self {
+            id: r.u32(0)?,
+            stack: MemoryDescriptor::from_bytes(data.get(24..)?)?,
+            context: r.location(40)?,
+        })
+    }
+}
+
+/// `MINIDUMP_MODULE`
+pub struct Module {
+    pub base: u64,
+    pub size: u32,
+    pub time_date_stamp: u32,
+    pub name_rva: u32,
+    /// `dwFileVersionMS` and `dwFileVersionLS` of the `VS_FIXEDFILEINFO`, when it's there.
+    pub file_version: Option<(u32, u32)>,
+    pub cv_record: Location,
+}
+
+impl Module {
+    pub const SIZE: usize = 108;
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut b = Bytes(vec![0; Self::SIZE]);
+        b.u64(0, self.base);
+        b.u32(8, self.size);
+        b.u32(16, self.time_date_stamp);
+        b.u32(20, self.name_rva);
+        if let Some((ms, ls)) = self.file_version {
+            b.u32(24, VS_FFI_SIGNATURE);
+            b.u32(32, ms);
+            b.u32(36, ls);
+        }
+        b.location(76, self.cv_record);
+        b.0
+    }
+
+    pub fn from_bytes(data: &[u8]) -> Option<Self> {
+        let r = Record(data);
+        Some(Self {
+            base: r.u64(0)?,
+            size: r.u32(8)?,
+            time_date_stamp: r.u32(16)?,
+            name_rva: r.u32(20)?,
+            file_version: (r.u32(24)? == VS_FFI_SIGNATURE).then_some((r.u32(32)?, r.u32(36)?)),
+            cv_record: r.location(76)?,
+        })
+    }
+
+    /// The file version as `major.minor.build.revision`.
+    pub fn version(&self) -> Option<String> {
+        let (ms, ls) = self.file_version.filter(|&v| v != (0, 0))?;
+        Some(format!(
+            "{}.{}.{}.{}",
+            ms >> 16,
+            ms & 0xffff,
+            ls >> 16,
+            ls & 0xffff
+        ))
+    }
+}
+
+/// `MINIDUMP_EXCEPTION_STREAM`, for a signal on Linux: the number as the code, `si_code` as the
+/// flags and `si_addr` as the address.
+pub struct Exception {
+    pub thread_id: u32,
+    pub code: u32,
+    pub flags: u32,
+    pub address: u64,
+    /// `ExceptionInformation`, at most 15.
+    pub parameters: Vec<u64>,
+    pub context: Location,
+}
+
+impl Exception {
+    pub const SIZE: usize = 168;
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut b = Bytes(vec![0; Self::SIZE]);
+        b.u32(0, self.thread_id);
+        b.u32(8, self.code);
+        b.u32(12, self.flags);
+        b.u64(24, self.address);
+        b.u32(32, self.parameters.len().min(15) as u32);
+        for (i, parameter) in self.parameters.iter().take(15).enumerate() {
+            b.u64(40 + i * 8, *parameter);
+        }
+        b.location(160, self.context);
+        b.0
+    }
+
+    pub fn from_bytes(data: &[u8]) -> Option<Self> {
+        let r = Record(data);
+        Some(Self {
+            thread_id: r.u32(0)?,
+            code: r.u32(8)?,
+            flags: r.u32(12)?,
+            address: r.u64(24)?,
+            parameters: (0..r.u32(32)?.min(15) as usize)
+                .map(|i| r.u64(40 + i * 8))
+                .collect::<Option<_>>()?,
+            context: r.location(160)?,
+        })
+    }
+}
+
+/// `MINIDUMP_SYSTEM_INFO`
+pub struct SystemInfo {
+    pub architecture: u16,
+    pub cpus: u8,
+    pub platform: u32,
+    pub csd_version_rva: u32,
+}
+
+impl SystemInfo {
+    pub const SIZE: usize = 56;
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut b = Bytes(vec![0; Self::SIZE]);
+        b.u16(0, self.architecture);
+        b.set(6, &[self.cpus]);
+        b.u32(20, self.platform);
+        b.u32(24, self.csd_version_rva);
+        b.0
+    }
+
+    pub fn from_bytes(data: &[u8]) -> Option<Self> {
+        let r = Record(data);
+        Some(Self {
+            architecture: r.u16(0)?,
+            cpus: *data.get(6)?,
+            platform: r.u32(20)?,
+            csd_version_rva: r.u32(24)?,
+        })
+    }
+}
+
+/// `CONTEXT_AMD64`, the general purpose registers are in the order of the instruction encoding.
+#[derive(Clone)]
+pub struct ContextAmd64 {
+    pub gpr: [u64; 16],
+    pub rip: u64,
+    pub eflags: u32,
+    /// `cs`, `ds`, `es`, `fs`, `gs` and `ss`.
+    pub segments: [u16; 6],
+    /// The legacy `FXSAVE` area, holding the x87 and SSE state.
+    pub fxsave: [u8; 512],
+}
+
+impl ContextAmd64 {
+    pub const SIZE: usize = 1232;
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut b = Bytes(vec![0; Self::SIZE]);
+        b.u32(48, CONTEXT_AMD64_FULL);
+        // MXCSR is also at offset 24 of the FXSAVE area.
+        b.set(52, &self.fxsave[24..28]);
+        for (i, &segment) in self.segments.iter().enumerate() {
+            b.u16(56 + i * 2, segment);
+        }
+        b.u32(68, self.eflags);
+        for (i, &reg) in self.gpr.iter().enumerate() {
+            b.u64(120 + i * 8, reg);
+        }
+        b.u64(248, self.rip);
+        b.set(256, &self.fxsave);
+        b.0
+    }
+
+    pub fn from_bytes(data: &[u8]) -> Option<Self> {
+        let r = Record(data);
+        let mut gpr = [0; 16];
+        for (i, reg) in gpr.iter_mut().enumerate() {
+            *reg = r.u64(120 + i * 8)?;
+        }
+        let mut segments = [0; 6];
+        for (i, segment) in segments.iter_mut().enumerate() {
+            *segment = r.u16(56 + i * 2)?;
+        }
+        Some(Self {
+            gpr,
+            rip: r.u64(248)?,
+            eflags: r.u32(68)?,
+            segments,
+            fxsave: r.get(256)?,
+        })
+    }
+}
+
+/// `CONTEXT_X86`, the general purpose registers are in the order of the instruction encoding.
+#[derive(Clone)]
+pub struct ContextX86 {
+    pub gpr: [u32; 8],
+    pub eip: u32,
+    pub eflags: u32,
+    /// `cs`, `ds`, `es`, `fs`, `gs` and `ss`.
+    pub segments: [u16; 6],
+    /// The extended registers, in the layout of the legacy `FXSAVE` area.
+    pub fxsave: [u8; 512],
+}
+
+impl ContextX86 {
+    pub fn from_bytes(data: &[u8]) -> Option<Self> {
+        let r = Record(data);
+        // `eax`, `ecx`, `edx`, `ebx`, `esp`, `ebp`, `esi` and `edi`.
+        let offsets = [176, 172, 168, 164, 196, 180, 160, 156];
+        let mut gpr = [0; 8];
+        for (reg, offset) in gpr.iter_mut().zip(offsets) {
+            *reg = r.u32(offset)?;
+        }
+        let mut segments = [0; 6];
+        for (segment, offset) in segments.iter_mut().zip([188, 152, 148, 144, 140, 200]) {
+            *segment = r.u16(offset)?;
+        }
+        Some(Self {
+            gpr,
+            eip: r.u32(184)?,
+            eflags: r.u32(192)?,
+            segments,
+            fxsave: r.get(204)?,
+        })
+    }
+}
+
+pub enum Context {
+    X86(ContextX86),
+    Amd64(ContextAmd64),
+    Arm64(ContextArm64),
+}
+
+const X86_GPR_NAMES: [&str; 8] = ["eax", "ecx", "edx", "ebx", "esp", "ebp", "esi", "edi"];
+
+const AMD64_GPR_NAMES: [&str; 16] = [
+    "rax", "rcx", "rdx", "rbx", "rsp", "rbp", "rsi", "rdi", "r8", "r9", "r10", "r11", "r12", "r13",
+    "r14", "r15",
+];
+
+impl Context {
+    pub fn pc(&self) -> u64 {
+        match self {
+            Context::X86(c) => c.eip as u64,
+            Context::Amd64(c) => c.rip,
+            Context::Arm64(c) => c.pc,
+        }
+    }
+
+    pub fn sp(&self) -> u64 {
+        match self {
+            Context::X86(c) => c.gpr[4] as u64,
+            Context::Amd64(c) => c.gpr[4],
+            Context::Arm64(c) => c.sp,
+        }
+    }
+
+    pub fn fp(&self) -> u64 {
+        match self {
+            Context::X86(c) => c.gpr[5] as u64,
+            Context::Amd64(c) => c.gpr[5],
+            Context::Arm64(c) => c.x[29],
+        }
+    }
+
+    /// The size of pointers, in bytes.
+    pub fn pointer_size(&self) -> u64 {
+        match self {
+            Context::X86(_) => 4,
+            Context::Amd64(_) | Context::Arm64(_) => 8,
+        }
+    }
+
+    /// The general purpose registers, by name.
+    pub fn gpr(&self) -> Vec<(String, u64)> {
+        match self {
+            Context::X86(c) => X86_GPR_NAMES
+                .iter()
+                .map(|name| name.to_string())
+                .zip(c.gpr.map(u64::from))
+                .collect(),
+            Context::Amd64(c) => AMD64_GPR_NAMES
+                .iter()
+                .map(|name| name.to_string())
+                .zip(c.gpr)
+                .collect(),
+            Context::Arm64(c) => (0..31).map(|i| format!("x{i}")).zip(c.x).collect(),
+        }
+    }
+}
+
+/// `CONTEXT_ARM64`
+#[derive(Clone)]
+pub struct ContextArm64 {
+    /// `x0` to `x28`, then `fp` and `lr`.
+    pub x: [u64; 31],
+    pub sp: u64,
+    pub pc: u64,
+    pub cpsr: u32,
+    pub v: [u128; 32],
+    pub fpsr: u32,
+    pub fpcr: u32,
+}
+
+impl ContextArm64 {
+    pub fn from_bytes(data: &[u8]) -> Option<Self> {
+        let r = Record(data);
+        let mut x = [0; 31];
+        for (i, reg) in x.iter_mut().enumerate() {
+            *reg = r.u64(8 + i * 8)?;
+        }
+        let mut v = [0; 32];
+        for (i, reg) in v.iter_mut().enumerate() {
+            *reg = r.get(272 + i * 16).map(u128::from_le_bytes)?;
+        }
+        Some(Self {
+            x,
+            sp: r.u64(256)?,
+            pc: r.u64(264)?,
+            cpsr: r.u32(4)?,
+            v,
+            fpcr: r.u32(784)?,
+            fpsr: r.u32(788)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn added_memory() {
+        let mut writer = Writer::new();
+        let stack = writer.write(&[1; 16]);
+        let descriptor = MemoryDescriptor {
+            start: 0x1000,
+            memory: stack,
+        };
+        let mut list = 1u32.to_le_bytes().to_vec();
+        list.extend(descriptor.to_bytes());
+        writer.stream(MEMORY_LIST_STREAM, &list);
+        let mut data = writer.finish(0);
+
+        add_memory(&mut data, &[(0x2000, b"message".to_vec())]).unwrap();
+        let dump = Minidump::parse(&data).unwrap();
+        assert_eq!(
+            dump.memory(),
+            [(0x1000, &[1; 16][..]), (0x2000, &b"message"[..])]
+        );
+
+        let mut empty = Writer::new().finish(0);
+        assert!(add_memory(&mut empty, &[(0x2000, vec![0])]).is_none());
+    }
+}
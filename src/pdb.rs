@@ -0,0 +1,768 @@
+use crate::{dwarf::Line, elf, minidump, pe::IMAGE_SECTION_HEADER};
+use std::{collections::HashMap, mem};
+
+pub const MAGIC: &[u8; 32] = b"Microsoft C/C++ MSF 7.00\r\n\x1aDS\0\0\0";
+const NIL_STREAM: u32 = 0xffff_ffff;
+const PDB_STREAM: usize = 1;
+const TPI_STREAM: usize = 2;
+const DBI_STREAM: usize = 3;
+const IPI_STREAM: usize = 4;
+
+// Indices of the streams of the optional debug header of the DBI stream.
+const DBG_FPO: usize = 0;
+const DBG_SECTION_HDR: usize = 5;
+const DBG_NEW_FPO: usize = 9;
+
+const S_END: u16 = 0x0006;
+const S_THUNK32: u16 = 0x1102;
+const S_BLOCK32: u16 = 0x1103;
+const S_WITH32: u16 = 0x1104;
+const S_PUB32: u16 = 0x110e;
+const S_LPROC32: u16 = 0x110f;
+const S_GPROC32: u16 = 0x1110;
+const S_GMANPROC: u16 = 0x112a;
+const S_LMANPROC: u16 = 0x112b;
+const S_SEPCODE: u16 = 0x1132;
+const S_LPROC32_ID: u16 = 0x1146;
+const S_GPROC32_ID: u16 = 0x1147;
+const S_INLINESITE: u16 = 0x114d;
+const S_INLINESITE_END: u16 = 0x114e;
+const S_PROC_ID_END: u16 = 0x114f;
+const S_LPROC32_DPC: u16 = 0x1155;
+const S_LPROC32_DPC_ID: u16 = 0x1156;
+const S_INLINESITE2: u16 = 0x115d;
+
+const CVPSF_CODE: u32 = 0x1;
+const CVPSF_FUNCTION: u32 = 0x2;
+
+const DEBUG_S_IGNORE: u32 = 0x8000_0000;
+const DEBUG_S_LINES: u32 = 0xf2;
+const DEBUG_S_FILECHKSMS: u32 = 0xf4;
+const DEBUG_S_INLINEELINES: u32 = 0xf6;
+const CV_INLINEE_SOURCE_LINE_SIGNATURE_EX: u32 = 1;
+
+const LF_CLASS: u16 = 0x1504;
+const LF_STRUCTURE: u16 = 0x1505;
+const LF_UNION: u16 = 0x1506;
+const LF_INTERFACE: u16 = 0x1519;
+const LF_FUNC_ID: u16 = 0x1601;
+const LF_MFUNC_ID: u16 = 0x1602;
+const LF_STRING_ID: u16 = 0x1605;
+
+/// Lines hidden from debuggers, such as those of compiler-generated code.
+const HIDDEN_LINES: [u32; 2] = [0xfeefee, 0xf00f00];
+
+/// A PDB, its streams in the blocks of a multi-stream file.
+pub struct Pdb<'a> {
+    data: &'a [u8],
+    block_size: usize,
+    /// The size and blocks of each stream.
+    streams: Vec<(u32, Vec<u32>)>,
+    guid: [u8; 16],
+    age: u32,
+    pub machine: u16,
+    /// The stream holding the public and global symbols.
+    sym_records: u16,
+    modules: Vec<ModuleInfo>,
+    debug_streams: Vec<u16>,
+    /// The `/names` string table, which C13 file checksums and frame data name their strings in.
+    names: Vec<u8>,
+}
+
+struct ModuleInfo {
+    stream: u16,
+    sym_size: usize,
+    c13_size: usize,
+}
+
+/// A function of the symbols of a module, at relative virtual addresses, with the functions
+/// inlined into it.
+pub struct Procedure {
+    pub address: u64,
+    pub size: u64,
+    pub name: String,
+    pub lines: Vec<Line>,
+    pub inlines: Vec<InlineSite>,
+}
+
+/// The code of a function inlined into a procedure, its sites listed in the order they nest.
+pub struct InlineSite {
+    /// 0 for functions inlined directly into the procedure.
+    pub depth: u32,
+    pub name: String,
+    pub lines: Vec<Line>,
+}
+
+/// The unwind rules of an x86 function, from its FPO or frame data record, as a Breakpad
+/// `STACK WIN` record.
+#[derive(Clone)]
+pub struct FrameData {
+    /// 0 for FPO records, 4 for frame data.
+    pub kind: u8,
+    pub address: u64,
+    pub size: u32,
+    pub prolog: u32,
+    pub epilog: u32,
+    pub params: u32,
+    pub saved_regs: u32,
+    pub locals: u32,
+    pub max_stack: u32,
+    /// The postfix program computing the caller's registers, for frame data.
+    pub program: Option<String>,
+    /// Whether an FPO function uses `ebp` as a general purpose register.
+    pub allocates_base_pointer: bool,
+}
+
+impl<'a> Pdb<'a> {
+    pub fn parse(data: &'a [u8]) -> Option<Self> {
+        if data.get(..MAGIC.len())? != MAGIC {
+            return None;
+        }
+        let block_size = elf::read::<u32>(data, 32)? as usize;
+        let directory_size = elf::read::<u32>(data, 44)? as usize;
+        let block_map = elf::read::<u32>(data, 52)? as usize;
+        if block_size == 0 {
+            return None;
+        }
+        let mut pdb = Self {
+            data,
+            block_size,
+            streams: vec![],
+            guid: [0; 16],
+            age: 0,
+            machine: 0,
+            sym_records: 0,
+            modules: vec![],
+            debug_streams: vec![],
+            names: vec![],
+        };
+
+        let directory_blocks = (0..directory_size.div_ceil(block_size))
+            .map(|i| elf::read(data, block_map.checked_mul(block_size)? + i * 4))
+            .collect::<Option<Vec<u32>>>()?;
+        let directory = pdb.read(directory_size as u32, &directory_blocks)?;
+        let count = elf::read::<u32>(&directory, 0)? as usize;
+        let mut offset = 4 + count * 4;
+        for i in 0..count {
+            let size = elf::read::<u32>(&directory, 4 + i * 4)?;
+            let blocks = match size {
+                NIL_STREAM => 0,
+                size => (size as usize).div_ceil(block_size),
+            };
+            let list = (0..blocks)
+                .map(|j| elf::read(&directory, offset + j * 4))
+                .collect::<Option<_>>()?;
+            offset += blocks * 4;
+            pdb.streams.push((size, list));
+        }
+
+        let info = pdb.stream(PDB_STREAM)?;
+        pdb.age = elf::read(&info, 8)?;
+        pdb.guid = elf::read(&info, 12)?;
+        if let Some(names) = named_streams(&info).get("/names") {
+            let names = pdb.stream(*names as usize).unwrap_or_default();
+            let size = elf::read::<u32>(&names, 8).unwrap_or_default() as usize;
+            pdb.names = names.get(12..12 + size).unwrap_or_default().to_vec();
+        }
+
+        // Optional: a PDB without one has no symbols but the identity of its image.
+        if let Some(dbi) = pdb.stream(DBI_STREAM) {
+            pdb.dbi(&dbi);
+        }
+        Some(pdb)
+    }
+
+    /// Read the header and the module and optional debug header substreams of the DBI stream.
+    fn dbi(&mut self, dbi: &[u8]) -> Option<()> {
+        self.age = elf::read(dbi, 8)?;
+        self.sym_records = elf::read(dbi, 20)?;
+        self.machine = elf::read(dbi, 58)?;
+        let size = |offset| elf::read::<i32>(dbi, offset).map(|size| size.max(0) as usize);
+        let modules = dbi.get(64..64 + size(24)?)?;
+        // Section contributions, section map, file info, type server map and EC substreams.
+        let skipped: usize = [28, 32, 36, 40, 52]
+            .into_iter()
+            .map(size)
+            .sum::<Option<_>>()?;
+        let debug = dbi.get(64 + size(24)? + skipped..)?.get(..size(48)?)?;
+        self.debug_streams = (0..debug.len() / 2)
+            .map(|i| elf::read(debug, i * 2).unwrap_or(u16::MAX))
+            .collect();
+
+        let mut offset = 0;
+        while offset + 64 <= modules.len() {
+            self.modules.push(ModuleInfo {
+                stream: elf::read(modules, offset + 34)?,
+                sym_size: elf::read::<u32>(modules, offset + 36)? as usize,
+                c13_size: elf::read::<u32>(modules, offset + 44)? as usize,
+            });
+            // The module and object file names follow, then padding to 4 bytes.
+            offset += 64;
+            for _ in 0..2 {
+                offset += modules[offset..].iter().position(|&c| c == 0)? + 1;
+            }
+            offset = offset.next_multiple_of(4);
+        }
+        Some(())
+    }
+
+    /// The data of the blocks of a stream of `size` bytes.
+    fn read(&self, size: u32, blocks: &[u32]) -> Option<Vec<u8>> {
+        let mut data = Vec::with_capacity(size as usize);
+        for &block in blocks {
+            let start = block as usize * self.block_size;
+            data.extend_from_slice(self.data.get(start..start + self.block_size)?);
+        }
+        data.truncate(size as usize);
+        Some(data)
+    }
+
+    fn stream(&self, i: usize) -> Option<Vec<u8>> {
+        let (size, blocks) = self.streams.get(i)?;
+        if *size == NIL_STREAM {
+            return None;
+        }
+        self.read(*size, blocks)
+    }
+
+    fn debug_stream(&self, i: usize) -> Option<Vec<u8>> {
+        self.stream(*self.debug_streams.get(i)? as usize)
+    }
+
+    /// The Breakpad debug identifier, which the CodeView record of the image holds too.
+    pub fn debug_id(&self) -> String {
+        minidump::debug_id(&self.guid, self.age)
+    }
+
+    fn sections(&self) -> Vec<IMAGE_SECTION_HEADER> {
+        let data = self.debug_stream(DBG_SECTION_HDR).unwrap_or_default();
+        let size = mem::size_of::<IMAGE_SECTION_HEADER>();
+        (0..data.len() / size)
+            .filter_map(|i| elf::read(&data, i * size))
+            .collect()
+    }
+
+    /// The public symbols of code, such as those of functions without debug info.
+    pub fn publics(&self) -> Vec<(u64, String)> {
+        let sections = self.sections();
+        let records = self.stream(self.sym_records as usize).unwrap_or_default();
+        records_of(&records)
+            .filter(|&(_, kind, _)| kind == S_PUB32)
+            .filter_map(|(_, _, body)| {
+                let flags: u32 = elf::read(body, 0)?;
+                if flags & (CVPSF_CODE | CVPSF_FUNCTION) == 0 {
+                    return None;
+                }
+                let address = rva(&sections, elf::read(body, 8)?, elf::read(body, 4)?)?;
+                Some((address, elf::c_str(body, 10)?.to_owned()))
+            })
+            .collect()
+    }
+
+    /// The procedures of all the modules, with their lines in the files of the returned list.
+    pub fn procedures(&self) -> (Vec<String>, Vec<Procedure>) {
+        let sections = self.sections();
+        let tpi = TypeStream::new(self.stream(TPI_STREAM));
+        let ipi = TypeStream::new(self.stream(IPI_STREAM));
+        let mut files = vec![];
+        let mut file_indices = HashMap::new();
+        let mut procedures = vec![];
+        for module in &self.modules {
+            let Some(stream) = self.stream(module.stream as usize) else {
+                continue;
+            };
+            let symbols = stream.get(4..module.sym_size).unwrap_or_default();
+            let c13 = stream
+                .get(module.sym_size..module.sym_size + module.c13_size)
+                .unwrap_or_default();
+
+            // Lines and inlinees name their file by the offset of its checksum.
+            let mut checksums = HashMap::new();
+            for (_, data) in subsections(c13).filter(|&(kind, _)| kind == DEBUG_S_FILECHKSMS) {
+                let mut offset = 0;
+                while let Some(name) = elf::read::<u32>(data, offset) {
+                    let Some(&size) = data.get(offset + 4) else {
+                        break;
+                    };
+                    let name = elf::c_str(&self.names, name as usize).unwrap_or("<unknown>");
+                    let index = *file_indices.entry(name.to_owned()).or_insert_with(|| {
+                        files.push(name.to_owned());
+                        files.len() - 1
+                    });
+                    checksums.insert(offset as u32, index);
+                    offset = (offset + 6 + size as usize).next_multiple_of(4);
+                }
+            }
+            let mut lines = vec![];
+            let mut inlinees = HashMap::new();
+            for (kind, data) in subsections(c13) {
+                match kind {
+                    DEBUG_S_LINES => lines.extend(line_block(data, &sections, &checksums)),
+                    DEBUG_S_INLINEELINES => {
+                        let extended =
+                            elf::read::<u32>(data, 0) == Some(CV_INLINEE_SOURCE_LINE_SIGNATURE_EX);
+                        let mut offset = 4;
+                        while let (Some(inlinee), Some(file), Some(line)) = (
+                            elf::read::<u32>(data, offset),
+                            elf::read::<u32>(data, offset + 4),
+                            elf::read::<u32>(data, offset + 8),
+                        ) {
+                            offset += 12;
+                            if extended {
+                                offset += 4 + elf::read::<u32>(data, offset).unwrap_or_default()
+                                    as usize
+                                    * 4;
+                            }
+                            if let Some(&file) = checksums.get(&file) {
+                                inlinees.insert(inlinee, (file, line));
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            lines.sort_by_key(|line| line.address);
+
+            // Blocks nest, closed by an end record each, and so do inline sites.
+            #[derive(PartialEq)]
+            enum Scope {
+                Procedure,
+                Inline,
+                Other,
+            }
+            let mut scopes = vec![];
+            for (_, kind, body) in records_of(symbols) {
+                match kind {
+                    S_GPROC32 | S_LPROC32 | S_GPROC32_ID | S_LPROC32_ID | S_LPROC32_DPC
+                    | S_LPROC32_DPC_ID => {
+                        let procedure = (|| {
+                            let address =
+                                rva(&sections, elf::read(body, 32)?, elf::read(body, 28)?)?;
+                            let size = elf::read::<u32>(body, 12)? as u64;
+                            let first = lines.partition_point(|line| line.address < address);
+                            Some(Procedure {
+                                address,
+                                size,
+                                name: elf::c_str(body, 35)?.to_owned(),
+                                lines: lines[first..]
+                                    .iter()
+                                    .take_while(|line| line.address < address + size)
+                                    .cloned()
+                                    .collect(),
+                                inlines: vec![],
+                            })
+                        })();
+                        scopes.push(match procedure {
+                            Some(procedure) => {
+                                procedures.push(procedure);
+                                Scope::Procedure
+                            }
+                            None => Scope::Other,
+                        });
+                    }
+                    S_INLINESITE | S_INLINESITE2 => {
+                        let depth = scopes.iter().filter(|&s| *s == Scope::Inline).count();
+                        let annotations = match kind {
+                            S_INLINESITE => 12,
+                            _ => 16,
+                        };
+                        let inlinee = elf::read::<u32>(body, 8);
+                        let procedure = procedures
+                            .last_mut()
+                            .filter(|_| scopes.contains(&Scope::Procedure));
+                        if let (Some(procedure), Some(inlinee)) = (procedure, inlinee) {
+                            let (file, line) = inlinees.get(&inlinee).copied().unwrap_or_default();
+                            procedure.inlines.push(InlineSite {
+                                depth: depth as u32,
+                                name: id_name(&ipi, &tpi, inlinee)
+                                    .unwrap_or_else(|| "<unknown>".to_owned()),
+                                lines: inline_lines(
+                                    body.get(annotations..).unwrap_or_default(),
+                                    procedure.address,
+                                    file,
+                                    line,
+                                    &checksums,
+                                ),
+                            });
+                        }
+                        scopes.push(Scope::Inline);
+                    }
+                    S_THUNK32 | S_BLOCK32 | S_WITH32 | S_SEPCODE | S_GMANPROC | S_LMANPROC => {
+                        scopes.push(Scope::Other)
+                    }
+                    S_END | S_PROC_ID_END | S_INLINESITE_END => {
+                        scopes.pop();
+                    }
+                    _ => {}
+                }
+            }
+        }
+        (files, procedures)
+    }
+
+    /// The FPO and frame data records of the image.
+    pub fn frame_data(&self) -> Vec<FrameData> {
+        let mut frames = vec![];
+        let fpo = self.debug_stream(DBG_FPO).unwrap_or_default();
+        for record in fpo.chunks_exact(16) {
+            let [start, size, locals] = [0, 4, 8].map(|i| elf::read::<u32>(record, i).unwrap());
+            let params = elf::read::<u16>(record, 12).unwrap() as u32;
+            let bits = elf::read::<u16>(record, 14).unwrap();
+            frames.push(FrameData {
+                kind: 0,
+                address: start as u64,
+                size,
+                prolog: (bits & 0xff) as u32,
+                epilog: 0,
+                // Counted in double words.
+                params: params * 4,
+                saved_regs: ((bits >> 8) & 0x7) as u32 * 4,
+                locals: locals * 4,
+                max_stack: 0,
+                program: None,
+                allocates_base_pointer: bits & 0x1000 != 0,
+            });
+        }
+        let new_fpo = self.debug_stream(DBG_NEW_FPO).unwrap_or_default();
+        for record in new_fpo.chunks_exact(32) {
+            let [start, size, locals, params, max_stack, program] =
+                [0, 4, 8, 12, 16, 20].map(|i| elf::read::<u32>(record, i).unwrap());
+            frames.push(FrameData {
+                kind: 4,
+                address: start as u64,
+                size,
+                prolog: elf::read::<u16>(record, 24).unwrap() as u32,
+                epilog: 0,
+                params,
+                saved_regs: elf::read::<u16>(record, 26).unwrap() as u32,
+                locals,
+                max_stack,
+                program: elf::c_str(&self.names, program as usize).map(ToOwned::to_owned),
+                allocates_base_pointer: false,
+            });
+        }
+        frames
+    }
+}
+
+/// The streams of the named stream map of the PDB stream, after its version, signature, age and
+/// GUID.
+fn named_streams(info: &[u8]) -> HashMap<String, u32> {
+    let mut streams = HashMap::new();
+    let Some(strings_size) = elf::read::<u32>(info, 28) else {
+        return streams;
+    };
+    let strings = info.get(32..32 + strings_size as usize).unwrap_or_default();
+    let mut offset = 32 + strings_size as usize;
+    let (Some(capacity), Some(words)) = (
+        elf::read::<u32>(info, offset + 4),
+        elf::read::<u32>(info, offset + 8),
+    ) else {
+        return streams;
+    };
+    // The present and deleted bit vectors of the hash table, then the present buckets.
+    let present = offset + 12;
+    offset = present + words as usize * 4;
+    offset += 4 + elf::read::<u32>(info, offset).unwrap_or_default() as usize * 4;
+    for i in 0..capacity as usize {
+        let word = elf::read::<u32>(info, present + i / 32 * 4).unwrap_or_default();
+        if word & (1 << (i % 32)) == 0 {
+            continue;
+        }
+        let (Some(name), Some(stream)) = (
+            elf::read::<u32>(info, offset),
+            elf::read::<u32>(info, offset + 4),
+        ) else {
+            break;
+        };
+        if let Some(name) = elf::c_str(strings, name as usize) {
+            streams.insert(name.to_owned(), stream);
+        }
+        offset += 8;
+    }
+    streams
+}
+
+/// The offset, kind and body of each symbol record.
+fn records_of(data: &[u8]) -> impl Iterator<Item = (usize, u16, &[u8])> {
+    let mut offset = 0;
+    std::iter::from_fn(move || {
+        let len = elf::read::<u16>(data, offset)? as usize;
+        let kind = elf::read::<u16>(data, offset + 2)?;
+        let body = data.get(offset + 4..offset + 2 + len.max(2))?;
+        let record = (offset, kind, body);
+        offset += 2 + len.max(2);
+        Some(record)
+    })
+}
+
+/// The kind and data of each C13 debug subsection.
+fn subsections(data: &[u8]) -> impl Iterator<Item = (u32, &[u8])> {
+    let mut offset = 0;
+    std::iter::from_fn(move || loop {
+        let kind = elf::read::<u32>(data, offset)?;
+        let len = elf::read::<u32>(data, offset + 4)? as usize;
+        let body = data.get(offset + 8..offset + 8 + len)?;
+        offset = (offset + 8 + len).next_multiple_of(4);
+        if kind & DEBUG_S_IGNORE == 0 {
+            return Some((kind, body));
+        }
+    })
+}
+
+fn rva(sections: &[IMAGE_SECTION_HEADER], section: u16, offset: u32) -> Option<u64> {
+    let section = sections.get((section as usize).checked_sub(1)?)?;
+    Some(section.VirtualAddress as u64 + offset as u64)
+}
+
+/// The lines of a `DEBUG_S_LINES` subsection, each running up to the next one.
+fn line_block(
+    data: &[u8],
+    sections: &[IMAGE_SECTION_HEADER],
+    checksums: &HashMap<u32, usize>,
+) -> Vec<Line> {
+    let mut lines = vec![];
+    let (Some(start), Some(section), Some(code_size)) = (
+        elf::read::<u32>(data, 0),
+        elf::read::<u16>(data, 4),
+        elf::read::<u32>(data, 8),
+    ) else {
+        return lines;
+    };
+    let Some(base) = rva(sections, section, start) else {
+        return lines;
+    };
+    let mut offset = 12;
+    while let (Some(file), Some(count), Some(size)) = (
+        elf::read::<u32>(data, offset),
+        elf::read::<u32>(data, offset + 4),
+        elf::read::<u32>(data, offset + 8),
+    ) {
+        let entries: Vec<(u32, u32)> = (0..count as usize)
+            .map_while(|i| {
+                let entry = offset + 12 + i * 8;
+                Some((
+                    elf::read(data, entry)?,
+                    elf::read::<u32>(data, entry + 4)? & 0xff_ffff,
+                ))
+            })
+            .collect();
+        for (i, &(address, line)) in entries.iter().enumerate() {
+            let end = entries.get(i + 1).map_or(code_size, |&(next, _)| next);
+            match checksums.get(&file) {
+                Some(&file) if !HIDDEN_LINES.contains(&line) && end > address => lines.push(Line {
+                    address: base + address as u64,
+                    size: (end - address) as u64,
+                    line,
+                    file,
+                }),
+                _ => {}
+            }
+        }
+        offset += (size as usize).max(12);
+    }
+    lines
+}
+
+/// The lines of an inline site from its binary annotations, whose code offsets are relative to
+/// the procedure at `address`.
+fn inline_lines(
+    annotations: &[u8],
+    address: u64,
+    mut file: usize,
+    mut line: u32,
+    checksums: &HashMap<u32, usize>,
+) -> Vec<Line> {
+    let mut data = annotations.iter().copied();
+    // `CVUncompressData`
+    let mut next = || {
+        let b = data.next()? as u32;
+        match b {
+            _ if b & 0x80 == 0 => Some(b),
+            _ if b & 0xc0 == 0x80 => Some((b & 0x3f) << 8 | data.next()? as u32),
+            _ if b & 0xe0 == 0xc0 => {
+                let [b1, b2, b3] = [data.next()?, data.next()?, data.next()?].map(u32::from);
+                Some((b & 0x1f) << 24 | b1 << 16 | b2 << 8 | b3)
+            }
+            _ => None,
+        }
+    };
+    let signed = |v: u32| match v & 1 {
+        0 => (v >> 1) as i32,
+        _ => -((v >> 1) as i32),
+    };
+    let mut lines: Vec<Line> = vec![];
+    let (mut offset, mut base) = (0u32, 0u32);
+    while let Some(op) = next() {
+        let length = match op {
+            1 => {
+                offset = next().unwrap_or_default();
+                continue;
+            }
+            2 => {
+                base = next().unwrap_or_default();
+                continue;
+            }
+            3 => {
+                offset = offset.wrapping_add(next().unwrap_or_default());
+                None
+            }
+            4 => {
+                let length = next().unwrap_or_default();
+                if let Some(last) = lines.last_mut().filter(|last| last.size == 0) {
+                    last.size = length as u64;
+                }
+                offset = offset.wrapping_add(length);
+                continue;
+            }
+            5 => {
+                file = next()
+                    .and_then(|id| checksums.get(&id))
+                    .copied()
+                    .unwrap_or(file);
+                continue;
+            }
+            6 => {
+                line = line.wrapping_add_signed(signed(next().unwrap_or_default()));
+                continue;
+            }
+            11 => {
+                let v = next().unwrap_or_default();
+                offset = offset.wrapping_add(v & 0xf);
+                line = line.wrapping_add_signed(signed(v >> 4));
+                None
+            }
+            12 => {
+                let length = next().unwrap_or_default();
+                offset = offset.wrapping_add(next().unwrap_or_default());
+                Some(length)
+            }
+            // Line end, range kind and columns.
+            7..=10 | 13 => {
+                next();
+                continue;
+            }
+            _ => break,
+        };
+        // Each line without a length runs up to the next one.
+        let at = address + offset.wrapping_add(base) as u64;
+        if let Some(last) = lines.last_mut().filter(|last| last.size == 0) {
+            last.size = at.saturating_sub(last.address);
+        }
+        lines.push(Line {
+            address: at,
+            size: length.unwrap_or_default() as u64,
+            line,
+            file,
+        });
+    }
+    lines.retain(|line| line.size > 0);
+    lines
+}
+
+/// The records of a TPI or IPI stream, indexed from its first type index.
+struct TypeStream {
+    first: u32,
+    data: Vec<u8>,
+    offsets: Vec<usize>,
+}
+
+impl TypeStream {
+    fn new(data: Option<Vec<u8>>) -> Self {
+        let data = data.unwrap_or_default();
+        let first = elf::read(&data, 8).unwrap_or_default();
+        let header_size = elf::read::<u32>(&data, 4).unwrap_or_default() as usize;
+        let records = data.get(header_size..).unwrap_or_default();
+        let offsets = records_of(records)
+            .map(|(offset, _, _)| header_size + offset)
+            .collect();
+        Self {
+            first,
+            data,
+            offsets,
+        }
+    }
+
+    fn get(&self, index: u32) -> Option<(u16, &[u8])> {
+        let offset = *self.offsets.get(index.checked_sub(self.first)? as usize)?;
+        let (_, kind, body) = records_of(&self.data[offset..]).next()?;
+        Some((kind, body))
+    }
+}
+
+/// The name of an inlined function, qualified by its namespace or class.
+fn id_name(ipi: &TypeStream, tpi: &TypeStream, id: u32) -> Option<String> {
+    let (kind, body) = ipi.get(id)?;
+    let name = elf::c_str(body, 8)?;
+    let scope: u32 = elf::read(body, 0)?;
+    let scope = match kind {
+        LF_FUNC_ID if scope != 0 => match ipi.get(scope)? {
+            (LF_STRING_ID, body) => elf::c_str(body, 4),
+            _ => None,
+        },
+        LF_MFUNC_ID => type_name(tpi, scope),
+        _ => None,
+    };
+    Some(match scope {
+        Some(scope) => format!("{scope}::{name}"),
+        None => name.to_owned(),
+    })
+}
+
+/// The name of a class, structure, interface or union.
+fn type_name(tpi: &TypeStream, index: u32) -> Option<&str> {
+    let (kind, body) = tpi.get(index)?;
+    let size = match kind {
+        LF_CLASS | LF_STRUCTURE | LF_INTERFACE => 16,
+        LF_UNION => 8,
+        _ => return None,
+    };
+    // The size is a numeric leaf: the value itself below 0x8000, else the kind of the one after.
+    let leaf = elf::read::<u16>(body, size)?;
+    let value = match leaf {
+        ..0x8000 => 0,
+        0x8000 => 1,
+        0x8001 | 0x8002 => 2,
+        0x8003 | 0x8004 => 4,
+        0x8009 | 0x800a => 8,
+        _ => return None,
+    };
+    elf::c_str(body, size + 2 + value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{fs, path::Path};
+
+    #[test]
+    fn parse() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/pdb/crash.pdb");
+        let data = fs::read(path).unwrap();
+        let pdb = Pdb::parse(&data).unwrap();
+        assert_eq!(pdb.debug_id(), "1CA1DB0DFE0FEEE5DBA5EBA11CAFE0C02");
+        assert_eq!(pdb.machine, 0x14c);
+        // Not `_data`.
+        let publics: Vec<_> = pdb.publics().into_iter().map(|(a, _)| a).collect();
+        assert_eq!(publics, [0x1000, 0x1040, 0x1080]);
+
+        let (files, procedures) = pdb.procedures();
+        assert_eq!(files, ["crash.c", "box.h"]);
+        let mid = &procedures[1];
+        assert_eq!(
+            (mid.address, mid.size, mid.name.as_str()),
+            (0x1040, 0x30, "mid")
+        );
+        let sites: Vec<_> = mid
+            .inlines
+            .iter()
+            .map(|site| (site.depth, site.name.as_str(), site.lines.len()))
+            .collect();
+        assert_eq!(sites, [(0, "ns::leaf", 2), (1, "Box::get", 1)]);
+        let get = &mid.inlines[1].lines[0];
+        assert_eq!(
+            (get.address, get.size, get.line, get.file),
+            (0x104a, 4, 4, 1)
+        );
+    }
+}
@@ -0,0 +1,227 @@
+use crate::elf;
+use std::mem;
+
+pub const IMAGE_FILE_MACHINE_I386: u16 = 0x14c;
+pub const IMAGE_FILE_MACHINE_AMD64: u16 = 0x8664;
+pub const IMAGE_FILE_MACHINE_ARM64: u16 = 0xaa64;
+pub const IMAGE_NT_OPTIONAL_HDR32_MAGIC: u16 = 0x10b;
+pub const IMAGE_NT_OPTIONAL_HDR64_MAGIC: u16 = 0x20b;
+pub const IMAGE_DIRECTORY_ENTRY_EXPORT: usize = 0;
+pub const IMAGE_DIRECTORY_ENTRY_DEBUG: usize = 6;
+pub const IMAGE_DEBUG_TYPE_CODEVIEW: u32 = 2;
+pub const CV_SIGNATURE_RSDS: u32 = 0x5344_5352;
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct IMAGE_FILE_HEADER {
+    pub Machine: u16,
+    pub NumberOfSections: u16,
+    pub TimeDateStamp: u32,
+    pub PointerToSymbolTable: u32,
+    pub NumberOfSymbols: u32,
+    pub SizeOfOptionalHeader: u16,
+    pub Characteristics: u16,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct IMAGE_DATA_DIRECTORY {
+    pub VirtualAddress: u32,
+    pub Size: u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct IMAGE_SECTION_HEADER {
+    pub Name: [u8; 8],
+    pub VirtualSize: u32,
+    pub VirtualAddress: u32,
+    pub SizeOfRawData: u32,
+    pub PointerToRawData: u32,
+    pub PointerToRelocations: u32,
+    pub PointerToLinenumbers: u32,
+    pub NumberOfRelocations: u16,
+    pub NumberOfLinenumbers: u16,
+    pub Characteristics: u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct IMAGE_EXPORT_DIRECTORY {
+    pub Characteristics: u32,
+    pub TimeDateStamp: u32,
+    pub MajorVersion: u16,
+    pub MinorVersion: u16,
+    pub Name: u32,
+    pub Base: u32,
+    pub NumberOfFunctions: u32,
+    pub NumberOfNames: u32,
+    pub AddressOfFunctions: u32,
+    pub AddressOfNames: u32,
+    pub AddressOfNameOrdinals: u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct IMAGE_DEBUG_DIRECTORY {
+    pub Characteristics: u32,
+    pub TimeDateStamp: u32,
+    pub MajorVersion: u16,
+    pub MinorVersion: u16,
+    pub Type: u32,
+    pub SizeOfData: u32,
+    pub AddressOfRawData: u32,
+    pub PointerToRawData: u32,
+}
+
+/// A PE image file, 32 or 64-bit.
+pub struct Pe<'a> {
+    data: &'a [u8],
+    pub header: IMAGE_FILE_HEADER,
+    pub size_of_image: u32,
+    /// The address the image was linked at.
+    pub image_base: u64,
+    directories: Vec<IMAGE_DATA_DIRECTORY>,
+    sections: Vec<IMAGE_SECTION_HEADER>,
+}
+
+/// The PDB a PE image was linked with, from its CodeView debug directory entry.
+pub struct CodeView {
+    pub guid: [u8; 16],
+    pub age: u32,
+    pub path: String,
+}
+
+impl<'a> Pe<'a> {
+    pub fn parse(data: &'a [u8]) -> Option<Self> {
+        if data.get(..2)? != b"MZ" {
+            return None;
+        }
+        let nt = elf::read::<u32>(data, 0x3c)? as usize;
+        if data.get(nt..nt + 4)? != b"PE\0\0" {
+            return None;
+        }
+        let header: IMAGE_FILE_HEADER = elf::read(data, nt + 4)?;
+        let optional = nt + 4 + mem::size_of::<IMAGE_FILE_HEADER>();
+        // The data directories follow their count, after the fields of either optional header.
+        let (directories, image_base) = match elf::read::<u16>(data, optional)? {
+            IMAGE_NT_OPTIONAL_HDR32_MAGIC => {
+                (optional + 92, elf::read::<u32>(data, optional + 28)? as u64)
+            }
+            IMAGE_NT_OPTIONAL_HDR64_MAGIC => (optional + 108, elf::read(data, optional + 24)?),
+            _ => return None,
+        };
+        let count = elf::read::<u32>(data, directories)?.min(16) as usize;
+        let directories = (0..count)
+            .map(|i| {
+                elf::read(
+                    data,
+                    directories + 4 + i * mem::size_of::<IMAGE_DATA_DIRECTORY>(),
+                )
+            })
+            .collect::<Option<_>>()?;
+        let sections = optional + header.SizeOfOptionalHeader as usize;
+        let sections = (0..header.NumberOfSections as usize)
+            .filter_map(|i| elf::read(data, sections + i * mem::size_of::<IMAGE_SECTION_HEADER>()))
+            .collect();
+        Some(Self {
+            data,
+            header,
+            size_of_image: elf::read(data, optional + 56)?,
+            image_base,
+            directories,
+            sections,
+        })
+    }
+
+    /// What symbol servers index the image by: its timestamp and size.
+    pub fn code_id(&self) -> String {
+        format!("{:08X}{:x}", self.header.TimeDateStamp, self.size_of_image)
+    }
+
+    /// The file offset of a relative virtual address.
+    fn offset(&self, rva: u32) -> Option<usize> {
+        let section = self.sections.iter().find(|s| {
+            s.VirtualAddress <= rva && rva - s.VirtualAddress < s.SizeOfRawData.max(s.VirtualSize)
+        })?;
+        Some(section.PointerToRawData as usize + (rva - section.VirtualAddress) as usize)
+    }
+
+    /// The file contents at a relative virtual address.
+    fn rva(&self, rva: u32, len: usize) -> Option<&'a [u8]> {
+        let start = self.offset(rva)?;
+        self.data.get(start..start.checked_add(len)?)
+    }
+
+    fn directory(&self, entry: usize) -> Option<IMAGE_DATA_DIRECTORY> {
+        self.directories
+            .get(entry)
+            .copied()
+            .filter(|dir| dir.VirtualAddress != 0)
+    }
+
+    pub fn codeview(&self) -> Option<CodeView> {
+        let dir = self.directory(IMAGE_DIRECTORY_ENTRY_DEBUG)?;
+        let entries = self.rva(dir.VirtualAddress, dir.Size as usize)?;
+        (0..entries.len() / mem::size_of::<IMAGE_DEBUG_DIRECTORY>()).find_map(|i| {
+            let entry: IMAGE_DEBUG_DIRECTORY =
+                elf::read(entries, i * mem::size_of::<IMAGE_DEBUG_DIRECTORY>())?;
+            if entry.Type != IMAGE_DEBUG_TYPE_CODEVIEW {
+                return None;
+            }
+            let cv = self
+                .data
+                .get(entry.PointerToRawData as usize..)?
+                .get(..entry.SizeOfData as usize)?;
+            if elf::read::<u32>(cv, 0)? != CV_SIGNATURE_RSDS {
+                return None;
+            }
+            Some(CodeView {
+                guid: elf::read(cv, 4)?,
+                age: elf::read(cv, 20)?,
+                path: elf::c_str(cv, 24)?.to_owned(),
+            })
+        })
+    }
+
+    /// The named exports and their relative virtual addresses, leaving out those forwarded to
+    /// other images.
+    pub fn exports(&self) -> Vec<(String, u32)> {
+        let Some(dir) = self.directory(IMAGE_DIRECTORY_ENTRY_EXPORT) else {
+            return vec![];
+        };
+        let Some(exports) = self
+            .rva(dir.VirtualAddress, mem::size_of::<IMAGE_EXPORT_DIRECTORY>())
+            .and_then(|data| elf::read::<IMAGE_EXPORT_DIRECTORY>(data, 0))
+        else {
+            return vec![];
+        };
+        let u32_at = |rva: u32, i: u32| {
+            let rva = rva.checked_add(i.checked_mul(4)?)?;
+            elf::read::<u32>(self.rva(rva, 4)?, 0)
+        };
+        (0..exports.NumberOfNames)
+            .filter_map(|i| {
+                let ordinal = exports.AddressOfNameOrdinals.checked_add(i * 2)?;
+                let ordinal = elf::read::<u16>(self.rva(ordinal, 2)?, 0)?;
+                let address = u32_at(exports.AddressOfFunctions, ordinal as u32)?;
+                // Forwarders point at a string in the export directory.
+                if address.wrapping_sub(dir.VirtualAddress) < dir.Size {
+                    return None;
+                }
+                let name = self.offset(u32_at(exports.AddressOfNames, i)?)?;
+                let name = elf::c_str(self.data, name)?;
+                Some((name.to_owned(), address))
+            })
+            .collect()
+    }
+}
+
+pub fn arch(machine: u16) -> Option<&'static str> {
+    match machine {
+        IMAGE_FILE_MACHINE_I386 => Some("x86"),
+        IMAGE_FILE_MACHINE_AMD64 => Some("x86_64"),
+        IMAGE_FILE_MACHINE_ARM64 => Some("arm64"),
+        _ => None,
+    }
+}
@@ -0,0 +1,472 @@
+use crate::minidump::{self, Minidump};
+use std::fmt;
+
+/// A crash reason, decoded from a Linux signal, a Mach exception or a Windows NTSTATUS.
+pub struct Reason {
+    /// e.g. `SIGSEGV SEGV_MAPERR` or `EXC_BAD_ACCESS / KERN_INVALID_ADDRESS`.
+    pub name: String,
+    pub address: Option<u64>,
+    pub explanation: &'static str,
+}
+
+impl fmt::Display for Reason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        if let Some(address) = self.address {
+            write!(f, " at {address:#x}")?;
+        }
+        if !self.explanation.is_empty() {
+            write!(f, ": {}", self.explanation)?;
+        }
+        Ok(())
+    }
+}
+
+const LINUX_SIGNALS: [&str; 32] = [
+    "",
+    "SIGHUP",
+    "SIGINT",
+    "SIGQUIT",
+    "SIGILL",
+    "SIGTRAP",
+    "SIGABRT",
+    "SIGBUS",
+    "SIGFPE",
+    "SIGKILL",
+    "SIGUSR1",
+    "SIGSEGV",
+    "SIGUSR2",
+    "SIGPIPE",
+    "SIGALRM",
+    "SIGTERM",
+    "SIGSTKFLT",
+    "SIGCHLD",
+    "SIGCONT",
+    "SIGSTOP",
+    "SIGTSTP",
+    "SIGTTIN",
+    "SIGTTOU",
+    "SIGURG",
+    "SIGXCPU",
+    "SIGXFSZ",
+    "SIGVTALRM",
+    "SIGPROF",
+    "SIGWINCH",
+    "SIGIO",
+    "SIGPWR",
+    "SIGSYS",
+];
+
+/// Signal numbers of macOS and the other BSDs.
+const BSD_SIGNALS: [&str; 32] = [
+    "",
+    "SIGHUP",
+    "SIGINT",
+    "SIGQUIT",
+    "SIGILL",
+    "SIGTRAP",
+    "SIGABRT",
+    "SIGEMT",
+    "SIGFPE",
+    "SIGKILL",
+    "SIGBUS",
+    "SIGSEGV",
+    "SIGSYS",
+    "SIGPIPE",
+    "SIGALRM",
+    "SIGTERM",
+    "SIGURG",
+    "SIGSTOP",
+    "SIGTSTP",
+    "SIGCONT",
+    "SIGCHLD",
+    "SIGTTIN",
+    "SIGTTOU",
+    "SIGIO",
+    "SIGXCPU",
+    "SIGXFSZ",
+    "SIGVTALRM",
+    "SIGPROF",
+    "SIGWINCH",
+    "SIGINFO",
+    "SIGUSR1",
+    "SIGUSR2",
+];
+
+fn signal_name(names: &[&str; 32], signo: i32) -> String {
+    match names.get(signo as usize) {
+        Some(name) if !name.is_empty() => name.to_string(),
+        _ => format!("signal {signo}"),
+    }
+}
+
+fn signal_explanation(name: &str) -> &'static str {
+    match name {
+        "SIGSEGV" => "invalid memory reference",
+        "SIGBUS" => "bus error",
+        "SIGILL" => "illegal instruction",
+        "SIGFPE" => "arithmetic exception",
+        "SIGABRT" => "aborted",
+        "SIGTRAP" => "trace or breakpoint trap",
+        "SIGSYS" => "bad system call",
+        "SIGKILL" => "killed",
+        "SIGTERM" => "terminated",
+        "SIGPIPE" => "write to a pipe with no reader",
+        "SIGXCPU" => "CPU time limit exceeded",
+        "SIGXFSZ" => "file size limit exceeded",
+        _ => "",
+    }
+}
+
+/// A Linux signal, with its `si_code` and `si_addr`.
+pub fn signal(signo: i32, code: i32, address: u64) -> Reason {
+    let name = signal_name(&LINUX_SIGNALS, signo);
+    let (code_name, explanation) = match (name.as_str(), code) {
+        (_, 0) => ("SI_USER", "sent by kill"),
+        (_, 0x80) => ("SI_KERNEL", "sent by the kernel"),
+        (_, -1) => ("SI_QUEUE", "sent by sigqueue"),
+        (_, -2) => ("SI_TIMER", "POSIX timer expired"),
+        (_, -6) => ("SI_TKILL", "sent by tkill or raise"),
+        ("SIGSEGV", 1) => ("SEGV_MAPERR", "address not mapped to object"),
+        ("SIGSEGV", 2) => ("SEGV_ACCERR", "invalid permissions for mapped object"),
+        ("SIGSEGV", 3) => ("SEGV_BNDERR", "failed address bound checks"),
+        ("SIGSEGV", 4) => ("SEGV_PKUERR", "access denied by memory protection keys"),
+        ("SIGBUS", 1) => ("BUS_ADRALN", "invalid address alignment"),
+        ("SIGBUS", 2) => ("BUS_ADRERR", "nonexistent physical address"),
+        ("SIGBUS", 3) => ("BUS_OBJERR", "object-specific hardware error"),
+        ("SIGBUS", 4) => ("BUS_MCEERR_AR", "hardware memory error consumed"),
+        ("SIGBUS", 5) => ("BUS_MCEERR_AO", "hardware memory error detected"),
+        ("SIGILL", 1) => ("ILL_ILLOPC", "illegal opcode"),
+        ("SIGILL", 2) => ("ILL_ILLOPN", "illegal operand"),
+        ("SIGILL", 3) => ("ILL_ILLADR", "illegal addressing mode"),
+        ("SIGILL", 4) => ("ILL_ILLTRP", "illegal trap"),
+        ("SIGILL", 5) => ("ILL_PRVOPC", "privileged opcode"),
+        ("SIGILL", 6) => ("ILL_PRVREG", "privileged register"),
+        ("SIGILL", 7) => ("ILL_COPROC", "coprocessor error"),
+        ("SIGILL", 8) => ("ILL_BADSTK", "internal stack error"),
+        ("SIGFPE", 1) => ("FPE_INTDIV", "integer divide by zero"),
+        ("SIGFPE", 2) => ("FPE_INTOVF", "integer overflow"),
+        ("SIGFPE", 3) => ("FPE_FLTDIV", "floating-point divide by zero"),
+        ("SIGFPE", 4) => ("FPE_FLTOVF", "floating-point overflow"),
+        ("SIGFPE", 5) => ("FPE_FLTUND", "floating-point underflow"),
+        ("SIGFPE", 6) => ("FPE_FLTRES", "floating-point inexact result"),
+        ("SIGFPE", 7) => ("FPE_FLTINV", "floating-point invalid operation"),
+        ("SIGFPE", 8) => ("FPE_FLTSUB", "subscript out of range"),
+        ("SIGTRAP", 1) => ("TRAP_BRKPT", "process breakpoint"),
+        ("SIGTRAP", 2) => ("TRAP_TRACE", "process trace trap"),
+        ("SIGTRAP", 3) => ("TRAP_BRANCH", "process taken branch trap"),
+        ("SIGTRAP", 4) => ("TRAP_HWBKPT", "hardware breakpoint or watchpoint"),
+        ("SIGSYS", 1) => ("SYS_SECCOMP", "system call denied by seccomp"),
+        _ => ("", ""),
+    };
+    let fault = matches!(
+        name.as_str(),
+        "SIGSEGV" | "SIGBUS" | "SIGILL" | "SIGFPE" | "SIGTRAP"
+    );
+    Reason {
+        name: match code_name {
+            "" => name.clone(),
+            code_name => format!("{name} {code_name}"),
+        },
+        // si_addr only holds the faulting address for faults raised by the CPU.
+        address: (fault && code > 0 && code != 0x80).then_some(address),
+        explanation: match explanation {
+            "" => signal_explanation(&name),
+            explanation => explanation,
+        },
+    }
+}
+
+/// A Mach exception, with its code and subcode, whose meaning depends on the architecture.
+pub fn mach(exception: u32, code: u64, subcode: Option<u64>, arm64: bool) -> Reason {
+    let (name, explanation) = match exception {
+        1 => ("EXC_BAD_ACCESS", "invalid memory access"),
+        2 => ("EXC_BAD_INSTRUCTION", "illegal or undefined instruction"),
+        3 => ("EXC_ARITHMETIC", "arithmetic exception"),
+        4 => ("EXC_EMULATION", "emulation instruction"),
+        5 => ("EXC_SOFTWARE", "software generated exception"),
+        6 => ("EXC_BREAKPOINT", "trace or breakpoint trap"),
+        7 => ("EXC_SYSCALL", "system call"),
+        8 => ("EXC_MACH_SYSCALL", "Mach system call"),
+        9 => ("EXC_RPC_ALERT", "RPC alert"),
+        10 => ("EXC_CRASH", "abnormal process exit"),
+        11 => ("EXC_RESOURCE", "resource consumption limit exceeded"),
+        12 => ("EXC_GUARD", "guarded resource violation"),
+        13 => ("EXC_CORPSE_NOTIFY", "corpse notification"),
+        _ => ("", ""),
+    };
+    let name = match name {
+        "" => format!("exception {exception}"),
+        name => name.to_owned(),
+    };
+
+    let mut address = None;
+    let (code_name, code_explanation) = match (exception, code, arm64) {
+        (1, 1, _) => ("KERN_INVALID_ADDRESS", "address not mapped"),
+        (1, 2, _) => (
+            "KERN_PROTECTION_FAILURE",
+            "invalid permissions for mapped memory",
+        ),
+        (1, 8, _) => ("KERN_NO_ACCESS", "access denied"),
+        (1, 9, _) => ("KERN_MEMORY_FAILURE", "physical memory failure"),
+        (1, 10, _) => ("KERN_MEMORY_ERROR", "memory error"),
+        (1, 13, false) => ("EXC_I386_GPFLT", "general protection fault"),
+        (1, 0x101, true) => ("EXC_ARM_DA_ALIGN", "misaligned data access"),
+        (1, 0x102, true) => ("EXC_ARM_DA_DEBUG", "debug data abort"),
+        (1, 0x103, true) => ("EXC_ARM_SP_ALIGN", "misaligned stack pointer"),
+        (1, 0x104, true) => ("EXC_ARM_SWP", "SWP instruction"),
+        (1, 0x105, true) => ("EXC_ARM_PAC_FAIL", "pointer authentication failure"),
+        (2, 1, false) => ("EXC_I386_INVOP", "invalid opcode"),
+        (2, 1, true) => ("EXC_ARM_UNDEFINED", "undefined instruction"),
+        (3, 1, false) => ("EXC_I386_DIV", "integer divide by zero"),
+        (3, 2, false) => ("EXC_I386_INTO", "integer overflow"),
+        (3, 5, false) => ("EXC_I386_EXTERR", "floating-point error"),
+        (3, 8, false) => ("EXC_I386_SSEEXTERR", "SSE floating-point error"),
+        (3, 1, true) => ("EXC_ARM_FP_IO", "floating-point invalid operation"),
+        (3, 2, true) => ("EXC_ARM_FP_DZ", "floating-point divide by zero"),
+        (3, 3, true) => ("EXC_ARM_FP_OF", "floating-point overflow"),
+        (3, 4, true) => ("EXC_ARM_FP_UF", "floating-point underflow"),
+        (3, 5, true) => ("EXC_ARM_FP_IX", "floating-point inexact result"),
+        (3, 6, true) => ("EXC_ARM_FP_ID", "floating-point denormal input"),
+        (5, 0x10000, _) => ("EXC_UNIX_BAD_SYSCALL", "bad system call"),
+        (5, 0x10001, _) => ("EXC_UNIX_BAD_PIPE", "write to a pipe with no reader"),
+        (5, 0x10002, _) => ("EXC_UNIX_ABORT", "aborted"),
+        (5, 0x10003, _) => ("EXC_SOFT_SIGNAL", "signal"),
+        (6, 1, false) => ("EXC_I386_SGL", "single step"),
+        (6, 2, false) => ("EXC_I386_BPT", "breakpoint instruction"),
+        (6, 1, true) => ("EXC_ARM_BREAKPOINT", "breakpoint instruction"),
+        _ => ("", ""),
+    };
+    let mut name = match code_name {
+        "" => name,
+        code_name => format!("{name} / {code_name}"),
+    };
+    match exception {
+        1 => address = subcode,
+        // The code packs the signal, then the original exception and its code.
+        10 => {
+            let signal = (code >> 24 & 0xff) as i32;
+            name += &format!(" / {}", signal_name(&BSD_SIGNALS, signal));
+        }
+        5 if code == 0x10003 => {
+            let signal = subcode.unwrap_or_default() as i32;
+            name += &format!(" / {}", signal_name(&BSD_SIGNALS, signal));
+        }
+        11 => {
+            let kind = match code >> 61 & 7 {
+                1 => "RESOURCE_TYPE_CPU",
+                2 => "RESOURCE_TYPE_WAKEUPS",
+                3 => "RESOURCE_TYPE_MEMORY",
+                4 => "RESOURCE_TYPE_IO",
+                5 => "RESOURCE_TYPE_THREADS",
+                _ => "",
+            };
+            if !kind.is_empty() {
+                name += &format!(" / {kind}");
+            }
+        }
+        12 => {
+            let kind = match code >> 61 & 7 {
+                1 => "GUARD_TYPE_MACH_PORT",
+                2 => "GUARD_TYPE_FD",
+                3 => "GUARD_TYPE_USER",
+                4 => "GUARD_TYPE_VN",
+                5 => "GUARD_TYPE_VIRT_MEMORY",
+                _ => "",
+            };
+            if !kind.is_empty() {
+                name += &format!(" / {kind}");
+            }
+        }
+        _ => {}
+    }
+    Reason {
+        name,
+        address,
+        explanation: match code_explanation {
+            "" => explanation,
+            code_explanation => code_explanation,
+        },
+    }
+}
+
+/// A Windows exception code, most of them NTSTATUS values, with its parameters.
+pub fn ntstatus(code: u32, parameters: &[u64]) -> Reason {
+    let (name, explanation) = match code {
+        0x8000_0001 => ("STATUS_GUARD_PAGE_VIOLATION", "guard page accessed"),
+        0x8000_0002 => ("STATUS_DATATYPE_MISALIGNMENT", "misaligned data access"),
+        0x8000_0003 => ("STATUS_BREAKPOINT", "breakpoint instruction"),
+        0x8000_0004 => ("STATUS_SINGLE_STEP", "single step"),
+        0x4000_0015 => (
+            "STATUS_FATAL_APP_EXIT",
+            "fatal application exit, such as abort",
+        ),
+        0x4000_001f => (
+            "STATUS_WX86_BREAKPOINT",
+            "breakpoint instruction in emulated code",
+        ),
+        0x4001_0005 => ("DBG_CONTROL_C", "Ctrl+C"),
+        0xc000_0005 => ("STATUS_ACCESS_VIOLATION", "invalid memory access"),
+        0xc000_0006 => ("STATUS_IN_PAGE_ERROR", "page could not be loaded"),
+        0xc000_0008 => ("STATUS_INVALID_HANDLE", "invalid handle"),
+        0xc000_0017 => ("STATUS_NO_MEMORY", "out of memory"),
+        0xc000_001d => ("STATUS_ILLEGAL_INSTRUCTION", "illegal instruction"),
+        0xc000_0025 => (
+            "STATUS_NONCONTINUABLE_EXCEPTION",
+            "resumed a noncontinuable exception",
+        ),
+        0xc000_0026 => (
+            "STATUS_INVALID_DISPOSITION",
+            "invalid exception disposition",
+        ),
+        0xc000_008c => ("STATUS_ARRAY_BOUNDS_EXCEEDED", "array bounds exceeded"),
+        0xc000_008d => (
+            "STATUS_FLOAT_DENORMAL_OPERAND",
+            "floating-point denormal operand",
+        ),
+        0xc000_008e => (
+            "STATUS_FLOAT_DIVIDE_BY_ZERO",
+            "floating-point divide by zero",
+        ),
+        0xc000_008f => (
+            "STATUS_FLOAT_INEXACT_RESULT",
+            "floating-point inexact result",
+        ),
+        0xc000_0090 => (
+            "STATUS_FLOAT_INVALID_OPERATION",
+            "floating-point invalid operation",
+        ),
+        0xc000_0091 => ("STATUS_FLOAT_OVERFLOW", "floating-point overflow"),
+        0xc000_0092 => ("STATUS_FLOAT_STACK_CHECK", "floating-point stack overflow"),
+        0xc000_0093 => ("STATUS_FLOAT_UNDERFLOW", "floating-point underflow"),
+        0xc000_0094 => ("STATUS_INTEGER_DIVIDE_BY_ZERO", "integer divide by zero"),
+        0xc000_0095 => ("STATUS_INTEGER_OVERFLOW", "integer overflow"),
+        0xc000_0096 => ("STATUS_PRIVILEGED_INSTRUCTION", "privileged instruction"),
+        0xc000_00fd => ("STATUS_STACK_OVERFLOW", "stack overflow"),
+        0xc000_0135 => ("STATUS_DLL_NOT_FOUND", "DLL not found"),
+        0xc000_0139 => ("STATUS_ENTRYPOINT_NOT_FOUND", "DLL entry point not found"),
+        0xc000_013a => ("STATUS_CONTROL_C_EXIT", "terminated by Ctrl+C"),
+        0xc000_0142 => ("STATUS_DLL_INIT_FAILED", "DLL initialization failed"),
+        0xc000_02b4 => (
+            "STATUS_FLOAT_MULTIPLE_FAULTS",
+            "multiple floating-point faults",
+        ),
+        0xc000_02b5 => (
+            "STATUS_FLOAT_MULTIPLE_TRAPS",
+            "multiple floating-point traps",
+        ),
+        0xc000_0374 => ("STATUS_HEAP_CORRUPTION", "heap corruption"),
+        0xc000_0409 => (
+            "STATUS_STACK_BUFFER_OVERRUN",
+            "fail fast, such as a /GS check",
+        ),
+        0xc000_0417 => (
+            "STATUS_INVALID_CRUNTIME_PARAMETER",
+            "invalid C runtime parameter",
+        ),
+        0xc000_0420 => ("STATUS_ASSERTION_FAILURE", "assertion failure"),
+        0xe06d_7363 => ("EXCEPTION_MSVC_CPP", "unhandled C++ exception"),
+        _ => ("", ""),
+    };
+    let mut name = match name {
+        "" => format!("exception {code:#010x}"),
+        name => name.to_owned(),
+    };
+    let mut address = None;
+    // The parameters of an access violation are the kind of access and its address.
+    if matches!(code, 0xc000_0005 | 0xc000_0006) && parameters.len() >= 2 {
+        let access = match parameters[0] {
+            0 => "READ",
+            1 => "WRITE",
+            8 => "EXEC",
+            _ => "",
+        };
+        if !access.is_empty() {
+            name += &format!(" / {access}");
+        }
+        address = Some(parameters[1]);
+    }
+    if code == 0xc000_0409 {
+        if let Some(&fast_fail) = parameters.first() {
+            name += &format!(" / FAST_FAIL {fast_fail}");
+        }
+    }
+    Reason {
+        name,
+        address,
+        explanation,
+    }
+}
+
+/// The reason recorded in a minidump's exception stream.
+pub fn minidump(dump: &Minidump) -> Option<Reason> {
+    let exception = dump.exception()?;
+    let info = dump.system_info()?;
+    Some(match info.platform {
+        minidump::PLATFORM_LINUX => signal(
+            exception.code as i32,
+            exception.flags as i32,
+            exception.address,
+        ),
+        minidump::PLATFORM_MAC_OS_X | minidump::PLATFORM_IOS => mach(
+            exception.code,
+            exception.flags as u64,
+            Some(exception.address),
+            info.architecture != minidump::PROCESSOR_ARCHITECTURE_AMD64,
+        ),
+        _ => ntstatus(exception.code, &exception.parameters),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reasons() {
+        let cases = [
+            (
+                mach(1, 1, Some(0x8), true),
+                "EXC_BAD_ACCESS / KERN_INVALID_ADDRESS at 0x8: address not mapped",
+            ),
+            (
+                mach(1, 13, Some(0), false),
+                "EXC_BAD_ACCESS / EXC_I386_GPFLT at 0x0: general protection fault",
+            ),
+            (
+                mach(10, 6 << 24, None, true),
+                "EXC_CRASH / SIGABRT: abnormal process exit",
+            ),
+            (
+                mach(12, 2 << 61, None, true),
+                "EXC_GUARD / GUARD_TYPE_FD: guarded resource violation",
+            ),
+            (
+                signal(11, 1, 0x10),
+                "SIGSEGV SEGV_MAPERR at 0x10: address not mapped to object",
+            ),
+            (signal(11, 0, 0x10), "SIGSEGV SI_USER: sent by kill"),
+            (signal(11, 0x80, 0), "SIGSEGV SI_KERNEL: sent by the kernel"),
+            (
+                signal(8, 1, 0x401000),
+                "SIGFPE FPE_INTDIV at 0x401000: integer divide by zero",
+            ),
+            (
+                ntstatus(0xc000_00fd, &[]),
+                "STATUS_STACK_OVERFLOW: stack overflow",
+            ),
+            (
+                ntstatus(0xc000_0005, &[1, 0x20]),
+                "STATUS_ACCESS_VIOLATION / WRITE at 0x20: invalid memory access",
+            ),
+            (
+                ntstatus(0xc000_0409, &[7]),
+                "STATUS_STACK_BUFFER_OVERRUN / FAST_FAIL 7: fail fast, such as a /GS check",
+            ),
+            (ntstatus(0xdead_beef, &[]), "exception 0xdeadbeef"),
+        ];
+        for (reason, expected) in cases {
+            assert_eq!(reason.to_string(), expected);
+        }
+    }
+}
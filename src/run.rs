@@ -0,0 +1,64 @@
+#[cfg(unix)]
+use crate::control;
+use regex::bytes::Regex;
+#[cfg(unix)]
+use std::os::unix::process::CommandExt;
+use std::{
+    ffi::OsString,
+    io::{self, BufRead, BufReader, Read, Write},
+    process::{Child, Command, Stdio},
+    sync::mpsc,
+    thread::{self, JoinHandle},
+};
+
+pub fn spawn(
+    command: &[OsString],
+    on_output: Option<Regex>,
+    dump_tx: &mpsc::Sender<()>,
+) -> (Child, Vec<JoinHandle<()>>) {
+    let mut cmd = Command::new(&command[0]);
+    cmd.args(&command[1..])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    #[cfg(unix)]
+    unsafe {
+        cmd.pre_exec(control::unblock);
+    }
+    let mut child = cmd.spawn().unwrap();
+
+    let forwards = vec![
+        forward(
+            child.stdout.take().unwrap(),
+            io::stdout(),
+            on_output.clone(),
+            dump_tx.clone(),
+        ),
+        forward(
+            child.stderr.take().unwrap(),
+            io::stderr(),
+            on_output,
+            dump_tx.clone(),
+        ),
+    ];
+    (child, forwards)
+}
+
+fn forward(
+    src: impl Read + Send + 'static,
+    mut dst: impl Write + Send + 'static,
+    pattern: Option<Regex>,
+    dump_tx: mpsc::Sender<()>,
+) -> JoinHandle<()> {
+    thread::spawn(move || {
+        let mut src = BufReader::new(src);
+        let mut line = vec![];
+        while src.read_until(b'\n', &mut line).unwrap_or_default() > 0 {
+            let _ = dst.write_all(&line);
+            let _ = dst.flush();
+            if pattern.as_ref().is_some_and(|p| p.is_match(&line)) {
+                let _ = dump_tx.send(());
+            }
+            line.clear();
+        }
+    })
+}
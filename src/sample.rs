@@ -0,0 +1,250 @@
+use crate::{signature, Backtrace};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Write,
+    thread,
+    time::{Duration, Instant},
+};
+
+const WIDTH: f64 = 1200.0;
+const FRAME_HEIGHT: usize = 16;
+/// Average width of a character of the frame labels.
+const CHAR_WIDTH: f64 = 7.0;
+/// Frames narrower than this many pixels are left out of the flamegraph.
+const MIN_WIDTH: f64 = 0.1;
+
+/// Stacks, root first, with the number of samples each was seen in.
+pub type Stacks = HashMap<Vec<String>, usize>;
+
+/// Call `collect` `hz` times a second for `duration`, or until it finds no thread left, counting
+/// the backtraces it returns.
+pub fn sample(
+    hz: u32,
+    duration: Duration,
+    mut collect: impl FnMut() -> Vec<Vec<Backtrace>>,
+) -> Stacks {
+    let interval = Duration::from_secs(1) / hz.max(1);
+    let start = Instant::now();
+    let mut next = start;
+    let mut stacks = Stacks::new();
+    while start.elapsed() < duration {
+        let backtraces = collect();
+        if backtraces.is_empty() {
+            break;
+        }
+        for backtrace in backtraces {
+            let stack = backtrace.iter().rev().map(signature::frame).collect();
+            *stacks.entry(stack).or_default() += 1;
+        }
+        // Ticks missed by a slow collection are dropped rather than caught up.
+        next += interval;
+        let now = Instant::now();
+        if next > now {
+            thread::sleep(next - now);
+        } else {
+            next = now;
+        }
+    }
+    stacks
+}
+
+/// A duration such as `30s`, `500ms` or `2m`, in seconds without a unit.
+pub fn parse_duration(s: &str) -> Result<Duration, String> {
+    let i = s
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(s.len());
+    let value: f64 = s[..i]
+        .parse()
+        .map_err(|_| format!("invalid duration `{s}`"))?;
+    let unit = match &s[i..] {
+        "ms" => 0.001,
+        "" | "s" => 1.0,
+        "m" => 60.0,
+        "h" => 3600.0,
+        unit => return Err(format!("unknown unit `{unit}`, expected ms, s, m or h")),
+    };
+    Duration::try_from_secs_f64(value * unit).map_err(|e| format!("invalid duration `{s}`: {e}"))
+}
+
+/// The collapsed stack format of `flamegraph.pl` and inferno, one `frame;frame;... count` line
+/// per stack.
+pub fn folded(stacks: &Stacks) -> String {
+    let mut lines: Vec<_> = stacks
+        .iter()
+        .map(|(stack, count)| format!("{} {count}\n", stack.join(";")))
+        .collect();
+    lines.sort();
+    lines.concat()
+}
+
+#[derive(Default)]
+struct Node {
+    count: usize,
+    children: BTreeMap<String, Node>,
+}
+
+impl Node {
+    fn depth(&self) -> usize {
+        self.children
+            .values()
+            .map(|child| 1 + child.depth())
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+/// An SVG flamegraph of the stacks, roots at the bottom, with the sample counts in tooltips.
+pub fn flamegraph(stacks: &Stacks, title: &str) -> String {
+    let mut root = Node::default();
+    for (stack, &count) in stacks {
+        root.count += count;
+        let mut node = &mut root;
+        for frame in stack {
+            node = node.children.entry(frame.clone()).or_default();
+            node.count += count;
+        }
+    }
+
+    // A line for the title, then one per frame up to the deepest stack.
+    let height = (root.depth() + 3) * FRAME_HEIGHT;
+    let mut svg = String::new();
+    writeln!(
+        svg,
+        r##"<?xml version="1.0" standalone="no"?>
+<svg version="1.1" width="{WIDTH}" height="{height}" viewBox="0 0 {WIDTH} {height}" xmlns="http://www.w3.org/2000/svg">
+<style>text {{ font-family: monospace; font-size: 12px; }} rect:hover {{ stroke: black; }}</style>
+<rect width="100%" height="100%" fill="#f8f8f8"/>
+<text x="{}" y="{FRAME_HEIGHT}" text-anchor="middle">{}</text>"##,
+        WIDTH / 2.0,
+        escape(title),
+    )
+    .unwrap();
+    let scale = WIDTH / root.count.max(1) as f64;
+    draw(&mut svg, "all", &root, root.count, 0.0, 0, height, scale);
+    svg += "</svg>\n";
+    svg
+}
+
+#[allow(clippy::too_many_arguments)]
+fn draw(
+    svg: &mut String,
+    name: &str,
+    node: &Node,
+    total: usize,
+    x: f64,
+    depth: usize,
+    height: usize,
+    scale: f64,
+) {
+    let width = node.count as f64 * scale;
+    if width < MIN_WIDTH {
+        return;
+    }
+    let y = height - (depth + 1) * FRAME_HEIGHT;
+    let percent = node.count as f64 * 100.0 / total as f64;
+    writeln!(
+        svg,
+        r#"<g><title>{} ({} samples, {percent:.2}%)</title><rect x="{x:.1}" y="{y}" width="{width:.1}" height="{}" fill="{}" rx="2"/>"#,
+        escape(name),
+        node.count,
+        FRAME_HEIGHT - 1,
+        color(name),
+    )
+    .unwrap();
+    // The label is cut to fit its frame, and left out when too little of it would.
+    let fits = ((width - 6.0) / CHAR_WIDTH).max(0.0) as usize;
+    if fits >= 3 {
+        let label = if name.chars().count() > fits {
+            name.chars().take(fits - 2).collect::<String>() + ".."
+        } else {
+            name.to_owned()
+        };
+        writeln!(
+            svg,
+            r#"<text x="{:.1}" y="{}">{}</text>"#,
+            x + 3.0,
+            y + FRAME_HEIGHT - 4,
+            escape(&label),
+        )
+        .unwrap();
+    }
+    svg.push_str("</g>\n");
+
+    let mut x = x;
+    for (name, child) in &node.children {
+        draw(svg, name, child, total, x, depth + 1, height, scale);
+        x += child.count as f64 * scale;
+    }
+}
+
+/// A warm color, the same for a function wherever it appears.
+fn color(name: &str) -> String {
+    // FNV-1a
+    let hash = name.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, b| {
+        (hash ^ b as u64).wrapping_mul(0x100_0000_01b3)
+    });
+    let (r, g, b) = (hash % 50, (hash >> 8) % 230, (hash >> 16) % 55);
+    format!("rgb({},{g},{b})", 205 + r)
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stacks(stacks: &[(&[&str], usize)]) -> Stacks {
+        stacks
+            .iter()
+            .map(|(stack, count)| (stack.iter().map(|f| f.to_string()).collect(), *count))
+            .collect()
+    }
+
+    #[test]
+    fn durations() {
+        assert_eq!(parse_duration("30"), Ok(Duration::from_secs(30)));
+        assert_eq!(parse_duration("30s"), Ok(Duration::from_secs(30)));
+        assert_eq!(parse_duration("500ms"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_duration("1.5m"), Ok(Duration::from_secs(90)));
+        assert_eq!(parse_duration("2h"), Ok(Duration::from_secs(7200)));
+        assert!(parse_duration("").is_err());
+        assert!(parse_duration("s").is_err());
+        assert!(parse_duration("1.2.3s").is_err());
+        assert!(parse_duration("10d").is_err());
+        assert!(parse_duration("1e400").is_err());
+        assert!(parse_duration(&"9".repeat(30)).is_err());
+    }
+
+    #[test]
+    fn collapsed() {
+        let stacks = stacks(&[
+            (&["main", "work"], 3),
+            (&["main"], 1),
+            (&["main", "idle"], 2),
+        ]);
+        assert_eq!(folded(&stacks), "main 1\nmain;idle 2\nmain;work 3\n");
+        assert_eq!(folded(&Stacks::new()), "");
+    }
+
+    #[test]
+    fn svg() {
+        let stacks = stacks(&[(&["main", "work<T>"], 3), (&["main", "idle"], 1)]);
+        let svg = flamegraph(&stacks, "pid 1 & co");
+        assert!(svg.ends_with("</svg>\n"));
+        assert!(svg.contains(">pid 1 &amp; co</text>"));
+        // all, main, then its two children.
+        assert_eq!(svg.matches("<g>").count(), 4);
+        assert!(svg.contains("<title>all (4 samples, 100.00%)</title>"));
+        assert!(svg.contains("<title>work&lt;T&gt; (3 samples, 75.00%)</title>"));
+        // Children in name order, as wide as their share of the samples.
+        assert!(svg.contains(r#"<rect x="0.0" y="32" width="300.0""#));
+        assert!(svg.contains(r#"<rect x="300.0" y="32" width="900.0""#));
+        // The title line, then three levels of frames.
+        assert!(svg.contains(r#"height="80""#));
+    }
+}
@@ -0,0 +1,229 @@
+use crate::{
+    analysis, minidump::Minidump, symbolizer, Backtrace, Bucket, SignatureOptions, Snapshot,
+    SymbolOptions,
+};
+use chrono::{Local, TimeZone};
+use std::{collections::HashMap, fs, path::Path};
+
+/// Frames of the crash machinery rather than of the crash itself, always skipped.
+const SKIP: &[&str] = &[
+    "abort",
+    "raise",
+    "gsignal",
+    "__pthread_kill*",
+    "pthread_kill*",
+    "__assert_fail*",
+    "__libc_message*",
+    "malloc_printerr",
+    "__fortify_fail",
+    "__stack_chk_fail",
+    "core::panicking::*",
+    "std::panicking::*",
+    "std::panic::*",
+    "std::process::abort",
+    "std::sys::abort_internal",
+    "panic_abort::*",
+    "__rust_start_panic",
+    "*rust_panic",
+];
+/// Dumps kept per bucket as examples.
+const EXAMPLES: usize = 3;
+
+/// The top frames of the crashing thread, or of the first thread when there is no exception.
+pub fn signature(snapshot: &Snapshot, options: &SignatureOptions) -> Vec<String> {
+    let Some(thread) = snapshot
+        .threads
+        .iter()
+        .find(|t| t.exception.is_some())
+        .or(snapshot.threads.first())
+    else {
+        return vec![];
+    };
+    thread
+        .backtrace
+        .iter()
+        .map(frame)
+        .filter(|frame| {
+            // Patterns match the function alone, or the whole `module!function`.
+            let function = frame.split_once('!').map_or(frame.as_str(), |(_, f)| f);
+            !SKIP
+                .iter()
+                .copied()
+                .chain(options.skip.iter().map(String::as_str))
+                .any(|pattern| glob(pattern, function) || glob(pattern, frame))
+        })
+        .take(options.frames)
+        .collect()
+}
+
+/// A frame as `module!function` without the generic arguments that vary between builds, or as
+/// `module+offset`.
+pub fn frame(backtrace: &Backtrace) -> String {
+    let module = backtrace.module.as_ref().map(|module| {
+        let name = Path::new(&module.path).file_name().unwrap_or_default();
+        (name.to_string_lossy(), module.load_address)
+    });
+    if let Some(symbol) = &backtrace.symbol {
+        let mut name = String::new();
+        let mut rest = symbol.as_str();
+        while let Some(i) = rest.find("::<") {
+            name += &rest[..i];
+            let mut depth = 0;
+            let end = rest[i + 2..].char_indices().find_map(|(j, c)| {
+                match c {
+                    '<' => depth += 1,
+                    '>' => depth -= 1,
+                    _ => {}
+                }
+                (depth == 0).then_some(i + 2 + j + 1)
+            });
+            rest = end.map_or("", |end| &rest[end..]);
+        }
+        name += rest;
+        return match module {
+            Some((module, _)) => format!("{module}!{name}"),
+            None => name,
+        };
+    }
+    match module {
+        Some((module, load_address)) => format!("{module}+{:#x}", backtrace.address - load_address),
+        None => format!("{:#x}", backtrace.address),
+    }
+}
+
+/// Match a pattern of `*` for any text and `?` for any character against the whole text.
+fn glob(pattern: &str, text: &str) -> bool {
+    let mut rest = pattern.chars();
+    match rest.next() {
+        None => text.is_empty(),
+        Some('*') => (0..=text.len())
+            .filter(|&i| text.is_char_boundary(i))
+            .any(|i| glob(rest.as_str(), &text[i..])),
+        Some('?') => {
+            let mut text = text.chars();
+            text.next().is_some() && glob(rest.as_str(), text.as_str())
+        }
+        Some(c) => text
+            .strip_prefix(c)
+            .is_some_and(|text| glob(rest.as_str(), text)),
+    }
+}
+
+/// Group the minidumps of a directory by signature, the most frequent first.
+pub fn bucket(
+    dir: &Path,
+    options: &SignatureOptions,
+    symbols: &SymbolOptions,
+) -> Result<Vec<Bucket>, String> {
+    let mut paths: Vec<_> = fs::read_dir(dir)
+        .map_err(|e| format!("{}: {e}", dir.display()))?
+        .filter_map(|entry| Some(entry.ok()?.path()))
+        .filter(|path| path.is_file())
+        .collect();
+    paths.sort();
+
+    let mut buckets: HashMap<Vec<String>, (usize, u32, u32, Vec<_>)> = HashMap::new();
+    // Dumps of the same builds share the symbols of their modules, loaded once.
+    let mut cache = symbolizer::Cache::new();
+    for path in paths {
+        let Ok(data) = fs::read(&path) else {
+            continue;
+        };
+        let Some(dump) = Minidump::parse(&data) else {
+            continue;
+        };
+        let snapshot = analysis::snapshot_cached(&dump, symbols, &mut cache);
+        let signature = signature(&snapshot, options);
+        let time = dump.time();
+        let bucket = buckets.entry(signature).or_insert((0, time, time, vec![]));
+        bucket.0 += 1;
+        bucket.1 = bucket.1.min(time);
+        bucket.2 = bucket.2.max(time);
+        if bucket.3.len() < EXAMPLES {
+            bucket.3.push(path);
+        }
+    }
+
+    let time = |t: u32| {
+        Local
+            .timestamp_opt(t as i64, 0)
+            .single()
+            .map(|t| t.to_rfc3339())
+            .unwrap_or_default()
+    };
+    let mut buckets: Vec<Bucket> = buckets
+        .into_iter()
+        .map(|(signature, (count, first, last, examples))| Bucket {
+            signature,
+            count,
+            first_seen: time(first),
+            last_seen: time(last),
+            examples,
+        })
+        .collect();
+    buckets.sort_by(|a, b| {
+        b.count
+            .cmp(&a.count)
+            .then_with(|| a.first_seen.cmp(&b.first_seen))
+    });
+    Ok(buckets)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Module;
+
+    #[test]
+    fn patterns() {
+        assert!(glob("abort", "abort"));
+        assert!(!glob("abort", "abort2"));
+        assert!(!glob("abort", "__abort"));
+        assert!(glob("core::panicking::*", "core::panicking::panic_fmt"));
+        assert!(glob("core::panicking::*", "core::panicking::"));
+        assert!(!glob("core::panicking::*", "core::panic"));
+        assert!(glob("*rust_panic", "__rustc::rust_panic"));
+        assert!(glob("*::drop*", "alloc::vec::Vec::drop_in_place"));
+        assert!(!glob("*::drop*", "alloc::vec::Vec::push"));
+        assert!(glob("pthread_kill@@GLIBC_2.3?", "pthread_kill@@GLIBC_2.34"));
+        assert!(glob("?", "é"));
+        assert!(!glob("?", ""));
+        assert!(!glob("a?c", "ac"));
+        assert!(glob("*", ""));
+    }
+
+    #[test]
+    fn frames() {
+        let module = Module {
+            path: "/usr/lib/libapp.so".to_owned(),
+            load_address: 0x1000,
+            ..Default::default()
+        };
+        let mut backtrace = Backtrace::new(0, 0x1234, &[]);
+        assert_eq!(frame(&backtrace), "0x1234");
+        backtrace.module = Some(module);
+        assert_eq!(frame(&backtrace), "libapp.so+0x234");
+
+        for (symbol, expected) in [
+            ("main", "libapp.so!main"),
+            ("app::Queue<T>::push", "libapp.so!app::Queue<T>::push"),
+            (
+                "core::ptr::drop_in_place::<alloc::vec::Vec<u8>>",
+                "libapp.so!core::ptr::drop_in_place",
+            ),
+            (
+                "<app::Queue as core::ops::Drop>::drop::<Vec<Option<u32>>>::{closure#0}",
+                "libapp.so!<app::Queue as core::ops::Drop>::drop::{closure#0}",
+            ),
+            (
+                "std::rt::lang_start::<()>::{{closure}}",
+                "libapp.so!std::rt::lang_start::{{closure}}",
+            ),
+            ("app::f::<u8>::g::<u16>", "libapp.so!app::f::g"),
+            ("truncated::<u8", "libapp.so!truncated"),
+        ] {
+            backtrace.symbol = Some(symbol.to_owned());
+            assert_eq!(frame(&backtrace), expected);
+        }
+    }
+}
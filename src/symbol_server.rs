@@ -0,0 +1,179 @@
+use crate::SymbolOptions;
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+/// How long a file no server had is not asked for again.
+const MISS_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Fetches the debug files missing on this machine from debuginfod and Microsoft-style symbol
+/// servers, into a local cache laid out as the servers are.
+pub struct SymbolServers {
+    debuginfod: Vec<String>,
+    symbol_servers: Vec<String>,
+    cache: PathBuf,
+    agent: ureq::Agent,
+}
+
+impl SymbolServers {
+    pub fn new(options: &SymbolOptions) -> Self {
+        let mut debuginfod = options.debuginfod.clone();
+        if debuginfod.is_empty() {
+            if let Ok(urls) = env::var("DEBUGINFOD_URLS") {
+                debuginfod = urls.split_whitespace().map(ToOwned::to_owned).collect();
+            }
+        }
+        let cache = options.symbol_cache.clone().unwrap_or_else(|| {
+            let cache = env::var_os("XDG_CACHE_HOME")
+                .map(PathBuf::from)
+                .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".cache")))
+                .unwrap_or_else(env::temp_dir);
+            cache.join("pmortem")
+        });
+        Self {
+            debuginfod,
+            symbol_servers: options.symbol_servers.clone(),
+            cache,
+            agent: ureq::AgentBuilder::new().timeout(TIMEOUT).build(),
+        }
+    }
+
+    /// The separate debug file of an ELF image, by build ID.
+    pub fn debuginfo(&self, build_id: &str) -> Option<PathBuf> {
+        self.fetch(&self.debuginfod, &format!("buildid/{build_id}/debuginfo"))
+    }
+
+    /// A file of a symbol server, indexed by the name and identifier of a debug or code file, as
+    /// `<name>/<id>/<file>`: a PDB or PE image under its own name, or a Breakpad symbol file.
+    pub fn file(&self, name: &str, id: &str, file: &str) -> Option<PathBuf> {
+        self.fetch(&self.symbol_servers, &format!("{name}/{id}/{file}"))
+    }
+
+    /// The cache's copy of `path`, downloading it from the first of `servers` having it unless all
+    /// of them lately didn't.
+    fn fetch(&self, servers: &[String], path: &str) -> Option<PathBuf> {
+        if servers.is_empty() || path.split('/').any(|part| part.is_empty() || part == "..") {
+            return None;
+        }
+        let cached = self.cache.join(path);
+        if cached.is_file() {
+            return Some(cached);
+        }
+        let missing = suffixed(&cached, ".missing");
+        let age = fs::metadata(&missing)
+            .and_then(|m| m.modified())
+            .map(|modified| {
+                SystemTime::now()
+                    .duration_since(modified)
+                    .unwrap_or_default()
+            });
+        if age.is_ok_and(|age| age < MISS_TTL) {
+            return None;
+        }
+        fs::create_dir_all(cached.parent()?).ok()?;
+        // A server that failed may have it, only a miss on all of them is remembered.
+        let mut failed = false;
+        for server in servers {
+            let url = format!("{}/{path}", server.trim_end_matches('/'));
+            match self.agent.get(&url).call() {
+                Ok(response) => {
+                    // Written aside first, so that an interrupted download isn't taken for the file.
+                    let partial = suffixed(&cached, ".partial");
+                    let written = fs::File::create(&partial)
+                        .and_then(|mut f| io::copy(&mut response.into_reader(), &mut f));
+                    if written.is_ok() && fs::rename(&partial, &cached).is_ok() {
+                        let _ = fs::remove_file(&missing);
+                        return Some(cached);
+                    }
+                    let _ = fs::remove_file(&partial);
+                    failed = true;
+                }
+                Err(ureq::Error::Status(404, _)) => {}
+                Err(_) => failed = true,
+            }
+        }
+        if !failed {
+            let _ = fs::write(&missing, "");
+        }
+        None
+    }
+}
+
+fn suffixed(path: &Path, suffix: &str) -> PathBuf {
+    let mut path = path.as_os_str().to_owned();
+    path.push(suffix);
+    PathBuf::from(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        thread,
+    };
+
+    /// Serves `body` at `path` and 404 elsewhere, counting the requests.
+    fn serve(path: &'static str, body: &'static [u8]) -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let count = requests.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut line = String::new();
+                BufReader::new(&stream).read_line(&mut line).unwrap();
+                count.fetch_add(1, Ordering::SeqCst);
+                let (status, body) = match line.split(' ').nth(1) == Some(path) {
+                    true => ("200 OK", body),
+                    false => ("404 Not Found", &b""[..]),
+                };
+                let header = format!(
+                    "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                    body.len()
+                );
+                stream.write_all(header.as_bytes()).unwrap();
+                stream.write_all(body).unwrap();
+            }
+        });
+        (url, requests)
+    }
+
+    #[test]
+    fn cache() {
+        let (url, requests) = serve("/buildid/0123abcd/debuginfo", b"debug info");
+        let cache = env::temp_dir().join(format!("pmortem-symbol-server-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&cache);
+        let servers = SymbolServers::new(&SymbolOptions {
+            debuginfod: vec![url.clone()],
+            symbol_servers: vec![format!("{url}/")],
+            symbol_cache: Some(cache.clone()),
+            ..Default::default()
+        });
+
+        let path = servers.debuginfo("0123abcd").unwrap();
+        assert_eq!(path, cache.join("buildid/0123abcd/debuginfo"));
+        assert_eq!(fs::read(&path).unwrap(), b"debug info");
+        assert_eq!(servers.debuginfo("0123abcd"), Some(path));
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        // Misses are remembered.
+        assert_eq!(servers.file("app.pdb", "ID", "app.sym"), None);
+        assert!(cache.join("app.pdb/ID/app.sym.missing").is_file());
+        assert_eq!(servers.file("app.pdb", "ID", "app.sym"), None);
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+
+        assert_eq!(servers.file("..", "ID", "app.sym"), None);
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+        fs::remove_dir_all(&cache).unwrap();
+    }
+}
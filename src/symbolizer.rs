@@ -0,0 +1,506 @@
+use crate::{
+    breakpad::{self, SymbolFile},
+    dwarf::{Fde, Line},
+    dyld_cache::DyldCache,
+    elf::{self, Elf},
+    macho::{self, MachO},
+    minidump,
+    pdb::FrameData,
+    pe::Pe,
+    symbol_server::SymbolServers,
+    Inlined, Module, SymbolOptions,
+};
+use std::{collections::HashMap, fs};
+
+/// Symbolizers of single modules loaded at 0, by code identifier, for `Symbolizer::with_cache`.
+pub type Cache = HashMap<String, Symbolizer>;
+
+/// Resolves the function and data symbols of the ELF and Mach-O modules loaded in a process and
+/// the exports of PE ones, and the lines, inlined functions and unwind rules of those with a
+/// Breakpad symbol file or a PDB.
+pub struct Symbolizer {
+    symbols: Vec<Symbol>,
+    files: Vec<String>,
+    /// Lines, with their file as an index in `files`.
+    lines: Vec<Line>,
+    inlines: Vec<Inline>,
+    cfi: Vec<Fde>,
+    stack_win: Vec<FrameData>,
+}
+
+/// A range of the code of a function inlined into another.
+#[derive(Clone)]
+struct Inline {
+    address: u64,
+    size: u64,
+    depth: u32,
+    name: String,
+    /// The call in the function it's inlined into.
+    file: usize,
+    line: u32,
+}
+
+#[derive(Clone)]
+struct Symbol {
+    name: String,
+    demangled: String,
+    address: u64,
+    size: u64,
+}
+
+impl Symbol {
+    fn new(name: &str, address: u64, size: u64) -> Self {
+        Self {
+            name: name.to_owned(),
+            demangled: format!("{:#}", rustc_demangle::demangle(name)),
+            address,
+            size,
+        }
+    }
+}
+
+impl Symbolizer {
+    pub fn new(modules: &[Module]) -> Self {
+        Self::with_options(modules, &SymbolOptions::default())
+    }
+
+    pub fn with_options(modules: &[Module], options: &SymbolOptions) -> Self {
+        let caches = match &options.dyld_cache {
+            Some(dir) => DyldCache::find(dir, options.dyld_cache_uuid),
+            None => vec![],
+        };
+        let servers = SymbolServers::new(options);
+        let mut symbolizer = Self::empty();
+        for module in modules {
+            symbolizer.load(module, options, &caches, &servers);
+        }
+        symbolizer.sort();
+        symbolizer
+    }
+
+    /// A symbolizer as `with_options`, taking the symbols of the modules `cache` holds from earlier
+    /// calls and adding those of the others to it.
+    pub fn with_cache(modules: &[Module], options: &SymbolOptions, cache: &mut Cache) -> Self {
+        let mut sources = None;
+        let mut symbolizer = Self::empty();
+        for module in modules {
+            let mut load = |module: &Module| {
+                let (caches, servers) = sources.get_or_insert_with(|| {
+                    let caches = match &options.dyld_cache {
+                        Some(dir) => DyldCache::find(dir, options.dyld_cache_uuid),
+                        None => vec![],
+                    };
+                    (caches, SymbolServers::new(options))
+                });
+                let mut part = Self::empty();
+                part.load(module, options, caches, servers);
+                part.sort();
+                part
+            };
+            // Modules without an identifier can't be told apart from other builds at their path.
+            let Some(code_id) = &module.code_id else {
+                symbolizer.extend(&load(module), 0);
+                continue;
+            };
+            // Cached at 0, to be moved wherever each process loaded the module.
+            let part = cache.entry(code_id.clone()).or_insert_with(|| {
+                load(&Module {
+                    load_address: 0,
+                    ..module.clone()
+                })
+            });
+            symbolizer.extend(part, module.load_address);
+        }
+        symbolizer.sort();
+        symbolizer
+    }
+
+    fn empty() -> Self {
+        Self {
+            symbols: vec![],
+            files: vec![],
+            lines: vec![],
+            inlines: vec![],
+            cfi: vec![],
+            stack_win: vec![],
+        }
+    }
+
+    /// Add the symbols of a module, and its lines and unwind rules when it has a symbol file.
+    fn load(
+        &mut self,
+        module: &Module,
+        options: &SymbolOptions,
+        caches: &[DyldCache],
+        servers: &SymbolServers,
+    ) {
+        // A symbol file has lines and unwind rules on top of symbols, and needs no binary.
+        if let Some(file) = symbol_file(module, options, servers) {
+            self.add(file, module.load_address);
+            return;
+        }
+        let symbols = &mut self.symbols;
+        let Ok(data) = fs::read(&module.path) else {
+            // System libraries are only in the shared cache since macOS 11.
+            let image = caches.iter().find_map(|cache| {
+                let image = cache.image(&module.path)?;
+                let (code_id, _) = macho::ids(&image.uuid()?);
+                if module.code_id.as_ref().is_some_and(|id| *id != code_id) {
+                    return None;
+                }
+                let mut syms = image.symbols();
+                syms.extend(cache.local_symbols(&module.path));
+                Some((image, syms))
+            });
+            if let Some((image, syms)) = image {
+                symbols.extend(macho_symbols(&image, syms, module.load_address));
+            }
+            return;
+        };
+        if let Some(elf) = Elf::parse(&data) {
+            if stale(module, elf.build_id().map(|id| minidump::elf_ids(id).0)) {
+                return;
+            }
+            let bias = module.load_address.wrapping_sub(elf.base_address());
+            symbols.extend(
+                elf.symbols()
+                    .into_iter()
+                    .filter(|sym| sym.kind == elf::STT_FUNC || sym.kind == elf::STT_OBJECT)
+                    .map(|sym| Symbol::new(sym.name, sym.value.wrapping_add(bias), sym.size)),
+            );
+        } else if let Some(image) = MachO::file(
+            macho::slice(&data),
+            module.arch.as_deref().and_then(macho::cputype),
+        ) {
+            if stale(module, image.uuid().map(|uuid| macho::ids(&uuid).0)) {
+                return;
+            }
+            symbols.extend(macho_symbols(&image, image.symbols(), module.load_address));
+        } else if let Some(pe) = Pe::parse(&data) {
+            if stale(module, Some(pe.code_id())) {
+                return;
+            }
+            // The PDB where the linker left it, if it's the one, or else the exports.
+            let pdb = pe.codeview().and_then(|cv| {
+                let name = cv.path.rsplit(['\\', '/']).next()?;
+                let file = breakpad::dump_syms(&fs::read(&cv.path).ok()?, name)?;
+                (file.debug_id == minidump::debug_id(&cv.guid, cv.age)).then_some(file)
+            });
+            if let Some(file) = pdb.or_else(|| breakpad::dump_syms(&data, "")) {
+                self.add(file, module.load_address);
+            }
+        }
+    }
+
+    /// Add everything `other` has, moved by `offset`.
+    fn extend(&mut self, other: &Symbolizer, offset: u64) {
+        let files = self.files.len();
+        self.files.extend(other.files.iter().cloned());
+        self.symbols.extend(other.symbols.iter().map(|sym| Symbol {
+            address: sym.address.wrapping_add(offset),
+            ..sym.clone()
+        }));
+        self.lines.extend(other.lines.iter().map(|line| Line {
+            address: line.address.wrapping_add(offset),
+            file: line.file + files,
+            ..line.clone()
+        }));
+        self.inlines
+            .extend(other.inlines.iter().map(|inline| Inline {
+                address: inline.address.wrapping_add(offset),
+                file: inline.file + files,
+                ..inline.clone()
+            }));
+        self.cfi.extend(other.cfi.iter().map(|fde| {
+            Fde {
+                address: fde.address.wrapping_add(offset),
+                size: fde.size,
+                rules: fde
+                    .rules
+                    .iter()
+                    .map(|(address, rules)| (address.wrapping_add(offset), rules.clone()))
+                    .collect(),
+            }
+        }));
+        self.stack_win
+            .extend(other.stack_win.iter().map(|frame| FrameData {
+                address: frame.address.wrapping_add(offset),
+                ..frame.clone()
+            }));
+    }
+
+    fn sort(&mut self) {
+        self.symbols.sort_by_key(|sym| sym.address);
+        self.lines.sort_by_key(|line| line.address);
+        self.inlines.sort_by_key(|inline| inline.address);
+        self.cfi.sort_by_key(|fde| fde.address);
+        self.stack_win.sort_by_key(|frame| frame.address);
+    }
+
+    /// Add the symbols, lines, inlined functions and unwind rules of the symbol file of a module
+    /// loaded at `load_address`.
+    fn add(&mut self, file: SymbolFile, load_address: u64) {
+        let mut syms: Vec<(u64, u64, String)> = file
+            .publics
+            .into_iter()
+            .map(|(address, name)| (address, 0, name))
+            .collect();
+        for func in file.functions {
+            syms.push((func.address, func.size, func.name));
+            self.lines.extend(func.lines.into_iter().map(|line| Line {
+                address: line.address.wrapping_add(load_address),
+                file: line.file + self.files.len(),
+                ..line
+            }));
+            for inline in func.inlines {
+                let name = file.origins.get(inline.origin).cloned().unwrap_or_default();
+                for (address, size) in inline.ranges {
+                    self.inlines.push(Inline {
+                        address: address.wrapping_add(load_address),
+                        size,
+                        depth: inline.depth,
+                        name: name.clone(),
+                        file: inline.call_file + self.files.len(),
+                        line: inline.call_line,
+                    });
+                }
+            }
+        }
+        self.files.extend(file.files);
+        // Publics have no size, each runs up to the next symbol.
+        syms.sort_by_key(|&(address, _, _)| address);
+        let starts: Vec<u64> = syms.iter().map(|&(address, _, _)| address).collect();
+        for (i, (address, size, name)) in syms.into_iter().enumerate() {
+            let size = match size {
+                0 => starts
+                    .get(i + 1..)
+                    .and_then(|next| next.iter().find(|&&next| next > address))
+                    .map_or(0, |next| next - address),
+                size => size,
+            };
+            let address = address.wrapping_add(load_address);
+            self.symbols.push(Symbol::new(&name, address, size));
+        }
+        self.cfi.extend(file.cfi.into_iter().map(|fde| {
+            Fde {
+                address: fde.address.wrapping_add(load_address),
+                size: fde.size,
+                rules: fde
+                    .rules
+                    .into_iter()
+                    .map(|(address, rules)| (address.wrapping_add(load_address), rules))
+                    .collect(),
+            }
+        }));
+        self.stack_win
+            .extend(file.stack_win.into_iter().map(|frame| FrameData {
+                address: frame.address.wrapping_add(load_address),
+                ..frame
+            }));
+    }
+
+    /// Address of the symbol with the given raw or demangled name.
+    pub fn lookup(&self, name: &str) -> Option<u64> {
+        self.symbols
+            .iter()
+            .find(|sym| sym.name == name || sym.demangled == name)
+            .map(|sym| sym.address)
+    }
+
+    /// Demangled name of the symbol holding the address.
+    pub fn symbol(&self, address: u64) -> Option<&str> {
+        self.locate(address).map(|(name, _)| name)
+    }
+
+    /// Demangled name and address of the symbol holding the address.
+    pub fn locate(&self, address: u64) -> Option<(&str, u64)> {
+        let i = self.symbols.partition_point(|sym| sym.address <= address);
+        let sym = self.symbols[..i].last()?;
+        (address < sym.address + sym.size).then_some((sym.demangled.as_str(), sym.address))
+    }
+
+    /// File and line of the address, as `file:line`, in the function holding it rather than in
+    /// those inlined into it at the address, which are listed innermost first with their own.
+    pub fn source(&self, address: u64) -> (Option<String>, Vec<Inlined>) {
+        let i = self.lines.partition_point(|line| line.address <= address);
+        let mut source = self.lines[..i]
+            .last()
+            .filter(|line| address < line.address + line.size)
+            .and_then(|line| Some(format!("{}:{}", self.files.get(line.file)?, line.line)));
+        // The ranges of the functions inlined into the one holding the address start in it.
+        let start = self.locate(address).map_or(address, |(_, start)| start);
+        let first = self
+            .inlines
+            .partition_point(|inline| inline.address < start);
+        let last = self
+            .inlines
+            .partition_point(|inline| inline.address <= address);
+        let mut inlines: Vec<&Inline> = self.inlines[first..last]
+            .iter()
+            .filter(|inline| address < inline.address + inline.size)
+            .collect();
+        inlines.sort_by_key(|inline| std::cmp::Reverse(inline.depth));
+        let inlined = inlines
+            .into_iter()
+            .map(|inline| Inlined {
+                symbol: inline.name.clone(),
+                source: std::mem::replace(
+                    &mut source,
+                    self.files
+                        .get(inline.file)
+                        .map(|file| format!("{file}:{}", inline.line)),
+                ),
+            })
+            .collect();
+        (source, inlined)
+    }
+
+    /// The pc, stack pointer and frame pointer of the caller of the frame at `address`, from the
+    /// unwind rules covering it, reading the stack through `read`.
+    pub fn unwind(
+        &self,
+        address: u64,
+        sp: u64,
+        fp: u64,
+        read: impl Fn(u64, &mut [u8]) -> bool,
+    ) -> Option<(u64, u64, u64)> {
+        let i = self.cfi.partition_point(|fde| fde.address <= address);
+        let Some(fde) = self.cfi[..i]
+            .last()
+            .filter(|fde| address < fde.address + fde.size)
+        else {
+            return self.unwind_win(address, sp, fp, read);
+        };
+        // Under the names of both x86-64 and arm64.
+        let registers = HashMap::from(
+            [
+                ("$rip", address),
+                ("$rsp", sp),
+                ("$rbp", fp),
+                ("pc", address),
+                ("sp", sp),
+                ("x29", fp),
+            ]
+            .map(|(name, value)| (name.to_owned(), value)),
+        );
+        let caller = breakpad::unwind(fde, address, &registers, read);
+        let fp = caller.get("$rbp").or(caller.get("x29")).unwrap_or(&fp);
+        Some((*caller.get(".ra")?, *caller.get(".cfa")?, *fp))
+    }
+
+    /// `unwind` with the `STACK WIN` records of x86 modules, frame data preferred over FPO.
+    fn unwind_win(
+        &self,
+        address: u64,
+        sp: u64,
+        fp: u64,
+        read: impl Fn(u64, &mut [u8]) -> bool,
+    ) -> Option<(u64, u64, u64)> {
+        let i = self
+            .stack_win
+            .partition_point(|frame| frame.address <= address);
+        let frame = [4, 0].into_iter().find_map(|kind| {
+            self.stack_win[..i]
+                .iter()
+                .rev()
+                .find(|frame| frame.kind == kind)
+                .filter(|frame| address < frame.address + frame.size as u64)
+        })?;
+        let registers = HashMap::from(
+            [("$eip", address), ("$esp", sp), ("$ebp", fp)]
+                .map(|(name, value)| (name.to_owned(), value)),
+        );
+        let caller = breakpad::unwind_win(frame, &registers, read)?;
+        // Past the arguments of the call, where the locals of the caller end.
+        Some((
+            caller["$eip"],
+            caller["$esp"] + frame.params as u64,
+            caller["$ebp"],
+        ))
+    }
+}
+
+/// The symbol file of a module: from a store or a symbol server, or made from its PDB, the debug
+/// file debuginfod has for an ELF image or the exports of a PE image a symbol server has.
+fn symbol_file(
+    module: &Module,
+    options: &SymbolOptions,
+    servers: &SymbolServers,
+) -> Option<SymbolFile> {
+    let debug_id = module.debug_id.as_deref()?;
+    // Windows paths in dumps analyzed elsewhere have backslashes.
+    let image = module.path.rsplit(['/', '\\']).next().unwrap_or_default();
+    let name = module.debug_file.as_deref().unwrap_or(image);
+    let file = options
+        .stores
+        .iter()
+        .find_map(|store| breakpad::load(store, name, debug_id));
+    if file.is_some() {
+        return file;
+    }
+    if let Some(path) = servers.file(name, debug_id, &breakpad::sym_name(name)) {
+        return breakpad::parse(&fs::read_to_string(path).ok()?);
+    }
+    // The PDB itself, stored as symbol servers lay them out, if it's the one the image was linked
+    // with.
+    if module.debug_file.is_some() {
+        let pdb = options
+            .stores
+            .iter()
+            .map(|store| store.join(name).join(debug_id).join(name))
+            .find(|path| path.is_file())
+            .or_else(|| servers.file(name, debug_id, name));
+        let file = pdb
+            .and_then(|path| breakpad::dump_syms(&fs::read(path).ok()?, name))
+            .filter(|file| file.debug_id == debug_id);
+        if file.is_some() {
+            return file;
+        }
+    }
+    let code_id = module.code_id.as_deref()?;
+    let path = match module.debug_file {
+        // Symbol servers index PE images by their timestamp and size.
+        Some(_) => servers.file(image, code_id, image),
+        // ELF build IDs are the only code identifiers in lowercase hex.
+        None if code_id
+            .bytes()
+            .all(|c| matches!(c, b'0'..=b'9' | b'a'..=b'f')) =>
+        {
+            servers.debuginfo(code_id)
+        }
+        None => None,
+    }?;
+    breakpad::dump_syms(&fs::read(path).ok()?, name)
+}
+
+/// Whether an image file found at the path of a module is another build than the one loaded, as
+/// both have a code identifier and they differ.
+pub fn stale(module: &Module, code_id: Option<String>) -> bool {
+    module
+        .code_id
+        .as_ref()
+        .zip(code_id)
+        .is_some_and(|(loaded, file)| *loaded != file)
+}
+
+/// The symbols of a Mach-O image loaded at `load_address`: `syms`, then its exports and the starts
+/// of its functions.
+fn macho_symbols<R: Fn(u64, &mut [u8]) -> bool>(
+    image: &MachO<R>,
+    syms: Vec<macho::Symbol>,
+    load_address: u64,
+) -> Vec<Symbol> {
+    let Some(text) = image.segments().iter().find(|seg| seg.name == "__TEXT") else {
+        return vec![];
+    };
+    let slide = load_address.wrapping_sub(text.vmaddr);
+    image
+        .sized_symbols(syms)
+        .into_iter()
+        .map(|(sym, size)| {
+            // C names are prefixed with an underscore.
+            let name = sym.name.strip_prefix('_').unwrap_or(&sym.name);
+            Symbol::new(name, sym.value.wrapping_add(slide), size)
+        })
+        .collect()
+}
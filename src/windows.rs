@@ -1,37 +1,38 @@
+use crate::{
+    condition::{self, Condition},
+    reason,
+    symbolizer::Symbolizer,
+};
 use std::{
     fs::File,
     mem,
     os::windows::io::{AsRawHandle, FromRawHandle, OwnedHandle},
     ptr,
+    sync::mpsc,
+    time::Duration,
 };
 use windows::{
     core::{self, Free, PCWSTR},
     Win32::{
         Foundation, Security,
-        System::{Diagnostics::Debug, Memory, Threading},
+        System::{
+            Diagnostics::{Debug, ToolHelp},
+            Memory, Threading,
+        },
     },
 };
 
 pub unsafe fn inspect(
     pid: i32,
-    catch_exc: bool,
+    session: bool,
     catch_exit: bool,
-    output_f: impl FnOnce() -> File,
+    dumps: mpsc::Receiver<()>,
+    mut output_f: impl FnMut(i32) -> File,
 ) {
     let process_id = pid as u32;
 
-    if !catch_exc && !catch_exit {
-        minidump_writer::minidump_writer::MinidumpWriter::dump_crash_context(
-            crash_context::CrashContext {
-                process_id,
-                thread_id: 0,
-                exception_code: 0,
-                exception_pointers: ptr::null(),
-            },
-            None,
-            &mut output_f(),
-        )
-        .unwrap();
+    if !session {
+        snapshot(process_id, &mut output_f(pid));
         return;
     }
 
@@ -51,12 +52,33 @@ pub unsafe fn inspect(
     Debug::DebugActiveProcess(process_id).unwrap();
     let mut event = Debug::DEBUG_EVENT::default();
     println!("inspecting process: {}", pid);
-    while let Ok(_) = Debug::WaitForDebugEvent(&mut event, Threading::INFINITE) {
+    loop {
+        if let Err(err) = Debug::WaitForDebugEvent(&mut event, DUMP_POLL_INTERVAL_MS) {
+            if err.code() != Foundation::ERROR_SEM_TIMEOUT.to_hresult() {
+                break;
+            }
+            while dumps.try_recv().is_ok() {
+                snapshot(process_id, &mut output_f(pid));
+            }
+            continue;
+        }
         match event.dwDebugEventCode {
             Debug::EXCEPTION_DEBUG_EVENT
                 if event.u.Exception.ExceptionRecord.ExceptionCode
                     != Foundation::EXCEPTION_BREAKPOINT =>
             {
+                let record = &event.u.Exception.ExceptionRecord;
+                println!(
+                    "exception: {}",
+                    reason::ntstatus(
+                        record.ExceptionCode.0 as u32,
+                        &record.ExceptionInformation[..record.NumberParameters as usize]
+                            .iter()
+                            .map(|&p| p as u64)
+                            .collect::<Vec<_>>(),
+                    )
+                );
+
                 let thread_h = Threading::OpenThread(
                     Threading::THREAD_GET_CONTEXT,
                     Foundation::FALSE,
@@ -86,7 +108,7 @@ pub unsafe fn inspect(
                         ) as _,
                     },
                     None,
-                    &mut output_f(),
+                    &mut output_f(pid),
                 )
                 .unwrap();
                 let _ = Debug::ContinueDebugEvent(
@@ -106,7 +128,7 @@ pub unsafe fn inspect(
                             exception_pointers: ptr::null(),
                         },
                         None,
-                        &mut output_f(),
+                        &mut output_f(pid),
                     )
                     .unwrap();
                 }
@@ -131,6 +153,89 @@ pub unsafe fn inspect(
     process_h.free();
 }
 
+/// Request a dump each time one of the conditions on the process's memory becomes true.
+pub unsafe fn poll(
+    pid: i32,
+    conditions: &[Condition],
+    interval: Duration,
+    dump_tx: mpsc::Sender<()>,
+) -> Result<(), String> {
+    let symbolizer = Symbolizer::new(&modules(pid as u32));
+    let conditions = conditions
+        .iter()
+        .map(|c| {
+            let address = c
+                .address()
+                .or_else(|| symbolizer.lookup(&c.target))
+                .ok_or_else(|| format!("symbol not found: {}", c.target))?;
+            Ok((address, c.clone()))
+        })
+        .collect::<Result<_, String>>()?;
+    let process_h = Threading::OpenProcess(Threading::PROCESS_VM_READ, false, pid as u32).unwrap();
+    let process_h = OwnedHandle::from_raw_handle(process_h.0 as _);
+    condition::poll(
+        conditions,
+        interval,
+        move |address, buf| {
+            Debug::ReadProcessMemory(
+                Foundation::HANDLE(process_h.as_raw_handle()),
+                address as _,
+                buf.as_mut_ptr() as _,
+                buf.len(),
+                None,
+            )
+            .is_ok()
+        },
+        dump_tx,
+    );
+    Ok(())
+}
+
+const DUMP_POLL_INTERVAL_MS: u32 = 100;
+
+/// The images loaded in a process.
+unsafe fn modules(process_id: u32) -> Vec<super::Module> {
+    let Ok(snapshot_h) = ToolHelp::CreateToolhelp32Snapshot(
+        ToolHelp::TH32CS_SNAPMODULE | ToolHelp::TH32CS_SNAPMODULE32,
+        process_id,
+    ) else {
+        return vec![];
+    };
+    let _snapshot_h = OwnedHandle::from_raw_handle(snapshot_h.0 as _);
+    let mut entry = ToolHelp::MODULEENTRY32W {
+        dwSize: mem::size_of::<ToolHelp::MODULEENTRY32W>() as u32,
+        ..Default::default()
+    };
+    let mut modules = vec![];
+    let mut next = ToolHelp::Module32FirstW(snapshot_h, &mut entry);
+    while next.is_ok() {
+        let path = &entry.szExePath;
+        let len = path.iter().position(|&c| c == 0).unwrap_or(path.len());
+        modules.push(super::Module {
+            path: String::from_utf16_lossy(&path[..len]),
+            load_address: entry.modBaseAddr as u64,
+            size: Some(entry.modBaseSize as u64),
+            ..Default::default()
+        });
+        next = ToolHelp::Module32NextW(snapshot_h, &mut entry);
+    }
+    modules
+}
+
+fn snapshot(process_id: u32, output: &mut File) {
+    minidump_writer::minidump_writer::MinidumpWriter::dump_crash_context(
+        crash_context::CrashContext {
+            process_id,
+            thread_id: 0,
+            exception_code: 0,
+            exception_pointers: ptr::null(),
+        },
+        None,
+        output,
+    )
+    .unwrap();
+}
+
 fn wow(h: Foundation::HANDLE) -> bool {
     let mut r = Foundation::BOOL::default();
     unsafe {
@@ -0,0 +1,15 @@
+#include <stdio.h>
+#include <stdlib.h>
+
+__attribute__((noinline)) int leaf(int *p) { return *p; }
+
+__attribute__((noinline)) int mid(int *p, int n) {
+    int x = n * 2;
+    return leaf(p) + x;
+}
+
+int main(int argc, char **argv) {
+    int *p = argc > 5 ? malloc(4) : NULL;
+    printf("%d\n", mid(p, argc));
+    return 0;
+}
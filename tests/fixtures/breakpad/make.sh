@@ -0,0 +1,7 @@
+#!/bin/sh
+# Builds crash without frame pointers, so that only its CFI unwinds it, and its expected symbol
+# file, checked by hand against `objdump --dwarf=decodedline,frames`.
+set -e
+cd "$(dirname "$0")"
+gcc -g -O2 -fomit-frame-pointer -fdebug-prefix-map="$PWD"=. -o crash crash.c
+pmortem dump-syms crash > crash.sym
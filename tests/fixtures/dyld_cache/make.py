@@ -0,0 +1,88 @@
+#!/usr/bin/env python3
+"""Writes a minimal split dyld shared cache: dyld_shared_cache_x86_64 holding the header and the
+__TEXT of /usr/lib/libfixture.dylib, dyld_shared_cache_x86_64.01 its __LINKEDIT with the exported
+_fixture_exported, and dyld_shared_cache_x86_64.symbols the local _fixture_local and a stab."""
+
+import struct
+
+MAIN_UUID = bytes.fromhex("0DDBA11C0FFEE5EEDBA5EBA11CAFE0C0")
+SUB_UUID = bytes.fromhex("0DDBA11C0FFEE5EEDBA5EBA11CAFE0C1")
+SYMBOLS_UUID = bytes.fromhex("0DDBA11C0FFEE5EEDBA5EBA11CAFE0C2")
+IMAGE_UUID = bytes.fromhex("0DDBA11C0FFEE5EEDBA5EBA11CAFE0C3")
+BASE = 0x7FF8_0000_0000
+TEXT = BASE + 0x1000
+LINKEDIT = BASE + 0x10000
+# Up to cacheSubType and its padding, so sub-cache entries carry their file suffix.
+HEADER_SIZE = 0x1D0
+PATH = b"/usr/lib/libfixture.dylib\0"
+
+
+def header(uuid, mappings, **fields):
+    h = bytearray(HEADER_SIZE)
+    h[0:16] = b"dyld_v1  x86_64\0"
+    struct.pack_into("<II", h, 16, HEADER_SIZE, len(mappings))
+    h[88:104] = uuid
+    for offset, value in fields.items():
+        offset = int(offset[1:])
+        if isinstance(value, bytes):
+            h[offset : offset + len(value)] = value
+        elif offset in (72, 80):
+            struct.pack_into("<Q", h, offset, value)
+        else:
+            struct.pack_into("<I", h, offset, value)
+    for address, size, file_offset in mappings:
+        h += struct.pack("<QQQII", address, size, file_offset, 5, 5)
+    return h
+
+
+def put(data, offset, chunk):
+    data[offset : offset + len(chunk)] = chunk
+
+
+def segment(name, vmaddr, vmsize, fileoff, filesize):
+    return struct.pack(
+        "<II16sQQQQiiII", 0x19, 72, name, vmaddr, vmsize, fileoff, filesize, 5, 5, 0, 0
+    )
+
+
+# The image: __TEXT in the main cache, __LINKEDIT at the start of the sub-cache's data.
+commands = (
+    segment(b"__TEXT", TEXT, 0x1000, 0x1000, 0x1000)
+    + segment(b"__LINKEDIT", LINKEDIT, 0x1000, 0x1000, 0x1000)
+    + struct.pack("<IIIIII", 0x2, 24, 0x1000, 1, 0x1010, 0x20)
+    + struct.pack("<II16s", 0x1B, 24, IMAGE_UUID)
+)
+image = struct.pack("<IiiIIIII", 0xFEEDFACF, 0x01000007, 3, 6, 4, len(commands), 0, 0) + commands
+
+main = header(
+    MAIN_UUID,
+    [(BASE, 0x2000, 0)],
+    _392=0x300,
+    _396=1,
+    _400=SYMBOLS_UUID,
+    _448=0x340,
+    _452=1,
+    _456=0,
+)
+main += bytes(0x2000 - len(main))
+put(main, 0x300, SUB_UUID + struct.pack("<Q", LINKEDIT - BASE) + b".01".ljust(32, b"\0"))
+put(main, 0x340, struct.pack("<QQQII", TEXT, 0, 0, 0x380, 0))
+put(main, 0x380, PATH)
+put(main, 0x1000, image)
+
+sub = header(SUB_UUID, [(LINKEDIT, 0x1000, 0x1000)])
+sub += bytes(0x2000 - len(sub))
+put(sub, 0x1000, struct.pack("<IBBHQ", 1, 0x0F, 1, 0, TEXT + 0x100))
+put(sub, 0x1010, b"\0_fixture_exported\0")
+
+symbols = header(SYMBOLS_UUID, [], _72=0x200)
+symbols += bytes(0x300 - len(symbols))
+put(symbols, 0x200, struct.pack("<IIIIII", 0x30, 2, 0x50, 0x20, 0x18, 1))
+put(symbols, 0x218, struct.pack("<QII", TEXT - BASE, 0, 2))
+put(symbols, 0x230, struct.pack("<IBBHQ", 1, 0x0E, 1, 0, TEXT + 0x200))
+put(symbols, 0x240, struct.pack("<IBBHQ", 1, 0x24, 1, 0, TEXT + 0x200))
+put(symbols, 0x250, b"\0_fixture_local\0")
+
+for suffix, data in [("", main), (".01", sub), (".symbols", symbols)]:
+    with open("dyld_shared_cache_x86_64" + suffix, "wb") as f:
+        f.write(data)
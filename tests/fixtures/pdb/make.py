@@ -0,0 +1,258 @@
+#!/usr/bin/env python3
+"""Writes crash.pdb, the PDB of a minimal x86 image with a .text section at 0x1000:
+
+- main at 0x1000, with frame data, and mid at 0x1040, with lines in crash.c;
+- ns::leaf inlined into mid at 0x1048, and Box::get of box.h inlined into it at 0x104a;
+- _exported at 0x1080, only a public with an FPO record, and a data public.
+
+Then its expected symbol file, checked by hand against `llvm-pdbutil dump -all crash.pdb`."""
+
+import os
+import struct
+import subprocess
+
+BLOCK = 512
+GUID = bytes.fromhex("0DDBA11C0FFEE5EEDBA5EBA11CAFE0C0")
+AGE = 2
+
+# Streams
+INFO, TPI, DBI, IPI, MODULE, NAMES, SYMBOLS, SECTIONS, FPO, FRAME_DATA = range(1, 11)
+
+# Type and item indices
+BOX = 0x1000
+NS, LEAF, GET = 0x1000, 0x1001, 0x1002
+
+
+def pad(data, align=4):
+    return data + bytes(-len(data) % align)
+
+
+def record(kind, body, align=4):
+    """A symbol or type record, padded with LF_PAD bytes as types are."""
+    padding = -(len(body) + 4) % align
+    body += bytes(0xF0 + i for i in range(padding, 0, -1))
+    return struct.pack("<HH", len(body) + 2, kind) + body
+
+
+# /names: file names and frame data programs, by offset.
+strings = bytearray(b"\0")
+
+
+def name(s):
+    offset = len(strings)
+    strings.extend(s.encode() + b"\0")
+    return offset
+
+
+CRASH_C, BOX_H = name("crash.c"), name("box.h")
+MAIN_PROGRAM = name("$T0 $ebp = $eip $T0 4 + ^ = $ebp $T0 ^ = $esp $T0 8 + =")
+MID_PROGRAM = name("$T0 .raSearch = $eip $T0 ^ = $esp $T0 4 + =")
+names = (
+    struct.pack("<III", 0xEFFEEFFE, 1, len(strings))
+    + strings
+    + struct.pack("<IIII", 2, CRASH_C, BOX_H, 2)
+)
+
+# PDB info stream, with the named stream map of /names.
+info = (
+    struct.pack("<III", 20000404, 0x5EED, 1)
+    + GUID
+    + struct.pack("<I", 7)
+    + b"/names\0"
+    + struct.pack("<IIIIIII", 1, 1, 1, 1, 0, 0, NAMES)
+    + struct.pack("<I", 20140508)
+)
+
+
+def type_stream(records):
+    data = b"".join(records)
+    header = struct.pack(
+        "<IIIIIHHIIIIIIII",
+        20040203,
+        56,
+        0x1000,
+        0x1000 + len(records),
+        len(data),
+        0xFFFF,
+        0xFFFF,
+        4,
+        0x3FFFF,
+        *[0] * 6,
+    )
+    return header + data
+
+
+tpi = type_stream(
+    # LF_STRUCTURE Box, a forward reference of size 4.
+    [record(0x1505, struct.pack("<HHIIIH", 0, 0x80, 0, 0, 0, 4) + b"Box\0")]
+)
+ipi = type_stream(
+    [
+        record(0x1605, struct.pack("<I", 0) + b"ns\0"),
+        record(0x1601, struct.pack("<II", NS, 0x3) + b"leaf\0"),
+        record(0x1602, struct.pack("<II", BOX, 0x3) + b"get\0"),
+    ]
+)
+
+# Module symbols: main, then mid with its inline sites nested.
+
+
+def proc(kind, offset, size, name):
+    return record(
+        kind,
+        struct.pack("<IIIIIIIIHB", 0, 0, 0, size, 0, size, 0, offset, 1, 0) + name + b"\0",
+    )
+
+
+def inline_site(inlinee, annotations):
+    return record(0x114D, struct.pack("<III", 0, 0, inlinee) + bytes(annotations))
+
+
+symbols = struct.pack("<I", 4) + b"".join(
+    [
+        proc(0x1110, 0x00, 0x40, b"main"),
+        record(0x0006, b""),
+        proc(0x1147, 0x40, 0x30, b"mid"),
+        # Line 1 + 1 at 0x48, line + 1 at 0x4a, 6 bytes long.
+        inline_site(LEAF, [6, 2, 3, 8, 11, 0x22, 4, 6]),
+        # box.h line 3 + 1 at 0x4a, 4 bytes long.
+        inline_site(GET, [6, 2, 3, 0x0A, 4, 4]),
+        record(0x114E, b""),
+        record(0x114E, b""),
+        record(0x114F, b""),
+    ]
+)
+
+
+def subsection(kind, data):
+    return pad(struct.pack("<II", kind, len(data)) + data)
+
+
+def lines(offset, size, entries):
+    block = struct.pack("<III", 0, len(entries), 12 + 8 * len(entries))
+    for address, line in entries:
+        block += struct.pack("<II", address, line | 0x80000000)
+    return struct.pack("<IHHI", offset, 1, 0, size) + block
+
+
+c13 = b"".join(
+    [
+        # Checksums of no kind, naming the files.
+        subsection(0xF4, struct.pack("<IBBHIBBH", CRASH_C, 0, 0, 0, BOX_H, 0, 0, 0)),
+        subsection(0xF2, lines(0x00, 0x40, [(0x00, 10), (0x20, 12)])),
+        subsection(0xF2, lines(0x40, 0x30, [(0x00, 5), (0x10, 7)])),
+        subsection(0xF6, struct.pack("<IIIIIII", 0, LEAF, 0, 1, GET, 8, 3)),
+    ]
+)
+# Then no global refs.
+module = symbols + c13 + struct.pack("<I", 0)
+
+# Public symbols: of code with a flag of function, and of data.
+publics = b"".join(
+    record(0x110E, struct.pack("<IIH", flags, offset, section) + name + b"\0")
+    for flags, offset, section, name in [
+        (2, 0x00, 1, b"_main"),
+        (2, 0x40, 1, b"_mid"),
+        (2, 0x80, 1, b"_exported"),
+        (0, 0x00, 2, b"_data"),
+    ]
+)
+
+
+def section(name, address, size, characteristics):
+    return struct.pack("<8sIIIIIIHHI", name, size, address, size, 0, 0, 0, 0, 0, characteristics)
+
+
+sections = section(b".text", 0x1000, 0x1000, 0x60000020) + section(
+    b".data", 0x2000, 0x1000, 0xC0000040
+)
+
+# _exported: 1 local and 2 parameter double words, a 3 bytes prolog saving a register.
+fpo = struct.pack("<IIIHH", 0x1080, 0x10, 1, 2, 3 | 1 << 8)
+frame_data = b"".join(
+    struct.pack("<IIIIIIHHI", *fields)
+    for fields in [
+        (0x1000, 0x40, 8, 4, 0, MAIN_PROGRAM, 4, 4, 4),
+        (0x1040, 0x30, 0, 8, 0, MID_PROGRAM, 0, 0, 4),
+    ]
+)
+
+# DBI: one module, then the optional debug header.
+module_info = pad(
+    struct.pack("<I28sHHIII", 0, bytes(28), 0, MODULE, len(symbols), 0, len(c13))
+    + bytes(16)
+    + b"crash.obj\0crash.obj\0"
+)
+section_contributions = struct.pack("<I", 0xEFFE0000 + 19970605)
+section_map = struct.pack("<HH", 0, 0)
+file_info = struct.pack("<HHHH", 1, 0, 0, 0)
+debug = [0xFFFF] * 11
+debug[0], debug[5], debug[9] = FPO, SECTIONS, FRAME_DATA
+debug_header = struct.pack("<11H", *debug)
+dbi = (
+    struct.pack(
+        "<iIIHHHHHHiiiiiIiiHHI",
+        -1,
+        19990903,
+        AGE,
+        0xFFFF,
+        0,
+        0xFFFF,
+        0,
+        SYMBOLS,
+        0,
+        len(module_info),
+        len(section_contributions),
+        len(section_map),
+        len(file_info),
+        0,
+        0,
+        len(debug_header),
+        0,
+        0,
+        0x14C,
+        0,
+    )
+    + module_info
+    + section_contributions
+    + section_map
+    + file_info
+    + debug_header
+)
+
+streams = [b"", info, tpi, dbi, ipi, module, names, publics, sections, fpo, frame_data]
+
+# The superblock, the two free block maps and the block map, then the streams and the directory.
+body = b""
+stream_blocks = []
+for stream in streams:
+    first = 4 + len(body) // BLOCK
+    body += stream.ljust(-(-len(stream) // BLOCK) * BLOCK, b"\0")
+    stream_blocks.append(range(first, 4 + len(body) // BLOCK))
+directory = struct.pack("<I", len(streams))
+directory += b"".join(struct.pack("<I", len(s)) for s in streams)
+directory += b"".join(struct.pack("<I", b) for bs in stream_blocks for b in bs)
+first = 4 + len(body) // BLOCK
+body += directory.ljust(-(-len(directory) // BLOCK) * BLOCK, b"\0")
+count = 4 + len(body) // BLOCK
+block_map = b"".join(struct.pack("<I", b) for b in range(first, count))
+superblock = b"Microsoft C/C++ MSF 7.00\r\n\x1aDS\0\0\0" + struct.pack(
+    "<IIIIII", BLOCK, 1, count, len(directory), 0, 3
+)
+# Used blocks are the 0 bits of the free block map.
+fpm = bytearray(b"\xff" * BLOCK)
+for i in range(count):
+    fpm[i // 8] &= ~(1 << (i % 8))
+data = (
+    superblock.ljust(BLOCK, b"\0")
+    + fpm
+    + b"\xff" * BLOCK
+    + block_map.ljust(BLOCK, b"\0")
+    + body
+)
+
+os.chdir(os.path.dirname(os.path.abspath(__file__)))
+with open("crash.pdb", "wb") as f:
+    f.write(data)
+with open("crash.sym", "wb") as f:
+    subprocess.run(["pmortem", "dump-syms", "crash.pdb"], check=True, stdout=f)